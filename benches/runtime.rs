@@ -0,0 +1,82 @@
+//! Rendimiento del ciclo principal del runtime.
+//!
+//! Mide, con 1, 10 y 100 `MockSensor` y un `NullCommunicator`, la latencia de
+//! un ciclo completo (lectura concurrente de todos los sensores y reparto del
+//! lote) y las lecturas por segundo en ejecuciones de varios ciclos. No usa
+//! GPIO ni red, así que se ejecuta en cualquier máquina:
+//!
+//! ```text
+//! cargo bench --bench runtime
+//! ```
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use iot_framework::core::runtime::RuntimeController;
+use iot_framework::devices::sensors::mock::MockSensor;
+use iot_framework::network::null::NullCommunicator;
+use iot_framework::SensorOutput;
+use std::time::Duration;
+
+/// Número de sensores de cada variante.
+const SENSOR_COUNTS: [usize; 3] = [1, 10, 100];
+
+/// Ciclos de cada ejecución en la medida de rendimiento.
+const THROUGHPUT_CYCLES: u64 = 20;
+
+/// Runtime con `sensors` sensores simulados en el mismo grupo, de modo que se
+/// leen de forma concurrente en cada ciclo. El intervalo mínimo hace que los
+/// ciclos se encadenen sin esperas.
+fn runtime(sensors: usize, null: &NullCommunicator) -> RuntimeController {
+    let mut builder = RuntimeController::builder()
+        .with_communicator(Box::new(null.clone()))
+        .with_interval(Duration::from_nanos(1));
+    for n in 0..sensors {
+        let values = vec![SensorOutput::Float(21.5), SensorOutput::Int(n as i64)];
+        builder = builder.add_sensor(format!("sensor-{n}"), Box::new(MockSensor::cycling(values)));
+    }
+    builder.build().expect("runtime de prueba válido")
+}
+
+/// Runtime de `tokio` con el reloj en pausa: las esperas entre ciclos avanzan
+/// el reloj al instante en lugar de redondearse a la resolución del
+/// temporizador (1 ms), así que solo se mide el trabajo del runtime. Las
+/// lecturas siguen ejecutándose en paralelo en el pool de `spawn_blocking`.
+fn tokio_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .expect("runtime de tokio")
+}
+
+/// Latencia de un ciclo: lanzar la tarea del grupo, leer todos los sensores,
+/// repartir el lote y detenerse.
+fn cycle_latency(c: &mut Criterion) {
+    let rt = tokio_runtime();
+    let mut group = c.benchmark_group("cycle_latency");
+    for sensors in SENSOR_COUNTS {
+        let null = NullCommunicator::new();
+        let mut runtime = runtime(sensors, &null);
+        group.bench_with_input(BenchmarkId::from_parameter(sensors), &sensors, |b, _| {
+            b.iter(|| rt.block_on(runtime.run_for_cycles(1)));
+        });
+        assert!(null.sent() > 0, "el comunicador no recibió lecturas");
+    }
+    group.finish();
+}
+
+/// Lecturas repartidas por segundo en ejecuciones de `THROUGHPUT_CYCLES` ciclos.
+fn throughput(c: &mut Criterion) {
+    let rt = tokio_runtime();
+    let mut group = c.benchmark_group("throughput");
+    for sensors in SENSOR_COUNTS {
+        let null = NullCommunicator::new();
+        let mut runtime = runtime(sensors, &null);
+        group.throughput(Throughput::Elements(sensors as u64 * THROUGHPUT_CYCLES));
+        group.bench_with_input(BenchmarkId::from_parameter(sensors), &sensors, |b, _| {
+            b.iter(|| rt.block_on(runtime.run_for_cycles(THROUGHPUT_CYCLES)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, cycle_latency, throughput);
+criterion_main!(benches);
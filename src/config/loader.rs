@@ -1,5 +1,22 @@
 use std::{fs}; // Utilidades para leer archivos y manejar rutas
 use crate::config::config::Config; // Estructura Config definida en el módulo config
+use thiserror::Error;
+
+/// Errores posibles al cargar la configuración.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// El archivo no existe o no se puede leer.
+    #[error("no se pudo leer la configuración: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// El contenido no es un TOML válido o no coincide con el esquema.
+    #[error("configuración TOML inválida: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    /// El TOML es válido pero algún valor no tiene sentido.
+    #[error("configuración inválida: {0}")]
+    Invalid(String),
+}
 
 /// Carga la configuración del framework desde un archivo TOML.
 /// 
@@ -7,23 +24,112 @@ use crate::config::config::Config; // Estructura Config definida en el módulo c
 /// - `path`: Ruta del archivo de configuración (`.toml`).
 /// 
 /// # Retorno
-/// Devuelve un `Config` con todos los parámetros del sistema, o un error si:
+/// Devuelve un `Config` con todos los parámetros del sistema, o un [`ConfigError`] si:
 /// - El archivo no existe.
 /// - No se puede leer.
 /// - El contenido no es un TOML válido.
+/// - Algún valor es inválido (ver [`parse_config`]).
 /// 
 /// # Ejemplo
-/// ```rust
+/// ```no_run
+/// use iot_framework::config::loader::load_config;
+///
 /// let config = load_config("config.toml").unwrap();
 /// println!("Dispositivo: {}", config.device.name);
 /// ```
-pub fn load_config(path: &str) -> Result<Config, Box<dyn std::error::Error>> {
+pub fn load_config(path: &str) -> Result<Config, ConfigError> {
     // Lee todo el contenido del archivo como un String
     let config_content = fs::read_to_string(path)?;
 
+    parse_config(&config_content)
+}
+
+/// Interpreta una configuración TOML ya leída en memoria.
+///
+/// Además de deserializar, valida que:
+/// - `runtime.interval_ms` sea mayor que cero.
+/// - El `interval_ms` y el `timeout_ms` propios de cada sensor, si se indican,
+///   sean mayores que cero.
+/// - Los `id` de los sensores no se repitan.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::config::loader::parse_config;
+///
+/// let config = parse_config(r#"
+///     [device]
+///     name = "Gateway 1"
+///     location = "Sala 203"
+///
+///     [[sensors]]
+///     id = "lluvia"
+///     type = "rain"
+///     pin = 17
+///
+///     [communication]
+///     type = "mqtt"
+///     broker_url = "mqtt://localhost:1883"
+///     topic = "smartcampus/ambiente"
+///
+///     [runtime]
+///     interval_ms = 5000
+/// "#).unwrap();
+///
+/// assert_eq!(config.device.location, "Sala 203");
+/// assert_eq!(config.sensors[0].pin, Some(17));
+/// assert_eq!(config.communication.r#type_, "mqtt");
+/// assert_eq!(config.runtime.interval_ms, 5000);
+/// ```
+///
+/// Un sensor con intervalo o tiempo máximo nulo se rechaza:
+/// ```
+/// use iot_framework::config::loader::{parse_config, ConfigError};
+///
+/// let config = |sensor_field: &str| format!(r#"
+///     [device]
+///     name = "Gateway 1"
+///     location = "Sala 203"
+///
+///     [[sensors]]
+///     id = "lluvia"
+///     type = "rain"
+///     {sensor_field}
+///
+///     [communication]
+///     type = "mqtt"
+///     broker_url = "mqtt://localhost:1883"
+///     topic = "smartcampus/ambiente"
+///
+///     [runtime]
+///     interval_ms = 5000
+/// "#);
+///
+/// assert!(parse_config(&config("interval_ms = 250")).is_ok());
+/// let err = parse_config(&config("interval_ms = 0")).unwrap_err();
+/// assert!(matches!(&err, ConfigError::Invalid(msg) if msg == "lluvia: interval_ms debe ser mayor que 0"));
+/// let err = parse_config(&config("timeout_ms = 0")).unwrap_err();
+/// assert!(matches!(&err, ConfigError::Invalid(msg) if msg == "lluvia: timeout_ms debe ser mayor que 0"));
+/// ```
+pub fn parse_config(content: &str) -> Result<Config, ConfigError> {
     // Convierte el String desde formato TOML a la estructura Config
     // Esto utiliza Serde + toml para deserializar automáticamente
-    let config: Config = toml::from_str(&config_content)?;
+    let config: Config = toml::from_str(content)?;
+
+    if config.runtime.interval_ms == 0 {
+        return Err(ConfigError::Invalid("runtime.interval_ms debe ser mayor que 0".into()));
+    }
+    let mut ids = std::collections::HashSet::new();
+    for sensor in config.sensor_configs() {
+        if !ids.insert(sensor.id.as_str()) {
+            return Err(ConfigError::Invalid(format!("id de sensor duplicado: {}", sensor.id)));
+        }
+        if sensor.interval_ms == Some(0) {
+            return Err(ConfigError::Invalid(format!("{}: interval_ms debe ser mayor que 0", sensor.id)));
+        }
+        if sensor.timeout_ms == Some(0) {
+            return Err(ConfigError::Invalid(format!("{}: timeout_ms debe ser mayor que 0", sensor.id)));
+        }
+    }
 
     // Devuelve la configuración cargada
     Ok(config)
@@ -1,2 +1,5 @@
+#[allow(clippy::module_inception)]
 pub mod config;
-pub mod loader;
\ No newline at end of file
+pub mod loader;
+#[cfg(feature = "hot-reload")]
+pub mod watcher;
@@ -0,0 +1,213 @@
+use crate::config::config::{Config, SensorConfig};
+use crate::config::loader::{load_config, ConfigError};
+use crate::core::factory::{build_actuator, build_sensor};
+use crate::core::runtime::{BoxedActuator, RuntimeUpdate};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info, warn};
+
+/// Errores al iniciar la vigilancia de la configuración.
+#[derive(Debug, Error)]
+pub enum WatcherError {
+    /// La configuración inicial no pudo cargarse.
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    /// El sistema de archivos no permite vigilar la ruta.
+    #[error("no se pudo vigilar la configuración: {0}")]
+    Notify(#[from] notify::Error),
+}
+
+/// Vigila `config.toml` y aplica los cambios al runtime sin reiniciarlo.
+///
+/// Cada vez que el archivo cambia se vuelve a cargar, se compara con la versión
+/// anterior y se envían al [`RuntimeController`](crate::core::runtime::RuntimeController)
+/// las [`RuntimeUpdate`]s necesarias (ver
+/// [`update_sender`](crate::core::runtime::RuntimeController::update_sender)).
+///
+/// Campos recargables en caliente:
+/// - `runtime.interval_ms` (no así `runtime.read_timeout_ms`, `runtime.range_check`
+///   ni `runtime.publish_errors`).
+/// - `interval_ms` de cada sensor (sin reconstruirlo).
+/// - Sensores nuevos, retirados o con cualquier otro parámetro cambiado
+///   (`type`, `pin`, `device_id`...): se reconstruyen con la factoría.
+/// - `[actuator]`: se apaga el actual y se construye el nuevo.
+///
+/// Los cambios en `[device]`, `[communication]`, `[storage]` y las reglas
+/// `[[alerts]]` solo se aplican al reiniciar; se registran con un aviso. Si el archivo nuevo no es válido se
+/// registra el error y se conserva la configuración anterior.
+///
+/// La vigilancia dura mientras el `ConfigWatcher` exista.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::config::watcher::ConfigWatcher;
+/// use iot_framework::core::runtime::RuntimeUpdate;
+/// use std::time::{Duration, Instant};
+///
+/// let config = |interval_ms: u64| format!(r#"
+///     [device]
+///     name = "Gateway 1"
+///     location = "Sala 203"
+///
+///     [communication]
+///     type = "console"
+///
+///     [runtime]
+///     interval_ms = {interval_ms}
+/// "#);
+/// let dir = std::env::temp_dir().join(format!("watcher-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("config.toml");
+/// std::fs::write(&path, config(5000)).unwrap();
+///
+/// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+/// let _watcher = ConfigWatcher::new(path.to_str().unwrap(), tx).unwrap();
+/// std::fs::write(&path, config(1000)).unwrap();
+///
+/// let deadline = Instant::now() + Duration::from_secs(5);
+/// let update = loop {
+///     match rx.try_recv() {
+///         Ok(update) => break update,
+///         Err(_) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(20)),
+///         Err(e) => panic!("sin recarga: {e}"),
+///     }
+/// };
+/// assert!(matches!(update, RuntimeUpdate::Interval(i) if i == Duration::from_millis(1000)));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    /// Carga `path` como configuración de referencia y empieza a vigilarla.
+    ///
+    /// Se vigila el directorio que contiene el archivo, ya que muchos editores
+    /// guardan escribiendo un archivo nuevo y renombrándolo.
+    pub fn new(path: &str, updates: UnboundedSender<RuntimeUpdate>) -> Result<Self, WatcherError> {
+        let mut current = load_config(path)?;
+        let file = PathBuf::from(path);
+        let file_name = file.file_name().map(|name| name.to_owned());
+        let dir = match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let config_path = path.to_string();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("Error vigilando la configuración: {}", e);
+                    return;
+                }
+            };
+            if event.kind.is_access() || event.kind.is_remove() {
+                return;
+            }
+            let touches_config = event
+                .paths
+                .iter()
+                .any(|p| p.file_name().map(|name| name.to_owned()) == file_name);
+            if !touches_config {
+                return;
+            }
+            match load_config(&config_path) {
+                Ok(new) => {
+                    if new != current {
+                        info!(path = %config_path, "configuración recargada");
+                        for update in diff(&current, &new) {
+                            if updates.send(update).is_err() {
+                                return;
+                            }
+                        }
+                        current = new;
+                    }
+                }
+                // Escrituras a medias o errores de edición: se espera al siguiente cambio.
+                Err(e) => error!("Configuración nueva ignorada: {}", e),
+            }
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Calcula los cambios a aplicar al pasar de `old` a `new`.
+fn diff(old: &Config, new: &Config) -> Vec<RuntimeUpdate> {
+    let mut updates = Vec::new();
+
+    if old.device != new.device {
+        warn!("Cambios en [device] requieren reiniciar");
+    }
+    if old.communication != new.communication {
+        warn!("Cambios en [communication] requieren reiniciar");
+    }
+    if old.storage != new.storage {
+        warn!("Cambios en [storage] requieren reiniciar");
+    }
+    if old.alerts != new.alerts {
+        warn!("Cambios en [[alerts]] requieren reiniciar");
+    }
+    if old.runtime.read_timeout_ms != new.runtime.read_timeout_ms {
+        warn!("Cambios en runtime.read_timeout_ms requieren reiniciar");
+    }
+    if old.runtime.range_check != new.runtime.range_check {
+        warn!("Cambios en runtime.range_check requieren reiniciar");
+    }
+    if old.runtime.publish_errors != new.runtime.publish_errors {
+        warn!("Cambios en runtime.publish_errors requieren reiniciar");
+    }
+    if old.runtime.interval_ms != new.runtime.interval_ms {
+        updates.push(RuntimeUpdate::Interval(Duration::from_millis(new.runtime.interval_ms)));
+    }
+
+    for scfg in old.sensor_configs() {
+        if !new.sensor_configs().any(|s| s.id == scfg.id) {
+            updates.push(RuntimeUpdate::RemoveSensor(scfg.id.clone()));
+        }
+    }
+    for scfg in new.sensor_configs() {
+        let interval = scfg.interval_ms.map(Duration::from_millis);
+        match old.sensor_configs().find(|s| s.id == scfg.id) {
+            Some(prev) if prev == scfg => {}
+            Some(prev) if same_hardware(prev, scfg) => {
+                updates.push(RuntimeUpdate::SensorInterval { id: scfg.id.clone(), interval });
+            }
+            _ => {
+                let scfg = scfg.clone();
+                updates.push(RuntimeUpdate::ReplaceSensor {
+                    id: scfg.id.clone(),
+                    interval,
+                    timeout: scfg.timeout_ms.map(Duration::from_millis),
+                    build: Box::new(move || {
+                        build_sensor(&scfg.r#type_, &scfg).map_err(|e| e.to_string())
+                    }),
+                });
+            }
+        }
+    }
+
+    if old.actuator != new.actuator {
+        let acfg = new.actuator.clone();
+        updates.push(RuntimeUpdate::ReplaceActuators(Box::new(move || {
+            let mut actuators: Vec<(Option<String>, BoxedActuator)> = Vec::new();
+            if let Some(acfg) = acfg {
+                let actuator = build_actuator(&acfg.r#type_, &acfg).map_err(|e| e.to_string())?;
+                actuators.push((acfg.id, actuator));
+            }
+            Ok(actuators)
+        })));
+    }
+
+    updates
+}
+
+/// Indica si dos configuraciones de sensor solo difieren en `interval_ms`.
+fn same_hardware(a: &SensorConfig, b: &SensorConfig) -> bool {
+    SensorConfig { interval_ms: None, ..a.clone() } == SensorConfig { interval_ms: None, ..b.clone() }
+}
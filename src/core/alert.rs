@@ -0,0 +1,290 @@
+use crate::core::{Quality, SensorOutput, SensorReading};
+use std::fmt;
+use std::time::SystemTime;
+
+/// Prefijo del id con que se publica una alerta como lectura (`alert/<id>`).
+pub const ALERT_ID_PREFIX: &str = "alert/";
+
+/// Comparación entre el valor leído y el umbral de un [`AlertRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Comparison {
+    /// `valor > umbral`.
+    Above,
+    /// `valor >= umbral`.
+    AtOrAbove,
+    /// `valor < umbral`.
+    Below,
+    /// `valor <= umbral`.
+    AtOrBelow,
+}
+
+impl Comparison {
+    /// Indica si `value` viola el umbral `threshold`.
+    pub fn holds(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Above => value > threshold,
+            Comparison::AtOrAbove => value >= threshold,
+            Comparison::Below => value < threshold,
+            Comparison::AtOrBelow => value <= threshold,
+        }
+    }
+
+    /// Operador de la comparación (`">"`, `">="`, `"<"`, `"<="`).
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Above => ">",
+            Comparison::AtOrAbove => ">=",
+            Comparison::Below => "<",
+            Comparison::AtOrBelow => "<=",
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Gravedad de una alerta.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    Info,
+    #[default]
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    /// Nombre en minúsculas (`"info"`, `"warning"`, `"critical"`).
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Umbral de alarma sobre las lecturas de un sensor.
+///
+/// Se evalúa con el valor numérico ([`SensorOutput::as_f64`]: `Int`, `Float`,
+/// `Bool` como `0`/`1` o `Text` numérico) de las lecturas del sensor
+/// `sensor_id`. Con `field` se evalúa esa clave de las lecturas `Map` (p. ej.
+/// `"temp"` de un DHT22). El resto de lecturas se ignora.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub sensor_id: String,
+    /// Clave a evaluar en las lecturas `Map`.
+    pub field: Option<String>,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    pub severity: Severity,
+}
+
+impl AlertRule {
+    /// Regla que se viola cuando `valor <comparison> threshold`.
+    pub fn new(sensor_id: impl Into<String>, comparison: Comparison, threshold: f64, severity: Severity) -> Self {
+        Self {
+            sensor_id: sensor_id.into(),
+            field: None,
+            comparison,
+            threshold,
+            severity,
+        }
+    }
+
+    /// Evalúa la clave `field` de las lecturas `Map`.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Valor de `reading` al que se aplica la regla, si le corresponde.
+    pub fn value_of(&self, reading: &SensorReading) -> Option<f64> {
+        if reading.sensor_id != self.sensor_id || reading.quality == Quality::Bad {
+            return None;
+        }
+        match (&reading.value, &self.field) {
+            (value, None) => value.as_f64(),
+            (SensorOutput::Map(values), Some(field)) => values.get(field).map(|v| *v as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Cambio de estado de un [`AlertRule`]: se activa (`active`) o vuelve a la
+/// normalidad.
+///
+/// Con la feature `serde` se serializa como objeto JSON, con la marca de tiempo
+/// en milisegundos desde el UNIX epoch. Para los comunicadores sin un canal
+/// propio de alertas se convierte en una [`SensorReading`] con id
+/// `alert/<sensor_id>` y la descripción como `SensorOutput::Text`.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::core::alert::{Alert, Comparison, Severity};
+/// use iot_framework::{SensorOutput, SensorReading};
+///
+/// let alert = Alert {
+///     sensor_id: "temp".into(),
+///     field: None,
+///     severity: Severity::Critical,
+///     comparison: Comparison::Above,
+///     threshold: 30.0,
+///     value: 31.5,
+///     active: true,
+///     timestamp: UNIX_EPOCH + Duration::from_millis(1000),
+/// };
+/// assert_eq!(alert.to_string(), "[critical] temp = 31.5 (> 30)");
+///
+/// let reading = SensorReading::from(alert);
+/// assert_eq!(reading.sensor_id, "alert/temp");
+/// assert_eq!(reading.value, SensorOutput::Text("[critical] temp = 31.5 (> 30)".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alert {
+    pub sensor_id: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub field: Option<String>,
+    pub severity: Severity,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    /// Valor que provocó el cambio de estado.
+    pub value: f64,
+    /// `true` al activarse la alerta, `false` al volver a la normalidad.
+    pub active: bool,
+    /// Marca de tiempo de la lectura que provocó el cambio.
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::types::unix_millis"))]
+    pub timestamp: SystemTime,
+}
+
+impl fmt::Display for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.severity, self.sensor_id)?;
+        if let Some(field) = &self.field {
+            write!(f, ".{}", field)?;
+        }
+        write!(f, " = {} ({} {})", self.value, self.comparison, self.threshold)?;
+        if !self.active {
+            f.write_str(" resuelta")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Alert> for SensorReading {
+    fn from(alert: Alert) -> Self {
+        SensorReading {
+            sensor_id: format!("{}{}", ALERT_ID_PREFIX, alert.sensor_id),
+            timestamp: alert.timestamp,
+            value: SensorOutput::Text(alert.to_string()),
+            unit: None,
+            quality: Quality::Good,
+            seq: 0,
+        }
+    }
+}
+
+/// Evalúa un conjunto de [`AlertRule`] y emite una [`Alert`] solo cuando una
+/// regla cambia de estado.
+///
+/// Mientras una regla sigue violada las lecturas siguientes no generan nuevas
+/// alertas; la primera lectura que vuelve a cumplirla emite la alerta de
+/// resolución (`active: false`). Las lecturas `Bad` no cambian el estado.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::alert::{AlertMonitor, AlertRule, Comparison, Severity};
+/// use iot_framework::{Quality, SensorOutput, SensorReading};
+///
+/// let mut monitor = AlertMonitor::new([AlertRule::new("temp", Comparison::Above, 30.0, Severity::Critical)]);
+/// let temp = |v| SensorReading::new("temp", SensorOutput::Float(v));
+///
+/// assert!(monitor.check(&temp(25.0)).is_empty());
+///
+/// // Solo la primera lectura por encima del umbral dispara la alerta.
+/// let fired = monitor.check(&temp(31.0));
+/// assert_eq!(fired.len(), 1);
+/// assert!(fired[0].active && fired[0].value == 31.0);
+/// assert!(monitor.check(&temp(32.0)).is_empty());
+/// assert!(monitor.check(&temp(35.0)).is_empty());
+/// assert!(monitor.check(&temp(0.0).with_quality(Quality::Bad)).is_empty());
+/// assert!(monitor.is_active(0));
+///
+/// // Volver a la normalidad también se notifica una sola vez.
+/// let cleared = monitor.check(&temp(29.0));
+/// assert_eq!(cleared.len(), 1);
+/// assert!(!cleared[0].active);
+/// assert!(monitor.check(&temp(28.0)).is_empty());
+///
+/// // Otros sensores no afectan a la regla.
+/// assert!(monitor.check(&SensorReading::new("humedad", SensorOutput::Float(99.0))).is_empty());
+/// assert_eq!(monitor.check(&temp(40.0)).len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AlertMonitor {
+    /// Cada regla con su estado actual (violada o no).
+    rules: Vec<(AlertRule, bool)>,
+}
+
+impl AlertMonitor {
+    /// Crea un monitor con las reglas `rules`, todas inicialmente sin violar.
+    pub fn new(rules: impl IntoIterator<Item = AlertRule>) -> Self {
+        Self {
+            rules: rules.into_iter().map(|rule| (rule, false)).collect(),
+        }
+    }
+
+    /// Añade una regla, inicialmente sin violar.
+    pub fn add(&mut self, rule: AlertRule) {
+        self.rules.push((rule, false));
+    }
+
+    /// Indica si no hay reglas.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Indica si la regla `index` (en orden de registro) está violada.
+    pub fn is_active(&self, index: usize) -> bool {
+        self.rules.get(index).is_some_and(|(_, active)| *active)
+    }
+
+    /// Evalúa `reading` y devuelve las alertas de las reglas que cambiaron de estado.
+    pub fn check(&mut self, reading: &SensorReading) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        for (rule, active) in &mut self.rules {
+            let Some(value) = rule.value_of(reading) else { continue };
+            let violated = rule.comparison.holds(value, rule.threshold);
+            if violated == *active {
+                continue;
+            }
+            *active = violated;
+            alerts.push(Alert {
+                sensor_id: rule.sensor_id.clone(),
+                field: rule.field.clone(),
+                severity: rule.severity,
+                comparison: rule.comparison,
+                threshold: rule.threshold,
+                value,
+                active: violated,
+                timestamp: reading.timestamp,
+            });
+        }
+        alerts
+    }
+}
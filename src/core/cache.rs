@@ -0,0 +1,88 @@
+use crate::core::SensorReading;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Última lectura de cada sensor, compartida entre el runtime y quien la consulte.
+///
+/// Es un `Arc<RwLock<HashMap<String, SensorReading>>>`: clonar un `ReadingCache`
+/// comparte el mismo contenido. Cada [`RuntimeController`](crate::core::runtime::RuntimeController)
+/// tiene uno que actualiza tras cada lectura válida (ver
+/// [`reading_cache`](crate::core::runtime::RuntimeController::reading_cache));
+/// los sensores virtuales, como
+/// [`VirtualSensor`](crate::devices::sensors::virtual_sensor::VirtualSensor), y
+/// los actuadores que dependen de otros sensores leen de él.
+///
+/// Los bloqueos duran solo lo que tarda copiar una lectura, por lo que puede
+/// consultarse desde cualquier hilo o tarea sin frenar al runtime.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::cache::ReadingCache;
+/// use iot_framework::{SensorOutput, SensorReading};
+///
+/// let cache = ReadingCache::new();
+/// let shared = cache.clone();
+/// cache.insert(SensorReading::new("temp", SensorOutput::Float(21.5)));
+/// cache.insert(SensorReading::new("temp", SensorOutput::Float(22.0)));
+/// assert_eq!(shared.get("temp").unwrap().value, SensorOutput::Float(22.0));
+/// assert!(shared.get("humedad").is_none());
+///
+/// // Escrituras y lecturas concurrentes: siempre se obtiene la última lectura.
+/// let writers: Vec<_> = ["a", "b", "c", "d"]
+///     .into_iter()
+///     .map(|id| {
+///         let cache = cache.clone();
+///         std::thread::spawn(move || {
+///             for n in 0..1000 {
+///                 cache.insert(SensorReading::new(id, SensorOutput::Int(n)));
+///             }
+///         })
+///     })
+///     .collect();
+/// let reader = std::thread::spawn(move || {
+///     let mut last = -1;
+///     while last < 999 {
+///         if let Some(reading) = shared.get("a") {
+///             let SensorOutput::Int(n) = reading.value else { panic!() };
+///             assert!(n >= last);
+///             last = n;
+///         }
+///     }
+/// });
+/// writers.into_iter().for_each(|w| w.join().unwrap());
+/// reader.join().unwrap();
+///
+/// let latest = cache.snapshot();
+/// assert_eq!(latest.len(), 5);
+/// assert!(["a", "b", "c", "d"].iter().all(|id| latest[*id].value == SensorOutput::Int(999)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReadingCache {
+    readings: Arc<RwLock<HashMap<String, SensorReading>>>,
+}
+
+impl ReadingCache {
+    /// Crea una caché vacía.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Guarda `reading` como la última de su sensor, reemplazando la anterior.
+    pub fn insert(&self, reading: SensorReading) {
+        // Una escritura interrumpida deja, como mucho, una lectura a medio
+        // actualizar: se sigue usando el contenido.
+        let mut readings = self.readings.write().unwrap_or_else(|e| e.into_inner());
+        readings.insert(reading.sensor_id.clone(), reading);
+    }
+
+    /// Devuelve la última lectura del sensor `id`, si la hay.
+    pub fn get(&self, id: &str) -> Option<SensorReading> {
+        let readings = self.readings.read().unwrap_or_else(|e| e.into_inner());
+        readings.get(id).cloned()
+    }
+
+    /// Copia de la última lectura de todos los sensores, indexada por id.
+    pub fn snapshot(&self) -> HashMap<String, SensorReading> {
+        self.readings.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
@@ -0,0 +1,326 @@
+use crate::core::{SensorOutput, SensorReading};
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Etiquetas de variante con que empieza cada valor codificado.
+pub mod tag {
+    pub const BOOL: u8 = 0x00;
+    pub const INT: u8 = 0x01;
+    pub const FLOAT: u8 = 0x02;
+    pub const TEXT: u8 = 0x03;
+    pub const BYTES: u8 = 0x04;
+    pub const MAP: u8 = 0x05;
+    pub const TIMESTAMP: u8 = 0x06;
+    pub const JSON: u8 = 0x07;
+}
+
+/// Errores al decodificar datos binarios.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CodecError {
+    /// Los datos terminan antes de lo que indica su contenido.
+    #[error("datos truncados")]
+    Truncated,
+    /// La etiqueta de variante no corresponde a ningún `SensorOutput`.
+    #[error("variante desconocida: 0x{0:02x}")]
+    UnknownTag(u8),
+    /// Un campo no tiene un valor válido (UTF-8, booleano, JSON, longitud).
+    #[error("valor no válido: {0}")]
+    Invalid(String),
+    /// Sobran bytes tras el valor.
+    #[error("{0} bytes sobrantes")]
+    TrailingBytes(usize),
+}
+
+/// Codifica un `SensorOutput` en binario compacto, para transportes con
+/// cargas pequeñas (LoRa, serie, BLE).
+///
+/// Cada valor empieza con una etiqueta de variante de 1 byte ([`tag`]). Los
+/// números van en big-endian y los campos de tamaño variable llevan delante
+/// su longitud como entero LEB128 (1 byte hasta 127):
+///
+/// | Variante    | Contenido                                                |
+/// |-------------|----------------------------------------------------------|
+/// | `Bool`      | 1 byte, `0` o `1`                                        |
+/// | `Int`       | `i64`, 8 bytes                                           |
+/// | `Float`     | `f32`, 4 bytes                                           |
+/// | `Text`      | longitud y UTF-8                                         |
+/// | `Bytes`     | longitud y los bytes                                     |
+/// | `Map`       | nº de claves y por cada una: longitud y clave UTF-8, valor `f32` |
+/// | `Timestamp` | milisegundos desde el UNIX epoch, `u64`                  |
+/// | `Json`      | longitud y el JSON compacto (feature `serde`)            |
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::core::codec::{decode, encode};
+/// use iot_framework::SensorOutput;
+///
+/// assert_eq!(encode(&SensorOutput::Float(21.5)), [0x02, 0x41, 0xAC, 0x00, 0x00]);
+/// assert_eq!(encode(&SensorOutput::Text("SECO".into())), b"\x03\x04SECO");
+///
+/// let at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+/// let values = vec![
+///     SensorOutput::Bool(true),
+///     SensorOutput::Bool(false),
+///     SensorOutput::Int(-7),
+///     SensorOutput::Int(i64::MIN),
+///     SensorOutput::Float(-0.25),
+///     SensorOutput::Text("HÚMEDO".into()),
+///     SensorOutput::Text(String::new()),
+///     SensorOutput::Text("x".repeat(100_000)),
+///     SensorOutput::Bytes(vec![0x00, 0xff, 0x2a]),
+///     SensorOutput::Bytes(Vec::new()),
+///     SensorOutput::Map([("humidity".into(), 48.0), ("temp".into(), 21.5)].into()),
+///     SensorOutput::Map(Default::default()),
+///     SensorOutput::Timestamp(at),
+/// #   #[cfg(feature = "serde")]
+/// #   SensorOutput::Json(serde_json::json!({"fix": {"lat": 40.4, "sats": [3, 7]}})),
+/// ];
+/// for value in values {
+///     assert_eq!(decode(&encode(&value)).unwrap(), value);
+/// }
+///
+/// // Un texto de 100 000 bytes solo necesita 3 bytes de longitud.
+/// assert_eq!(encode(&SensorOutput::Text("x".repeat(100_000))).len(), 1 + 3 + 100_000);
+/// ```
+pub fn encode(value: &SensorOutput) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_value(&mut out, value);
+    out
+}
+
+/// Decodifica un valor codificado con [`encode`].
+///
+/// # Errores
+/// - `CodecError::UnknownTag` si la etiqueta de variante no existe (o es
+///   `Json` sin la feature `serde`).
+/// - `CodecError::Truncated` si faltan bytes.
+/// - `CodecError::Invalid` si algún campo no es válido.
+/// - `CodecError::TrailingBytes` si sobran bytes al final.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::codec::{decode, CodecError};
+/// use iot_framework::SensorOutput;
+///
+/// assert_eq!(decode(&[0x00, 0x01]), Ok(SensorOutput::Bool(true)));
+/// assert_eq!(decode(&[0x09]), Err(CodecError::UnknownTag(0x09)));
+/// assert_eq!(decode(&[0x02, 0x41]), Err(CodecError::Truncated));
+/// assert_eq!(decode(b"\x03\x10SECO"), Err(CodecError::Truncated));
+/// assert!(matches!(decode(&[0x00, 0x02]), Err(CodecError::Invalid(_))));
+/// assert_eq!(decode(&[0x00, 0x01, 0xff]), Err(CodecError::TrailingBytes(1)));
+/// assert_eq!(decode(&[]), Err(CodecError::Truncated));
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<SensorOutput, CodecError> {
+    let mut reader = Reader(bytes);
+    let value = reader.value()?;
+    reader.finish()?;
+    Ok(value)
+}
+
+/// Codifica una lectura: id del sensor (longitud y UTF-8), marca de tiempo
+/// en milisegundos (`u64`), número de secuencia (LEB128, 1 byte hasta 127)
+/// y el valor ([`encode`]).
+///
+/// La secuencia viaja en la trama para que el receptor detecte lecturas
+/// perdidas o repetidas (ver [`SensorReading::with_seq`]). La unidad y la
+/// calidad no se codifican para ahorrar bytes: el receptor conoce la unidad
+/// de cada sensor por su id.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::core::codec::{decode_reading, encode_reading};
+/// use iot_framework::{SensorOutput, SensorReading};
+///
+/// let mut reading = SensorReading::new("t1", SensorOutput::Int(3)).with_seq(7);
+/// reading.timestamp = UNIX_EPOCH + Duration::from_millis(1000);
+///
+/// let bytes = encode_reading(&reading);
+/// assert_eq!(bytes, b"\x02t1\0\0\0\0\0\0\x03\xe8\x07\x01\0\0\0\0\0\0\0\x03");
+///
+/// let decoded = decode_reading(&bytes).unwrap();
+/// assert_eq!((decoded.sensor_id.as_str(), decoded.timestamp), ("t1", reading.timestamp));
+/// assert_eq!((decoded.seq, decoded.value), (7, SensorOutput::Int(3)));
+///
+/// // Las secuencias grandes ocupan más bytes pero se conservan.
+/// let reading = reading.with_seq(u64::MAX);
+/// assert_eq!(decode_reading(&encode_reading(&reading)).unwrap().seq, u64::MAX);
+/// ```
+pub fn encode_reading(reading: &SensorReading) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_prefixed(&mut out, reading.sensor_id.as_bytes());
+    out.extend_from_slice(&unix_millis(reading.timestamp).to_be_bytes());
+    write_varint(&mut out, reading.seq);
+    write_value(&mut out, &reading.value);
+    out
+}
+
+/// Decodifica una lectura de [`encode_reading`] con su número de secuencia,
+/// sin unidad y de calidad buena.
+///
+/// # Errores
+/// Los mismos que [`decode`].
+pub fn decode_reading(bytes: &[u8]) -> Result<SensorReading, CodecError> {
+    let mut reader = Reader(bytes);
+    let sensor_id = reader.text()?;
+    let timestamp = reader.timestamp()?;
+    let seq = reader.u64_varint()?;
+    let value = reader.value()?;
+    reader.finish()?;
+    let mut reading = SensorReading::new(sensor_id, value).with_seq(seq);
+    reading.timestamp = timestamp;
+    Ok(reading)
+}
+
+/// Milisegundos desde el UNIX epoch (`0` para instantes anteriores).
+fn unix_millis(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+/// Añade `value` como entero LEB128: 7 bits por byte, el bit alto indica que
+/// sigue otro byte.
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Añade `bytes` precedidos de su longitud.
+fn write_prefixed(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn write_value(out: &mut Vec<u8>, value: &SensorOutput) {
+    match value {
+        SensorOutput::Bool(b) => out.extend_from_slice(&[tag::BOOL, *b as u8]),
+        SensorOutput::Int(v) => {
+            out.push(tag::INT);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        SensorOutput::Float(v) => {
+            out.push(tag::FLOAT);
+            out.extend_from_slice(&v.to_be_bytes());
+        }
+        SensorOutput::Text(t) => {
+            out.push(tag::TEXT);
+            write_prefixed(out, t.as_bytes());
+        }
+        SensorOutput::Bytes(bytes) => {
+            out.push(tag::BYTES);
+            write_prefixed(out, bytes);
+        }
+        SensorOutput::Map(fields) => {
+            out.push(tag::MAP);
+            write_varint(out, fields.len() as u64);
+            for (key, v) in fields {
+                write_prefixed(out, key.as_bytes());
+                out.extend_from_slice(&v.to_be_bytes());
+            }
+        }
+        SensorOutput::Timestamp(at) => {
+            out.push(tag::TIMESTAMP);
+            out.extend_from_slice(&unix_millis(*at).to_be_bytes());
+        }
+        #[cfg(feature = "serde")]
+        SensorOutput::Json(v) => {
+            out.push(tag::JSON);
+            write_prefixed(out, v.to_string().as_bytes());
+        }
+    }
+}
+
+/// Lectura secuencial de los datos codificados.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], CodecError> {
+        if self.0.len() < n {
+            return Err(CodecError::Truncated);
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CodecError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn byte(&mut self) -> Result<u8, CodecError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u64_varint(&mut self) -> Result<u64, CodecError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(CodecError::Invalid("entero LEB128 demasiado largo".to_string()))
+    }
+
+    fn varint(&mut self) -> Result<usize, CodecError> {
+        let value = self.u64_varint()?;
+        usize::try_from(value).map_err(|_| CodecError::Invalid(format!("longitud {}", value)))
+    }
+
+    fn prefixed(&mut self) -> Result<&'a [u8], CodecError> {
+        let len = self.varint()?;
+        self.take(len)
+    }
+
+    fn text(&mut self) -> Result<String, CodecError> {
+        String::from_utf8(self.prefixed()?.to_vec()).map_err(|e| CodecError::Invalid(e.to_string()))
+    }
+
+    fn timestamp(&mut self) -> Result<SystemTime, CodecError> {
+        Ok(UNIX_EPOCH + Duration::from_millis(u64::from_be_bytes(self.array()?)))
+    }
+
+    fn value(&mut self) -> Result<SensorOutput, CodecError> {
+        Ok(match self.byte()? {
+            tag::BOOL => match self.byte()? {
+                0 => SensorOutput::Bool(false),
+                1 => SensorOutput::Bool(true),
+                other => return Err(CodecError::Invalid(format!("booleano {}", other))),
+            },
+            tag::INT => SensorOutput::Int(i64::from_be_bytes(self.array()?)),
+            tag::FLOAT => SensorOutput::Float(f32::from_be_bytes(self.array()?)),
+            tag::TEXT => SensorOutput::Text(self.text()?),
+            tag::BYTES => SensorOutput::Bytes(self.prefixed()?.to_vec()),
+            tag::MAP => {
+                let count = self.varint()?;
+                let mut fields = BTreeMap::new();
+                for _ in 0..count {
+                    let key = self.text()?;
+                    fields.insert(key, f32::from_be_bytes(self.array()?));
+                }
+                SensorOutput::Map(fields)
+            }
+            tag::TIMESTAMP => SensorOutput::Timestamp(self.timestamp()?),
+            #[cfg(feature = "serde")]
+            tag::JSON => SensorOutput::Json(
+                serde_json::from_slice(self.prefixed()?).map_err(|e| CodecError::Invalid(e.to_string()))?,
+            ),
+            other => return Err(CodecError::UnknownTag(other)),
+        })
+    }
+
+    fn finish(self) -> Result<(), CodecError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(CodecError::TrailingBytes(self.0.len()))
+        }
+    }
+}
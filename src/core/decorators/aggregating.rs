@@ -0,0 +1,181 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorKind, SensorMetadata, Unit};
+use crate::core::SensorOutput;
+use std::time::{Duration, Instant};
+
+/// `AggregatingSensor` acumula las lecturas numéricas de otro sensor durante
+/// una ventana de tiempo y entrega solo su resumen.
+///
+/// Sirve para enviar a la nube un mensaje por intervalo en lugar de cada
+/// muestra. Mientras la ventana está abierta las lecturas devuelven
+/// `SensorError::Suppressed` (que el runtime omite sin contarlas como fallos);
+/// la primera lectura que llega con la ventana cumplida se incluye en ella y
+/// se entrega como `SensorOutput::Json`
+/// `{"min": .., "max": .., "avg": .., "count": ..}`, y la ventana vuelve a
+/// empezar en la siguiente muestra. [`flush`](Self::flush) cierra la ventana
+/// en cualquier momento y no entrega nada si está vacía; el runtime lo llama
+/// al apagarse (vía [`Sensor::flush`]), así que la última ventana no se pierde.
+///
+/// Solo se acumulan los valores `Int` y `Float`; el resto pasa sin cambios.
+/// Los errores del sensor interno se devuelven sin afectar a la ventana.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, Instant};
+/// use iot_framework::core::decorators::AggregatingSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::devices::sensors::mock::MockSensor;
+/// use iot_framework::SensorOutput;
+///
+/// let samples = [21.0, 23.5, 20.5, 22.0, 24.0].map(SensorOutput::Float);
+/// let mut temp = AggregatingSensor::new(MockSensor::cycling(samples.to_vec()), Duration::from_secs(60));
+///
+/// // Sin muestras no hay nada que entregar.
+/// assert_eq!(temp.flush(), None);
+///
+/// let start = Instant::now();
+/// for i in 0..4 {
+///     let at = start + Duration::from_secs(15 * i);
+///     assert!(matches!(temp.read_at(at), Err(SensorError::Suppressed)));
+/// }
+/// // La quinta muestra cumple el minuto y cierra la ventana.
+/// let stats = serde_json::json!({"min": 20.5, "max": 24.0, "avg": 22.2, "count": 5});
+/// assert_eq!(temp.read_at(start + Duration::from_secs(60)).unwrap(), SensorOutput::Json(stats));
+/// assert_eq!(temp.flush(), None);
+///
+/// // `flush` cierra una ventana a medias.
+/// temp.read_at(start + Duration::from_secs(61)).unwrap_err();
+/// temp.read_at(start + Duration::from_secs(62)).unwrap_err();
+/// let stats = serde_json::json!({"min": 21.0, "max": 23.5, "avg": 22.25, "count": 2});
+/// assert_eq!(temp.flush(), Some(SensorOutput::Json(stats)));
+/// ```
+///
+/// En el runtime, la ventana abierta al apagar se publica como última lectura:
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::core::decorators::AggregatingSensor;
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::devices::sensors::mock::MockSensor;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::SensorOutput;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+/// let samples = [21.0, 23.5, 20.5].map(SensorOutput::Float);
+/// let temp = AggregatingSensor::new(MockSensor::cycling(samples.to_vec()), Duration::from_secs(60));
+/// let sent = RecordingCommunicator::new();
+/// let mut runtime = RuntimeController::builder()
+///     .with_communicator(Box::new(sent.clone()))
+///     .with_interval(Duration::from_secs(1))
+///     .add_sensor("temp", Box::new(temp))
+///     .build()
+///     .unwrap();
+///
+/// // Tres muestras no llenan el minuto: nada se publica hasta apagar.
+/// runtime.run_for_cycles(3).await;
+/// let stats = serde_json::json!({"min": 20.5, "max": 23.5, "avg": 21.666666666666668, "count": 3});
+/// assert_eq!(sent.values("temp"), [SensorOutput::Json(stats)]);
+/// assert_eq!(sent.readings()[0].seq, 1);
+/// # }
+/// ```
+pub struct AggregatingSensor<S> {
+    inner: S,
+    window: Duration,
+    stats: Option<WindowStats>,
+}
+
+/// Resumen de la ventana en curso.
+struct WindowStats {
+    started: Instant,
+    min: f64,
+    max: f64,
+    sum: f64,
+    count: u64,
+}
+
+impl WindowStats {
+    fn new(started: Instant, value: f64) -> Self {
+        Self { started, min: value, max: value, sum: value, count: 1 }
+    }
+
+    fn add(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn to_output(&self) -> SensorOutput {
+        SensorOutput::Json(serde_json::json!({
+            "min": self.min,
+            "max": self.max,
+            "avg": self.sum / self.count as f64,
+            "count": self.count,
+        }))
+    }
+}
+
+impl<S> AggregatingSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `AggregatingSensor` que resume cada `window`, contada desde la
+    /// primera muestra de la ventana.
+    pub fn new(inner: S, window: Duration) -> Self {
+        Self { inner, window, stats: None }
+    }
+
+    /// Lee el sensor interno como si la lectura ocurriera en `now`.
+    ///
+    /// [`Sensor::read`] lo invoca con `Instant::now()`; es público para poder
+    /// cerrar ventanas en instantes conocidos.
+    pub fn read_at(&mut self, now: Instant) -> Result<SensorOutput, SensorError> {
+        let value = match self.inner.read()? {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        let stats = match self.stats.take() {
+            Some(mut stats) => {
+                stats.add(value);
+                stats
+            }
+            None => WindowStats::new(now, value),
+        };
+        if now.saturating_duration_since(stats.started) < self.window {
+            self.stats = Some(stats);
+            return Err(SensorError::Suppressed);
+        }
+        Ok(stats.to_output())
+    }
+
+    /// Cierra la ventana en curso y devuelve su resumen, o `None` si no tenía
+    /// muestras.
+    pub fn flush(&mut self) -> Option<SensorOutput> {
+        self.stats.take().map(|stats| stats.to_output())
+    }
+}
+
+impl<S> Sensor for AggregatingSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.read_at(Instant::now())
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    /// Nombre y unidad del sensor envuelto, sin rango: el resumen es un objeto
+    /// con varias magnitudes.
+    fn metadata(&self) -> SensorMetadata {
+        let inner = self.inner.metadata();
+        SensorMetadata::new(inner.name, SensorKind::Composite).with_unit(inner.unit)
+    }
+    fn flush(&mut self) -> Option<Self::Output> {
+        AggregatingSensor::flush(self)
+    }
+}
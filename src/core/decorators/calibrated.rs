@@ -0,0 +1,101 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use crate::core::SensorOutput;
+use crate::storage::calibration::CalibrationStore;
+
+/// `CalibratedSensor` corrige las lecturas con la calibración guardada para
+/// su id en un [`CalibrationStore`].
+///
+/// La calibración se consulta en cada lectura, así que un cambio con
+/// [`CalibrationStore::set_calibration`] se aplica desde la siguiente sin
+/// reiniciar. Sin calibración para el id las lecturas pasan sin cambios; con
+/// ella, como en [`ScaledSensor`](crate::core::decorators::ScaledSensor), los
+/// valores `Int` y `Float` se devuelven como `SensorOutput::Float` y el resto
+/// pasa sin cambios.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::CalibratedSensor;
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::mock::MockSensor;
+/// use iot_framework::storage::calibration::CalibrationStore;
+/// use iot_framework::SensorOutput;
+///
+/// let dir = std::env::temp_dir().join(format!("calibrated-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("calibration.json");
+/// let raw = || MockSensor::cycling(vec![SensorOutput::Float(20.0)]);
+///
+/// let store = CalibrationStore::open(&path).unwrap();
+/// let mut temp = CalibratedSensor::new(raw(), store.clone(), "temp");
+/// assert_eq!(temp.read().unwrap(), SensorOutput::Float(20.0));
+///
+/// // El técnico corrige la deriva en campo; se aplica en la siguiente lectura.
+/// store.set_calibration("temp", 1.1, -0.5).unwrap();
+/// assert_eq!(temp.read().unwrap(), SensorOutput::Float(21.5));
+///
+/// // Tras un reinicio la calibración se recupera del disco.
+/// let mut temp = CalibratedSensor::new(raw(), CalibrationStore::open(&path).unwrap(), "temp");
+/// assert_eq!(temp.read().unwrap(), SensorOutput::Float(21.5));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub struct CalibratedSensor<S> {
+    inner: S,
+    store: CalibrationStore,
+    id: String,
+}
+
+impl<S> CalibratedSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `CalibratedSensor` que aplica la calibración de `id` en `store`
+    /// (normalmente el mismo id con que se registra en el runtime).
+    pub fn new(inner: S, store: CalibrationStore, id: impl Into<String>) -> Self {
+        Self {
+            inner,
+            store,
+            id: id.into(),
+        }
+    }
+}
+
+impl<S> Sensor for CalibratedSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let output = self.inner.read()?;
+        let Some(calibration) = self.store.get(&self.id) else {
+            return Ok(output);
+        };
+        let value = match output {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        Ok(SensorOutput::Float(calibration.apply(value) as f32))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    /// Metadatos del sensor envuelto con el rango corregido por la calibración
+    /// actual (los límites se intercambian si `scale` es negativo).
+    fn metadata(&self) -> SensorMetadata {
+        let inner = self.inner.metadata();
+        let Some(calibration) = self.store.get(&self.id) else {
+            return inner;
+        };
+        let map = |v: Option<f64>| v.map(|v| calibration.apply(v));
+        let (min, max) = if calibration.scale < 0.0 {
+            (map(inner.max), map(inner.min))
+        } else {
+            (map(inner.min), map(inner.max))
+        };
+        SensorMetadata { min, max, ..inner }
+    }
+}
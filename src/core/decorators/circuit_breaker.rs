@@ -0,0 +1,178 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use std::time::{Duration, Instant};
+
+/// Estado de un [`CircuitBreakerSensor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Funcionamiento normal: cada lectura llega al sensor.
+    Closed,
+    /// Demasiados fallos seguidos: las lecturas fallan sin tocar el sensor
+    /// hasta que pase la espera.
+    Open,
+    /// Pasó la espera: las próximas lecturas prueban si el sensor se recuperó.
+    HalfOpen,
+}
+
+/// Fase interna del circuito con los datos que necesita cada una.
+#[derive(Debug, Clone, Copy)]
+enum Circuit {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { successes: u32 },
+}
+
+/// `CircuitBreakerSensor` deja de leer un sensor que falla una y otra vez.
+///
+/// Tras `failure_threshold` fallos consecutivos el circuito se abre: durante
+/// `cooldown` las lecturas devuelven al instante `SensorError::CircuitOpen`
+/// (con el tiempo restante) sin llamar al sensor, lo que evita martillear un
+/// bus caído y llenar el registro de errores repetidos. Pasada la espera el
+/// circuito queda entreabierto y la siguiente lectura llega al sensor: con
+/// [`with_success_threshold`](Self::with_success_threshold) lecturas correctas
+/// seguidas (una por defecto) se cierra de nuevo, y un solo fallo lo vuelve a
+/// abrir otro `cooldown`.
+///
+/// Las omisiones deliberadas (`Warmup`, `Suppressed`) no cuentan como fallo
+/// ni como acierto.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, Instant};
+/// use iot_framework::core::decorators::circuit_breaker::{CircuitBreakerSensor, CircuitState};
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// /// Sensor que sigue un guion de aciertos y fallos, y cuenta sus lecturas.
+/// struct Script(Vec<bool>, usize);
+/// impl Sensor for Script {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         self.1 += 1;
+///         match self.0.remove(0) {
+///             true => Ok(SensorOutput::Float(21.5)),
+///             false => Err(SensorError::ReadError("sin ACK".into())),
+///         }
+///     }
+/// }
+///
+/// let script = Script(vec![true, false, false, false, false, true, true], 0);
+/// let mut sensor = CircuitBreakerSensor::new(script, 3, Duration::from_secs(30));
+/// let start = Instant::now();
+/// let at = |secs| start + Duration::from_secs(secs);
+///
+/// // Cerrado: los fallos llegan tal cual hasta alcanzar el umbral.
+/// assert!(sensor.read_at(at(0)).is_ok());
+/// for secs in 1..=3 {
+///     assert!(matches!(sensor.read_at(at(secs)), Err(SensorError::ReadError(_))));
+/// }
+/// assert_eq!(sensor.state_at(at(3)), CircuitState::Open);
+///
+/// // Abierto: falla al instante sin leer el sensor.
+/// assert!(matches!(sensor.read_at(at(13)), Err(SensorError::CircuitOpen(left)) if left == Duration::from_secs(20)));
+/// assert_eq!(sensor.inner().1, 4);
+///
+/// // Entreabierto tras la espera: la prueba falla y se reabre.
+/// assert_eq!(sensor.state_at(at(33)), CircuitState::HalfOpen);
+/// assert!(matches!(sensor.read_at(at(33)), Err(SensorError::ReadError(_))));
+/// assert_eq!(sensor.state_at(at(34)), CircuitState::Open);
+///
+/// // La siguiente prueba sale bien y el circuito se cierra.
+/// assert_eq!(sensor.state_at(at(63)), CircuitState::HalfOpen);
+/// assert!(sensor.read_at(at(63)).is_ok());
+/// assert_eq!(sensor.state_at(at(63)), CircuitState::Closed);
+/// assert!(sensor.read_at(at(64)).is_ok());
+/// assert_eq!(sensor.inner().1, 7);
+/// ```
+pub struct CircuitBreakerSensor<S> {
+    inner: S,
+    failure_threshold: u32,
+    success_threshold: u32,
+    cooldown: Duration,
+    circuit: Circuit,
+}
+
+impl<S: Sensor> CircuitBreakerSensor<S> {
+    /// Crea un `CircuitBreakerSensor` que se abre tras `failure_threshold`
+    /// fallos consecutivos (un valor de `0` se trata como `1`) y espera
+    /// `cooldown` antes de volver a probar el sensor.
+    pub fn new(inner: S, failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            inner,
+            failure_threshold: failure_threshold.max(1),
+            success_threshold: 1,
+            cooldown,
+            circuit: Circuit::Closed { failures: 0 },
+        }
+    }
+
+    /// Exige `successes` lecturas correctas seguidas con el circuito
+    /// entreabierto para cerrarlo (por defecto 1; `0` se trata como `1`).
+    pub fn with_success_threshold(mut self, successes: u32) -> Self {
+        self.success_threshold = successes.max(1);
+        self
+    }
+
+    /// Devuelve una referencia al sensor envuelto.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Estado del circuito en `now`: abierto pasa a entreabierto en cuanto se
+    /// cumple la espera.
+    pub fn state_at(&self, now: Instant) -> CircuitState {
+        match self.circuit {
+            Circuit::Closed { .. } => CircuitState::Closed,
+            Circuit::Open { since } if now.saturating_duration_since(since) < self.cooldown => CircuitState::Open,
+            Circuit::Open { .. } | Circuit::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Estado actual del circuito.
+    pub fn state(&self) -> CircuitState {
+        self.state_at(Instant::now())
+    }
+
+    /// Lee el sensor interno como si la lectura ocurriera en `now`.
+    ///
+    /// [`Sensor::read`] lo invoca con `Instant::now()`; es público para poder
+    /// recorrer las transiciones con instantes conocidos.
+    pub fn read_at(&mut self, now: Instant) -> Result<S::Output, SensorError> {
+        if let Circuit::Open { since } = self.circuit {
+            let elapsed = now.saturating_duration_since(since);
+            if elapsed < self.cooldown {
+                return Err(SensorError::CircuitOpen(self.cooldown - elapsed));
+            }
+            self.circuit = Circuit::HalfOpen { successes: 0 };
+        }
+        let result = self.inner.read();
+        self.circuit = match (&result, self.circuit) {
+            (Err(SensorError::Warmup(_) | SensorError::Suppressed), circuit) => circuit,
+            (Ok(_), Circuit::HalfOpen { successes }) if successes + 1 < self.success_threshold => {
+                Circuit::HalfOpen { successes: successes + 1 }
+            }
+            (Ok(_), _) => Circuit::Closed { failures: 0 },
+            (Err(_), Circuit::Closed { failures }) if failures + 1 < self.failure_threshold => {
+                Circuit::Closed { failures: failures + 1 }
+            }
+            (Err(_), _) => Circuit::Open { since: now },
+        };
+        result
+    }
+}
+
+impl<S: Sensor> Sensor for CircuitBreakerSensor<S> {
+    type Output = S::Output;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.read_at(Instant::now())
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.inner.metadata()
+    }
+}
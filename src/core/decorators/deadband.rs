@@ -0,0 +1,89 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use crate::core::SensorOutput;
+
+/// `DeadbandSensor` solo entrega una lectura numérica cuando se aleja del
+/// último valor entregado más de `delta`.
+///
+/// Evita publicar el ruido de sensores que oscilan alrededor de un valor
+/// (21.50, 21.51, 21.49...). Las lecturas dentro de la banda devuelven
+/// `SensorError::Suppressed`, que el runtime omite sin contarlas como fallos.
+/// La primera lectura siempre se entrega, y la referencia solo se actualiza
+/// con los valores entregados, de modo que una deriva lenta acaba publicándose.
+///
+/// Los valores no numéricos pasan sin cambios y no afectan a la referencia.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::DeadbandSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// struct Steps(Vec<f32>);
+/// impl Sensor for Steps {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         Ok(SensorOutput::Float(self.0.remove(0)))
+///     }
+/// }
+///
+/// let mut sensor = DeadbandSensor::new(Steps(vec![21.50, 21.51, 21.49, 21.58, 21.62, 21.45]), 0.1);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.50));
+/// assert!(matches!(sensor.read(), Err(SensorError::Suppressed)));
+/// assert!(matches!(sensor.read(), Err(SensorError::Suppressed)));
+/// assert!(matches!(sensor.read(), Err(SensorError::Suppressed)));
+/// // Supera la banda respecto del último valor entregado (21.50).
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.62));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.45));
+/// ```
+pub struct DeadbandSensor<S> {
+    inner: S,
+    delta: f64,
+    last: Option<f64>,
+}
+
+impl<S> DeadbandSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `DeadbandSensor` con una banda de `±delta` alrededor del último
+    /// valor entregado.
+    pub fn new(inner: S, delta: f64) -> Self {
+        Self {
+            inner,
+            delta: delta.abs(),
+            last: None,
+        }
+    }
+}
+
+impl<S> Sensor for DeadbandSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let output = self.inner.read()?;
+        let value = match output {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        if let Some(last) = self.last {
+            if (value - last).abs() <= self.delta {
+                return Err(SensorError::Suppressed);
+            }
+        }
+        self.last = Some(value);
+        Ok(output)
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.inner.metadata()
+    }
+}
@@ -0,0 +1,104 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorKind, SensorMetadata};
+use crate::core::SensorOutput;
+use std::time::Instant;
+
+/// `DerivativeSensor` devuelve la velocidad de cambio de otro sensor, en
+/// unidades por segundo.
+///
+/// Guarda la lectura y el instante anteriores y calcula
+/// `(actual - anterior) / segundos transcurridos` como `SensorOutput::Float`.
+/// La primera lectura devuelve `0.0`, ya que no hay muestra previa. Útil para
+/// detectar fugas o subidas bruscas, donde importa la tendencia y no el nivel.
+///
+/// Solo admite valores `Int` y `Float`; cualquier otro produce
+/// `SensorError::ParseError`. La unidad resultante no se declara (sería la del
+/// sensor interno por segundo).
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, Instant};
+/// use iot_framework::core::decorators::DerivativeSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// struct Level(Vec<SensorOutput>);
+/// impl Sensor for Level {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         Ok(self.0.remove(0))
+///     }
+/// }
+///
+/// let start = Instant::now();
+/// let mut sensor = DerivativeSensor::new(Level(vec![
+///     SensorOutput::Float(10.0),
+///     SensorOutput::Int(16),
+///     SensorOutput::Float(13.0),
+///     SensorOutput::Text("n/a".into()),
+/// ]));
+///
+/// assert_eq!(sensor.read_at(start).unwrap(), SensorOutput::Float(0.0));
+/// // +6 en 2 s → 3/s
+/// assert_eq!(sensor.read_at(start + Duration::from_secs(2)).unwrap(), SensorOutput::Float(3.0));
+/// // −3 en 500 ms → −6/s
+/// assert_eq!(sensor.read_at(start + Duration::from_millis(2500)).unwrap(), SensorOutput::Float(-6.0));
+/// assert!(matches!(sensor.read_at(start + Duration::from_secs(3)), Err(SensorError::ParseError(_))));
+/// ```
+pub struct DerivativeSensor<S> {
+    inner: S,
+    previous: Option<(f64, Instant)>,
+}
+
+impl<S> DerivativeSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `DerivativeSensor` sin muestra previa.
+    pub fn new(inner: S) -> Self {
+        Self { inner, previous: None }
+    }
+
+    /// Lee el sensor interno como si la lectura ocurriera en `now`.
+    ///
+    /// [`Sensor::read`] lo invoca con `Instant::now()`; es público para poder
+    /// calcular pendientes con instantes conocidos.
+    pub fn read_at(&mut self, now: Instant) -> Result<SensorOutput, SensorError> {
+        let value = match self.inner.read()? {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => {
+                return Err(SensorError::ParseError(format!(
+                    "DerivativeSensor requiere valores numéricos, se recibió {:?}",
+                    other
+                )))
+            }
+        };
+        let rate = match self.previous {
+            Some((prev, at)) => {
+                let elapsed = now.saturating_duration_since(at).as_secs_f64();
+                if elapsed > 0.0 { (value - prev) / elapsed } else { 0.0 }
+            }
+            None => 0.0,
+        };
+        self.previous = Some((value, now));
+        Ok(SensorOutput::Float(rate as f32))
+    }
+}
+
+impl<S> Sensor for DerivativeSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.read_at(Instant::now())
+    }
+
+    /// Nombre del sensor envuelto; sin unidad ni rango, ya que ambos dependen
+    /// de la velocidad del proceso medido.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new(self.inner.metadata().name, SensorKind::Numeric)
+    }
+}
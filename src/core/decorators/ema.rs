@@ -0,0 +1,92 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use crate::core::SensorOutput;
+
+/// `EmaSensor` aplica una media móvil exponencial a las lecturas numéricas.
+///
+/// Cada muestra `Int`/`Float` actualiza el promedio con
+/// `nuevo = alpha * muestra + (1 - alpha) * anterior` y se devuelve como
+/// `SensorOutput::Float`. La primera muestra inicializa el promedio. Frente a
+/// [`SmoothingSensor`](super::SmoothingSensor) reacciona antes a los cambios y
+/// no necesita guardar una ventana: con `alpha` cercano a 1 sigue casi sin
+/// retraso a la entrada; cercano a 0, filtra más.
+///
+/// Los valores no numéricos pasan sin cambios y no afectan al promedio.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::EmaSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// struct Step(u32);
+/// impl Sensor for Step {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         self.0 += 1;
+///         Ok(SensorOutput::Float(if self.0 == 1 { 0.0 } else { 100.0 }))
+///     }
+/// }
+///
+/// let mut sensor = EmaSensor::new(Step(0), 0.5);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(0.0)); // inicializa
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(50.0));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(75.0));
+///
+/// // Ante un escalón, la distancia al nuevo nivel se reduce en `(1 - alpha)` por muestra.
+/// let mut sensor = EmaSensor::new(Step(0), 0.2);
+/// sensor.read().unwrap();
+/// for n in 1..=20 {
+///     let SensorOutput::Float(v) = sensor.read().unwrap() else { unreachable!() };
+///     let expected = 100.0 * (1.0 - 0.8f64.powi(n));
+///     assert!((v as f64 - expected).abs() < 1e-3, "muestra {n}: {v} != {expected}");
+/// }
+/// ```
+pub struct EmaSensor<S> {
+    inner: S,
+    alpha: f64,
+    average: Option<f64>,
+}
+
+impl<S> EmaSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `EmaSensor` con factor de suavizado `alpha`, limitado a `0..=1`.
+    pub fn new(inner: S, alpha: f64) -> Self {
+        Self {
+            inner,
+            alpha: alpha.clamp(0.0, 1.0),
+            average: None,
+        }
+    }
+}
+
+impl<S> Sensor for EmaSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let value = match self.inner.read()? {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        let average = match self.average {
+            Some(prev) => self.alpha * value + (1.0 - self.alpha) * prev,
+            None => value,
+        };
+        self.average = Some(average);
+        Ok(SensorOutput::Float(average as f32))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.inner.metadata()
+    }
+}
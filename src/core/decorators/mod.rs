@@ -0,0 +1,29 @@
+//! Decoradores de sensores.
+//!
+//! Cada decorador envuelve otro [`Sensor`](crate::core::traits::sensor::Sensor) y
+//! modifica su comportamiento (reintentos, filtrado, transformaciones) sin tocar
+//! el driver original. Pueden componerse entre sí.
+
+#[cfg(feature = "serde")]
+pub mod aggregating;
+#[cfg(feature = "serde")]
+pub mod calibrated;
+pub mod circuit_breaker;
+pub mod deadband;
+pub mod derivative;
+pub mod ema;
+pub mod retry;
+pub mod scaled;
+pub mod smoothing;
+
+#[cfg(feature = "serde")]
+pub use aggregating::AggregatingSensor;
+#[cfg(feature = "serde")]
+pub use calibrated::CalibratedSensor;
+pub use circuit_breaker::CircuitBreakerSensor;
+pub use deadband::DeadbandSensor;
+pub use derivative::DerivativeSensor;
+pub use ema::EmaSensor;
+pub use retry::RetrySensor;
+pub use scaled::ScaledSensor;
+pub use smoothing::SmoothingSensor;
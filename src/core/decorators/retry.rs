@@ -0,0 +1,109 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use std::thread;
+use std::time::Duration;
+
+/// `RetrySensor` reintenta la lectura de un sensor inestable con espera exponencial.
+///
+/// Útil para sensores como el DS18B20, que ocasionalmente devuelven un `w1_slave`
+/// malformado. Si la lectura falla, se espera `base_delay`, luego `2 * base_delay`,
+/// `4 * base_delay`, etc., hasta agotar `max_attempts`; entonces se propaga el
+/// último `SensorError`. Solo se reintentan los errores transitorios (ver
+/// [`SensorError::is_transient`]): un dispositivo inexistente o sin permisos
+/// se devuelve al primer intento.
+///
+/// La espera es bloqueante: dentro del runtime el sensor se lee en
+/// `spawn_blocking`, por lo que no detiene a los demás sensores.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::core::decorators::RetrySensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// /// Devuelve, en orden, los resultados del guion y cuenta sus lecturas.
+/// struct Script(Vec<Result<f32, SensorError>>, usize);
+/// impl Sensor for Script {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         self.1 += 1;
+///         self.0.remove(0).map(SensorOutput::Float)
+///     }
+/// }
+/// let fail = || Err(SensorError::ReadError("CRC incorrecto".into()));
+///
+/// // Dos fallos transitorios y un acierto: el valor llega en el tercer intento.
+/// let mut sensor = RetrySensor::new(Script(vec![fail(), fail(), Ok(21.5)], 0), 3, Duration::ZERO);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.5));
+/// assert_eq!(sensor.inner().1, 3);
+///
+/// // Un error no transitorio se devuelve al primer intento.
+/// let missing = Err(SensorError::NotFound("/sys/bus/w1/devices/28-0001".into()));
+/// let mut sensor = RetrySensor::new(Script(vec![missing, Ok(21.5)], 0), 3, Duration::ZERO);
+/// assert!(matches!(sensor.read(), Err(SensorError::NotFound(_))));
+/// assert_eq!(sensor.inner().1, 1);
+///
+/// // Agotados los intentos se propaga el último error.
+/// let last = Err(SensorError::Timeout(Duration::from_millis(750)));
+/// let mut sensor = RetrySensor::new(Script(vec![fail(), fail(), last, Ok(21.5)], 0), 3, Duration::ZERO);
+/// assert!(matches!(sensor.read(), Err(SensorError::Timeout(_))));
+/// assert_eq!(sensor.inner().1, 3);
+/// ```
+pub struct RetrySensor<S> {
+    inner: S,
+    max_attempts: u32,
+    base_delay: Duration,
+}
+
+impl<S: Sensor> RetrySensor<S> {
+    /// Crea un nuevo `RetrySensor`.
+    ///
+    /// # Parámetros
+    /// - `inner`: sensor a envolver.
+    /// - `max_attempts`: número total de intentos (un valor de `0` se trata como `1`).
+    /// - `base_delay`: espera antes del primer reintento; se duplica en cada intento.
+    pub fn new(inner: S, max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            inner,
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        }
+    }
+
+    /// Devuelve una referencia al sensor envuelto.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: Sensor> Sensor for RetrySensor<S> {
+    type Output = S::Output;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let mut delay = self.base_delay;
+        let mut attempt = 1;
+        loop {
+            match self.inner.read() {
+                Ok(value) => return Ok(value),
+                // Reintentar no acorta el calentamiento, no cambia lo que filtra un
+                // decorador ni hace aparecer el dispositivo.
+                Err(e) if !e.is_transient() => return Err(e),
+                Err(e) if attempt >= self.max_attempts => return Err(e),
+                Err(_) => {
+                    thread::sleep(delay);
+                    delay = delay.saturating_mul(2);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.inner.metadata()
+    }
+}
@@ -0,0 +1,124 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use crate::core::SensorOutput;
+
+/// `ScaledSensor` aplica la transformación lineal `valor * scale + offset` a
+/// las lecturas numéricas.
+///
+/// Sirve para pasar cuentas crudas a unidades de ingeniería (cuentas de ADC a
+/// voltios, corrección de un offset de fábrica...). Los valores `Int` y `Float`
+/// se devuelven como `SensorOutput::Float`; el resto pasa sin cambios.
+///
+/// Como la transformación suele cambiar la magnitud, la unidad puede
+/// redefinirse con [`with_unit`](ScaledSensor::with_unit); si no, se conserva
+/// la del sensor interno.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::ScaledSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::{SensorOutput, Unit};
+///
+/// struct Fixed(SensorOutput);
+/// impl Sensor for Fixed {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         Ok(self.0.clone())
+///     }
+/// }
+///
+/// // Cuentas de un ADC de 10 bits a voltios (3.3 V de referencia).
+/// let mut volts = ScaledSensor::new(Fixed(SensorOutput::Int(512)), 3.3 / 1023.0, 0.0)
+///     .with_unit(Some(Unit::Volt));
+/// assert_eq!(volts.read().unwrap(), SensorOutput::Float(512.0 * 3.3 / 1023.0));
+/// assert_eq!(volts.unit(), Some(Unit::Volt));
+///
+/// let mut corrected = ScaledSensor::new(Fixed(SensorOutput::Float(20.0)), 1.0, -0.5);
+/// assert_eq!(corrected.read().unwrap(), SensorOutput::Float(19.5));
+///
+/// let mut text = ScaledSensor::new(Fixed(SensorOutput::Text("n/a".into())), 2.0, 1.0);
+/// assert_eq!(text.read().unwrap(), SensorOutput::Text("n/a".into()));
+/// ```
+pub struct ScaledSensor<S> {
+    inner: S,
+    scale: f64,
+    offset: f64,
+    unit: Option<Option<Unit>>,
+}
+
+impl<S> ScaledSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `ScaledSensor` que devuelve `valor * scale + offset`.
+    pub fn new(inner: S, scale: f64, offset: f64) -> Self {
+        Self {
+            inner,
+            scale,
+            offset,
+            unit: None,
+        }
+    }
+
+    /// Sustituye la unidad del sensor interno por `unit`.
+    pub fn with_unit(mut self, unit: Option<Unit>) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+}
+
+impl<S> Sensor for ScaledSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let value = match self.inner.read()? {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        Ok(SensorOutput::Float((value * self.scale + self.offset) as f32))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        match self.unit {
+            Some(unit) => unit,
+            None => self.inner.unit(),
+        }
+    }
+
+    /// Metadatos del sensor envuelto con el rango transformado (los límites se
+    /// intercambian si `scale` es negativo) y la unidad de salida.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::decorators::ScaledSensor;
+    /// use iot_framework::core::traits::sensor::Sensor;
+    /// use iot_framework::devices::sensors::temperature::Temperature;
+    /// use iot_framework::Unit;
+    ///
+    /// let ds18b20 = Temperature::from_path("/sys/bus/w1/devices/28-000005e2fdc3/w1_slave").unwrap();
+    /// let fahrenheit = ScaledSensor::new(ds18b20, 1.8, 32.0).with_unit(Some(Unit::Fahrenheit));
+    /// let meta = fahrenheit.metadata();
+    /// assert_eq!(meta.name, "DS18B20");
+    /// assert_eq!(meta.unit, Some(Unit::Fahrenheit));
+    /// assert_eq!((meta.min, meta.max), (Some(-67.0), Some(257.0)));
+    /// ```
+    fn metadata(&self) -> SensorMetadata {
+        let inner = self.inner.metadata();
+        let map = |v: Option<f64>| v.map(|v| v * self.scale + self.offset);
+        let (min, max) = if self.scale < 0.0 {
+            (map(inner.max), map(inner.min))
+        } else {
+            (map(inner.min), map(inner.max))
+        };
+        SensorMetadata {
+            unit: self.unit(),
+            min,
+            max,
+            ..inner
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::{SensorMetadata, Unit};
+use crate::core::SensorOutput;
+use std::collections::VecDeque;
+
+/// `SmoothingSensor` aplica una media móvil simple a las lecturas numéricas.
+///
+/// Mantiene un buffer circular con las últimas `window` lecturas `Int`/`Float`
+/// y devuelve su promedio como `SensorOutput::Float`. Mientras la ventana no
+/// está llena se promedian las muestras disponibles.
+///
+/// Los valores no numéricos (`Bool`, `Text`, `Bytes`, `Map`) pasan sin cambios y
+/// no afectan a la ventana.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::SmoothingSensor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::SensorOutput;
+///
+/// struct Steps(Vec<f32>);
+/// impl Sensor for Steps {
+///     type Output = SensorOutput;
+///     fn read(&mut self) -> Result<SensorOutput, SensorError> {
+///         Ok(SensorOutput::Float(self.0.remove(0)))
+///     }
+/// }
+///
+/// let mut sensor = SmoothingSensor::new(Steps(vec![10.0, 20.0, 30.0, 40.0]), 3);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(10.0)); // ventana parcial
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(15.0));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(20.0));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(30.0)); // descarta el 10.0
+/// ```
+pub struct SmoothingSensor<S> {
+    inner: S,
+    window: usize,
+    samples: VecDeque<f64>,
+}
+
+impl<S> SmoothingSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    /// Crea un `SmoothingSensor` con una ventana de `window` muestras (mínimo 1).
+    pub fn new(inner: S, window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            inner,
+            window,
+            samples: VecDeque::with_capacity(window),
+        }
+    }
+}
+
+impl<S> Sensor for SmoothingSensor<S>
+where
+    S: Sensor<Output = SensorOutput>,
+{
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let value = match self.inner.read()? {
+            SensorOutput::Int(v) => v as f64,
+            SensorOutput::Float(v) => v as f64,
+            other => return Ok(other),
+        };
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+        let mean = self.samples.iter().sum::<f64>() / self.samples.len() as f64;
+        Ok(SensorOutput::Float(mean as f32))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.inner.unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.inner.metadata()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::core::{SensorOutput, SensorReading};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Contadores de actividad del runtime.
+///
+/// Los actualizan las tareas de sensores y el ciclo principal mediante
+/// operaciones atómicas, por lo que pueden consultarse desde otra tarea
+/// mientras el runtime se ejecuta (ver [`RuntimeController::metrics_handle`]).
+///
+/// [`RuntimeController::metrics_handle`]: crate::core::runtime::RuntimeController::metrics_handle
+#[derive(Debug, Default)]
+pub struct RuntimeMetrics {
+    reads_ok: AtomicU64,
+    reads_err: AtomicU64,
+    sends_ok: AtomicU64,
+    sends_err: AtomicU64,
+    read_timeouts: AtomicU64,
+    out_of_range: AtomicU64,
+    batches_dropped: AtomicU64,
+    /// Duración del último ciclo de lectura, en microsegundos.
+    last_cycle_micros: AtomicU64,
+    /// Último valor entregado por cada sensor.
+    last_values: Mutex<BTreeMap<String, SensorOutput>>,
+}
+
+/// Copia inmutable de [`RuntimeMetrics`] en un instante dado.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// Lecturas de sensores completadas con éxito.
+    pub reads_ok: u64,
+    /// Lecturas de sensores fallidas.
+    pub reads_err: u64,
+    /// Lecturas enviadas correctamente por el comunicador.
+    pub sends_ok: u64,
+    /// Envíos fallidos del comunicador.
+    pub sends_err: u64,
+    /// Lecturas abandonadas por superar el tiempo máximo (incluidas en `reads_err`).
+    pub read_timeouts: u64,
+    /// Lecturas descartadas por salir del rango declarado (incluidas en `reads_err`).
+    pub out_of_range: u64,
+    /// Lotes de lecturas descartados por llenarse el canal hacia el comunicador
+    /// (solo con [`Backpressure::DropOldest`]). Cada lote son las lecturas de un
+    /// ciclo de un grupo de sensores.
+    ///
+    /// [`Backpressure::DropOldest`]: crate::core::runtime::Backpressure::DropOldest
+    pub batches_dropped: u64,
+    /// Tiempo que tardó el último ciclo en leer todos sus sensores.
+    pub last_cycle_duration: Duration,
+}
+
+impl RuntimeMetrics {
+    /// Registra el resultado de una lectura.
+    pub fn record_read(&self, ok: bool) {
+        let counter = if ok { &self.reads_ok } else { &self.reads_err };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Registra el resultado de un envío.
+    pub fn record_send(&self, ok: bool) {
+        let counter = if ok { &self.sends_ok } else { &self.sends_err };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Registra una lectura abandonada por superar el tiempo máximo.
+    pub fn record_timeout(&self) {
+        self.read_timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Registra una lectura descartada por salir del rango declarado.
+    pub fn record_out_of_range(&self) {
+        self.out_of_range.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Registra `count` lotes de lecturas descartados sin enviar.
+    pub fn record_dropped(&self, count: u64) {
+        self.batches_dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Registra la duración de un ciclo de lectura.
+    pub fn record_cycle(&self, duration: Duration) {
+        self.last_cycle_micros
+            .store(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Guarda el valor de `reading` como último valor de su sensor.
+    pub fn record_value(&self, reading: &SensorReading) {
+        if let Ok(mut values) = self.last_values.lock() {
+            values.insert(reading.sensor_id.clone(), reading.value.clone());
+        }
+    }
+
+    /// Devuelve el último valor conocido de cada sensor, ordenado por id.
+    pub fn last_values(&self) -> BTreeMap<String, SensorOutput> {
+        self.last_values
+            .lock()
+            .map(|values| values.clone())
+            .unwrap_or_default()
+    }
+
+    /// Devuelve una copia de los contadores actuales.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            reads_ok: self.reads_ok.load(Ordering::Relaxed),
+            reads_err: self.reads_err.load(Ordering::Relaxed),
+            sends_ok: self.sends_ok.load(Ordering::Relaxed),
+            sends_err: self.sends_err.load(Ordering::Relaxed),
+            read_timeouts: self.read_timeouts.load(Ordering::Relaxed),
+            out_of_range: self.out_of_range.load(Ordering::Relaxed),
+            batches_dropped: self.batches_dropped.load(Ordering::Relaxed),
+            last_cycle_duration: Duration::from_micros(
+                self.last_cycle_micros.load(Ordering::Relaxed),
+            ),
+        }
+    }
+}
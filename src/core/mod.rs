@@ -1,5 +1,12 @@
 pub mod traits;
+pub mod alert;
+pub mod cache;
+pub mod codec;
+pub mod decorators;
+pub mod factory;
+pub mod metrics;
 pub mod runtime;
+pub mod schedule;
 pub mod types;
 
-pub use types::SensorOutput;
\ No newline at end of file
+pub use types::{ActuatorCommand, Quality, SensorKind, SensorMetadata, SensorOutput, SensorReading, Unit};
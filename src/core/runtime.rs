@@ -1,10 +1,102 @@
-use crate::core::traits::actuator::Actuator;
+use crate::core::alert::{AlertMonitor, AlertRule};
+use crate::core::cache::ReadingCache;
+use crate::core::metrics::{MetricsSnapshot, RuntimeMetrics};
+use crate::core::schedule::Schedule;
+use crate::core::traits::actuator::{
+    Actuator, ActuatorError, ActuatorResult, ActuatorState, AsyncActuator, BlockingActuator,
+};
 use crate::core::traits::communicator::Communicator;
-use crate::core::traits::sensor::Sensor;
-use crate::core::SensorOutput;
-use crate::devices::sensors;
-use tokio::time::{sleep, Duration};
+use crate::core::traits::sensor::{AsyncSensor, BlockingSensor, Sensor, SensorError};
+use crate::core::traits::storage::Storage;
+use crate::core::{ActuatorCommand, Quality, SensorMetadata, SensorOutput, SensorReading};
+use futures_util::future::join_all;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tokio::time::{sleep, Duration, Instant};
+use tracing::{debug, debug_span, error, info, warn, Instrument};
 
+/// Sensor síncrono tal como se registra en el runtime.
+pub type BoxedSensor = Box<dyn Sensor<Output = SensorOutput> + Send>;
+
+/// Sensor asíncrono tal como lo gestiona el runtime.
+pub type BoxedAsyncSensor = Box<dyn AsyncSensor<Output = SensorOutput> + Send>;
+
+/// Actuador síncrono tal como se registra en el runtime.
+pub type BoxedActuator = Box<dyn Actuator<Command = SensorReading> + Send>;
+
+/// Actuador asíncrono tal como lo gestiona el runtime.
+pub type BoxedAsyncActuator = Box<dyn AsyncActuator<Command = SensorReading> + Send>;
+
+/// Comunicador tal como lo gestiona el runtime.
+pub type BoxedCommunicator = Box<dyn Communicator<Command = SensorReading, Response = ()> + Send>;
+
+/// Almacenamiento tal como lo gestiona el runtime.
+pub type BoxedStorage = Box<dyn Storage + Send>;
+
+/// Capacidad por defecto (en lotes) del canal por el que las tareas de sensores
+/// entregan sus lecturas (ver [`RuntimeControllerBuilder::with_channel_capacity`]).
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 64;
+
+/// Cada cuánto se consulta al comunicador por órdenes remotas.
+const RECEIVE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Actuador registrado en el runtime; el id permite dirigirle órdenes remotas.
+struct ActuatorSlot {
+    id: Option<String>,
+    /// Los actuadores síncronos se guardan envueltos en [`BlockingActuator`].
+    actuator: BoxedAsyncActuator,
+}
+
+/// Qué sensores alimentan a cada actuador con id (ver
+/// [`RuntimeControllerBuilder::route`]).
+///
+/// Un actuador sin rutas, o sin id, recibe las lecturas de todos los sensores.
+#[derive(Debug, Clone, Default)]
+struct ActuatorRoutes {
+    /// Ids de actuador por id de sensor.
+    by_sensor: HashMap<String, Vec<String>>,
+    /// Actuadores con al menos una ruta.
+    routed: HashSet<String>,
+}
+
+impl ActuatorRoutes {
+    fn add(&mut self, sensor_id: String, actuator_id: String) {
+        let targets = self.by_sensor.entry(sensor_id).or_default();
+        if !targets.contains(&actuator_id) {
+            targets.push(actuator_id.clone());
+        }
+        self.routed.insert(actuator_id);
+    }
+
+    /// Indica si la lectura de `sensor_id` debe llegar al actuador `actuator_id`.
+    fn accepts(&self, actuator_id: Option<&str>, sensor_id: &str) -> bool {
+        let Some(actuator_id) = actuator_id.filter(|id| self.routed.contains(*id)) else {
+            return true;
+        };
+        self.by_sensor
+            .get(sensor_id)
+            .is_some_and(|targets| targets.iter().any(|id| id == actuator_id))
+    }
+}
+
+/// Sensor registrado en el runtime con su identificador y su planificación propia.
+struct SensorSlot {
+    id: String,
+    /// Los sensores síncronos se guardan envueltos en [`BlockingSensor`].
+    sensor: BoxedAsyncSensor,
+    /// Planificación específica del sensor; `None` usa el intervalo global.
+    schedule: Option<Schedule>,
+    /// Tiempo máximo de lectura específico del sensor; `None` usa el global.
+    timeout: Option<Duration>,
+    /// Retraso de la primera lectura de cada ejecución respecto a la
+    /// planificación, para escalonar sensores con la misma cadencia.
+    start_offset: Duration,
+    /// Último número de secuencia asignado a una lectura del sensor.
+    seq: u64,
+}
 
 /// # RuntimeController
 /// 
@@ -13,88 +105,2168 @@ use tokio::time::{sleep, Duration};
 /// los actuadores y el mecanismo de comunicación (por ejemplo, MQTT, AMQP, etc.).
 /// 
 /// Características principales:
-/// - Lee datos de los sensores.
+/// - Lee datos de los sensores, cada uno con su propia cadencia.
 /// - Envía los datos a través del comunicador.
 /// - Puede accionar dispositivos (actuadores) en base a la información recibida.
-/// - Ejecuta este ciclo de manera periódica gracias a un intervalo definido.
-
+///
+/// Los sensores que comparten planificación (un intervalo o, con la feature
+/// `cron`, una expresión cron; ver [`Schedule`]) forman un *ciclo*: una tarea de `tokio`
+/// los lee todos de forma concurrente en cada tick y entrega el lote por un
+/// canal `mpsc`, con las lecturas en el orden de registro. Así un sensor lento
+/// no retrasa las lecturas de los demás y el registro es determinista. Los
+/// sensores con intervalo `Duration::ZERO` (dirigidos por eventos) tienen su
+/// propia tarea, y los que tienen un desfase inicial distinto (ver
+/// [`RuntimeControllerBuilder::with_sensor_start_offset`]) forman ciclos
+/// aparte. El ciclo principal consume el canal y reparte cada lectura al
+/// comunicador y a los actuadores (a todos, o solo a los enrutados desde ese
+/// sensor; ver [`RuntimeControllerBuilder::route`]).
+///
+/// El canal es acotado: si el comunicador no da abasto, la política de
+/// [`Backpressure`] decide si las tareas de sensores esperan o se descartan
+/// los lotes más antiguos.
+///
+/// Cada lectura puede limitarse con un tiempo máximo (ver
+/// [`RuntimeControllerBuilder::with_read_timeout`]): si un sensor se cuelga (bus
+/// I2C bloqueado, por ejemplo) se registra el fallo y el ciclo continúa con el
+/// resto de sensores en lugar de detenerse indefinidamente. También puede
+/// activarse la validación de rango (ver
+/// [`RuntimeControllerBuilder::with_range_check`]), que descarta las lecturas
+/// fuera de los límites declarados por cada sensor. Los valores `Float` (o
+/// campos de un `Map`) `NaN` o infinitos se rechazan siempre como
+/// `SensorError::NonFinite`, ya que no pueden publicarse como JSON válido. Por
+/// defecto las lecturas fallidas se omiten; con
+/// [`RuntimeControllerBuilder::with_publish_errors`] se publican marcadas como
+/// [`Quality::Bad`].
+///
+/// Cada lectura válida se evalúa contra las reglas de alarma (ver
+/// [`RuntimeControllerBuilder::with_alert_rule`]); cuando una regla cambia de
+/// estado la alerta se publica con [`Communicator::send_alert`].
+///
+/// Además, el ciclo principal consulta periódicamente [`Communicator::receive`]
+/// y entrega cada [`ActuatorCommand`] recibido a los actuadores registrados con
+/// ese id (ver [`RuntimeControllerBuilder::add_actuator_with_id`]); las
+/// órdenes enviadas con [`RuntimeHandle::command`] siguen el mismo camino. Tras
+/// cada orden ejecutada se publica su confirmación `ack/<id>` con el estado
+/// resultante del actuador (ver [`ActuatorResult`]).
+///
+/// # Ejemplo
+/// Un valor derivado que divide por cero no llega al comunicador:
+/// ```
+/// use std::collections::BTreeMap;
+/// use std::time::Duration;
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::devices::sensors::mock::MockSensor;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::SensorOutput;
+///
+/// # #[tokio::main] async fn main() {
+/// let values = vec![
+///     SensorOutput::Float(21.5),
+///     SensorOutput::Float(0.0 / 0.0),
+///     SensorOutput::Map(BTreeMap::from([("ratio".into(), f32::INFINITY)])),
+/// ];
+/// let sent = RecordingCommunicator::new();
+/// let mut runtime = RuntimeController::builder()
+///     .with_communicator(Box::new(sent.clone()))
+///     .with_interval(Duration::from_millis(1))
+///     .add_sensor("derivado", Box::new(MockSensor::cycling(values)))
+///     .build()
+///     .unwrap();
+///
+/// runtime.run_for_cycles(3).await;
+/// assert_eq!(sent.values("derivado"), vec![SensorOutput::Float(21.5)]);
+/// assert_eq!(runtime.metrics().reads_err, 2);
+/// # }
+/// ```
 pub struct RuntimeController {
-    /// Lista de sensores registrados en el runtime.
-    /// Cada sensor debe implementar el trait `Sensor` y producir un `SensorOutput`.
-    sensors: Vec<Box<dyn Sensor<Output = SensorOutput> + Send>>,
+    /// Lista de sensores registrados en el runtime junto con su identificador.
+    /// Cada sensor debe implementar el trait `Sensor` y producir un `SensorOutput`;
+    /// el runtime lo empaqueta en un `SensorReading` usando el id asociado.
+    sensors: Vec<SensorSlot>,
    
     /// Lista opcional de actuadores.
-    /// Los actuadores reciben comandos (del mismo tipo que producen los sensores) 
+    /// Los actuadores reciben las lecturas (`SensorReading`) producidas por los sensores
     /// y ejecutan acciones.
-    actuators: Option<Vec<Box<dyn Actuator<Command = SensorOutput> + Send>>>,
+    actuators: Option<Vec<ActuatorSlot>>,
+
+    /// Sensores de los que recibe lecturas cada actuador con rutas.
+    routes: ActuatorRoutes,
    
     /// Módulo de comunicación.
     /// Se encarga de transmitir los datos de los sensores hacia el exterior
     /// (por ejemplo, publicarlos en un broker MQTT).
-    communicator: Box<dyn Communicator<Command = SensorOutput, Response = ()> + Send>,
+    communicator: BoxedCommunicator,
+
+    /// Almacenamiento opcional donde se persiste cada lectura tras enviarla.
+    storage: Option<BoxedStorage>,
 
-    /// Intervalo de tiempo entre cada iteración del ciclo de ejecución.
+    /// Intervalo por defecto para los sensores que no definen uno propio.
     interval: Duration,
+
+    /// Tiempo máximo de lectura para los sensores que no definen uno propio.
+    read_timeout: Option<Duration>,
+
+    /// Si se descartan las lecturas fuera del rango de [`Sensor::metadata`].
+    range_check: bool,
+
+    /// Si las lecturas fallidas se publican marcadas como [`Quality::Bad`].
+    publish_errors: bool,
+
+    /// Qué hacer cuando el canal de lecturas está lleno.
+    backpressure: Backpressure,
+
+    /// Capacidad, en lotes, del canal de lecturas.
+    channel_capacity: usize,
+
+    /// Contadores de lecturas y envíos, compartidos con las tareas de sensores.
+    metrics: Arc<RuntimeMetrics>,
+
+    /// Última lectura válida de cada sensor (ver [`RuntimeController::reading_cache`]).
+    reading_cache: ReadingCache,
+
+    /// Reglas de alarma y su estado actual.
+    alerts: AlertMonitor,
+
+    /// Cambios de configuración pendientes (ver [`RuntimeController::update_sender`]).
+    /// Es `None` solo mientras `run` lo tiene prestado.
+    updates: Option<mpsc::UnboundedReceiver<RuntimeUpdate>>,
+    update_tx: mpsc::UnboundedSender<RuntimeUpdate>,
+
+    /// Órdenes enviadas con [`RuntimeHandle::command`]. Es `None` solo
+    /// mientras una sesión de lectura lo tiene prestado.
+    commands: Option<mpsc::UnboundedReceiver<RemoteCommand>>,
+    command_tx: mpsc::UnboundedSender<RemoteCommand>,
+}
+
+/// Orden enviada con [`RuntimeHandle::command`] junto con el canal por el que
+/// se responde su resultado.
+struct RemoteCommand {
+    command: ActuatorCommand,
+    reply: oneshot::Sender<Result<ActuatorResult, CommandError>>,
+}
+
+/// Constructor diferido de un sensor, usado al recargar la configuración.
+pub type SensorBuildFn = Box<dyn FnOnce() -> Result<BoxedSensor, String> + Send>;
+
+/// Constructor diferido de los actuadores (con su id opcional).
+pub type ActuatorsBuildFn =
+    Box<dyn FnOnce() -> Result<Vec<(Option<String>, BoxedActuator)>, String> + Send>;
+
+/// Cambio aplicable a un runtime en ejecución.
+///
+/// Al recibir uno, el runtime detiene momentáneamente las tareas de sensores,
+/// aplica todos los cambios pendientes y vuelve a lanzarlas; el comunicador y
+/// el almacenamiento no se ven afectados.
+pub enum RuntimeUpdate {
+    /// Nuevo intervalo global.
+    Interval(Duration),
+    /// Nuevo intervalo propio de un sensor (`None` usa el global).
+    SensorInterval { id: String, interval: Option<Duration> },
+    /// Retira el sensor `id` (si existe) y registra el que devuelva `build`.
+    ///
+    /// `build` se ejecuta después de liberar el sensor anterior, de modo que el
+    /// nuevo pueda usar los mismos pines.
+    ReplaceSensor {
+        id: String,
+        interval: Option<Duration>,
+        /// Tiempo máximo de lectura propio (`None` usa el global).
+        timeout: Option<Duration>,
+        build: SensorBuildFn,
+    },
+    /// Registra un sensor ya construido, reemplazando al que tuviera el mismo id
+    /// (ver [`RuntimeHandle::add_sensor`]).
+    AddSensor {
+        id: String,
+        sensor: BoxedAsyncSensor,
+        /// Planificación propia (`None` usa el intervalo global).
+        schedule: Option<Schedule>,
+    },
+    /// Retira el sensor `id`.
+    RemoveSensor(String),
+    /// Apaga y descarta los actuadores actuales y registra los que devuelva la función.
+    ReplaceActuators(ActuatorsBuildFn),
+}
+
+/// El runtime al que iba dirigida una [`RuntimeHandle`] ya no existe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("el runtime ya no existe")]
+pub struct RuntimeClosed;
+
+/// Error al ejecutar una orden con [`RuntimeHandle::command`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CommandError {
+    /// Ningún actuador está registrado con ese id.
+    #[error("actuador desconocido: {0}")]
+    UnknownActuator(String),
+    /// El actuador rechazó la orden o falló al ejecutarla.
+    #[error("error ejecutando la orden: {0}")]
+    Execute(String),
+    /// El runtime ya no existe.
+    #[error(transparent)]
+    Closed(#[from] RuntimeClosed),
+}
+
+/// Permite añadir y retirar sensores mientras [`RuntimeController::run`] está
+/// en curso (ver [`RuntimeController::handle`]).
+///
+/// Es un envoltorio sobre el canal de [`RuntimeUpdate`]s: los sensores nunca se
+/// comparten entre hilos tras un `Mutex`, sino que viajan por el canal y el
+/// bucle principal los aplica entre ciclos, tras recuperar los sensores de las
+/// tareas en curso. Puede clonarse y usarse desde cualquier hilo o tarea; los
+/// cambios enviados antes de `run` se aplican al arrancar.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use tokio::time::{sleep_until, Instant};
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::devices::sensors::counter::CounterSensor;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::SensorOutput;
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+/// let sent = RecordingCommunicator::new();
+/// let mut runtime = RuntimeController::builder()
+///     .with_communicator(Box::new(sent.clone()))
+///     .with_interval(Duration::from_millis(10))
+///     .add_sensor("fijo", Box::new(CounterSensor::new()))
+///     .build()
+///     .unwrap();
+///
+/// let handle = runtime.handle();
+/// let cache = runtime.reading_cache();
+/// let (tx, rx) = tokio::sync::watch::channel(false);
+/// let start = Instant::now();
+/// let running = tokio::spawn(async move {
+///     runtime.run(rx).await;
+///     runtime
+/// });
+///
+/// // Se conecta un sensor nuevo con el runtime en marcha.
+/// sleep_until(start + Duration::from_millis(35)).await;
+/// assert!(cache.get("nuevo").is_none());
+/// handle.add_sensor("nuevo", Box::new(CounterSensor::new())).unwrap();
+/// sleep_until(start + Duration::from_millis(62)).await;
+/// // Aplicar el cambio reinicia las lecturas: ambos leen a los 35 ms y luego
+/// // cada 10 ms ("fijo" ya había leído a los 0, 10, 20 y 30 ms).
+/// let ints = |n: i64| (0..n).map(SensorOutput::Int).collect::<Vec<_>>();
+/// assert_eq!(sent.values("nuevo"), ints(3));
+/// assert_eq!(sent.values("fijo"), ints(7));
+///
+/// // Y se retira: la caché conserva su última lectura, pero ya no cambia.
+/// handle.remove_sensor("nuevo").unwrap();
+/// sleep_until(start + Duration::from_millis(105)).await;
+/// assert_eq!(sent.values("nuevo"), ints(3));
+/// assert_eq!(cache.get("nuevo").unwrap().value, SensorOutput::Int(2));
+/// assert_eq!(sent.values("fijo"), ints(12));
+///
+/// tx.send(true).unwrap();
+/// let runtime = running.await.unwrap();
+/// assert_eq!(runtime.sensor_ids(), ["fijo"]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RuntimeHandle {
+    updates: mpsc::UnboundedSender<RuntimeUpdate>,
+    commands: mpsc::UnboundedSender<RemoteCommand>,
+}
+
+impl RuntimeHandle {
+    /// Añade un sensor síncrono con el intervalo global; si ya existe uno con
+    /// el mismo id, lo reemplaza.
+    pub fn add_sensor(&self, id: impl Into<String>, sensor: BoxedSensor) -> Result<(), RuntimeClosed> {
+        self.add_sensor_with_interval(id, sensor, None)
+    }
+
+    /// Añade un sensor síncrono con su propio intervalo (`None` usa el global).
+    pub fn add_sensor_with_interval(
+        &self,
+        id: impl Into<String>,
+        sensor: BoxedSensor,
+        interval: Option<Duration>,
+    ) -> Result<(), RuntimeClosed> {
+        self.add_async_sensor(id, Box::new(BlockingSensor::new(sensor)), interval)
+    }
+
+    /// Añade un sensor asíncrono (`interval` en `None` usa el global).
+    pub fn add_async_sensor(
+        &self,
+        id: impl Into<String>,
+        sensor: BoxedAsyncSensor,
+        interval: Option<Duration>,
+    ) -> Result<(), RuntimeClosed> {
+        self.send(RuntimeUpdate::AddSensor {
+            id: id.into(),
+            sensor,
+            schedule: interval.map(Schedule::EveryInterval),
+        })
+    }
+
+    /// Retira el sensor `id`; no hace nada si no existe.
+    pub fn remove_sensor(&self, id: impl Into<String>) -> Result<(), RuntimeClosed> {
+        self.send(RuntimeUpdate::RemoveSensor(id.into()))
+    }
+
+    /// Entrega `command` a los actuadores registrados con su `actuator_id` y
+    /// espera a que la ejecuten, igual que las órdenes recibidas por el
+    /// comunicador. Devuelve el estado en que quedó el actuador (el último, si
+    /// varios comparten id).
+    ///
+    /// Las órdenes se atienden mientras [`RuntimeController::run`] está en
+    /// curso, sin detener las tareas de sensores; las enviadas antes se
+    /// ejecutan al arrancar.
+    ///
+    /// Como con las órdenes del comunicador, el runtime publica además la
+    /// confirmación `ack/<id>` con el estado resultante.
+    ///
+    /// # Errores
+    /// - `CommandError::UnknownActuator` si ningún actuador tiene ese id.
+    /// - `CommandError::Execute` si algún actuador devuelve un error.
+    /// - `CommandError::Closed` si el runtime ya no existe.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::{CommandError, RuntimeController};
+    /// use iot_framework::core::traits::actuator::ActuatorState;
+    /// use iot_framework::core::ActuatorCommand;
+    /// use iot_framework::devices::actuators::dummy::DummyActuator;
+    /// use iot_framework::network::null::RecordingCommunicator;
+    /// use iot_framework::SensorOutput;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let sent = RecordingCommunicator::new();
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(sent.clone()))
+    ///     .with_interval(Duration::from_secs(60))
+    ///     .add_actuator_with_id("rele", Box::new(DummyActuator::new()))
+    ///     .build()
+    ///     .unwrap();
+    /// let handle = runtime.handle();
+    /// let (stop, shutdown) = tokio::sync::watch::channel(false);
+    /// let running = tokio::spawn(async move { runtime.run(shutdown).await });
+    ///
+    /// let result = handle.command(ActuatorCommand::new("rele", SensorOutput::Bool(true))).await.unwrap();
+    /// assert_eq!(result.state, ActuatorState::Known(SensorOutput::Bool(true)));
+    /// let unknown = handle.command(ActuatorCommand::new("bomba", SensorOutput::Bool(true))).await;
+    /// assert_eq!(unknown.unwrap_err(), CommandError::UnknownActuator("bomba".to_string()));
+    ///
+    /// stop.send(true).unwrap();
+    /// running.await.unwrap();
+    /// // La confirmación se publicó por el comunicador.
+    /// let sent = sent.readings();
+    /// assert_eq!(sent.len(), 1);
+    /// assert_eq!((sent[0].sensor_id.as_str(), &sent[0].value), ("ack/rele", &SensorOutput::Bool(true)));
+    /// # }
+    /// ```
+    pub async fn command(&self, command: ActuatorCommand) -> Result<ActuatorResult, CommandError> {
+        let (reply, result) = oneshot::channel();
+        self.commands
+            .send(RemoteCommand { command, reply })
+            .map_err(|_| RuntimeClosed)?;
+        result.await.map_err(|_| RuntimeClosed)?
+    }
+
+    fn send(&self, update: RuntimeUpdate) -> Result<(), RuntimeClosed> {
+        self.updates.send(update).map_err(|_| RuntimeClosed)
+    }
 }
 
 impl RuntimeController {
      /// Crea una nueva instancia de `RuntimeController`.
     ///
+    /// Se mantiene por compatibilidad; para configuraciones nuevas es preferible
+    /// [`RuntimeController::builder`].
+    ///
     /// # Parámetros
-    /// - `sensors`: lista de sensores a gestionar.
+    /// - `sensors`: lista de pares `(id, sensor)` a gestionar.
     /// - `actuators`: lista opcional de actuadores (puede ser `None` si no hay).
     /// - `communicator`: componente de comunicación a usar.
-    /// - `interval`: tiempo en segundos entre cada ejecución del ciclo.
+    /// - `interval`: tiempo en segundos entre lecturas de cada sensor.
     ///
     /// # Retorna
     /// - Una nueva instancia del controlador de runtime lista para ejecutarse.
     pub fn new(
-        sensors: Vec<Box<dyn Sensor<Output = SensorOutput> + Send>>,
-        actuators: Option<Vec<Box<dyn Actuator<Command = SensorOutput> + Send>>>,
-        communicator: Box<dyn Communicator<Command = SensorOutput, Response = ()> + Send>,
+        sensors: Vec<(String, BoxedSensor)>,
+        actuators: Option<Vec<BoxedActuator>>,
+        communicator: BoxedCommunicator,
         interval: u64,
     ) -> Self {
-        Self {
-            sensors,
-            actuators,
-            communicator,
-            interval: Duration::from_secs(interval),
+        let sensors = sensors
+            .into_iter()
+            .map(|(id, sensor)| (id, sensor, None))
+            .collect();
+        Self::with_intervals(sensors, actuators, communicator, Duration::from_secs(interval))
+    }
+
+    /// Crea un `RuntimeController` donde cada sensor puede tener su propio intervalo.
+    ///
+    /// # Parámetros
+    /// - `sensors`: lista de `(id, sensor, intervalo)`. Un intervalo `None`
+    ///   usa `default_interval`.
+    /// - `actuators`: lista opcional de actuadores (puede ser `None` si no hay).
+    /// - `communicator`: componente de comunicación a usar.
+    /// - `default_interval`: intervalo para los sensores sin intervalo propio.
+    pub fn with_intervals(
+        sensors: Vec<(String, BoxedSensor, Option<Duration>)>,
+        actuators: Option<Vec<BoxedActuator>>,
+        communicator: BoxedCommunicator,
+        default_interval: Duration,
+    ) -> Self {
+        let mut builder = Self::builder()
+            .with_communicator(communicator)
+            .with_interval(default_interval);
+        for (id, sensor, interval) in sensors {
+            builder = match interval {
+                Some(interval) => builder.add_sensor_with_interval(id, sensor, interval),
+                None => builder.add_sensor(id, sensor),
+            };
         }
+        for actuator in actuators.into_iter().flatten() {
+            builder = builder.add_actuator(actuator);
+        }
+        builder
+            .build()
+            .expect("el comunicador siempre está definido")
+    }
+
+    /// Crea un [`RuntimeControllerBuilder`] vacío.
+    pub fn builder() -> RuntimeControllerBuilder {
+        RuntimeControllerBuilder::default()
+    }
+
+    /// Registra un sensor asíncrono.
+    ///
+    /// Los sensores dirigidos por eventos, como
+    /// [`InterruptSensor`](crate::devices::sensors::interrupt::InterruptSensor),
+    /// se registran con un intervalo `Duration::ZERO`: su `read()` ya espera al
+    /// siguiente evento, por lo que cada evento se entrega en cuanto ocurre.
+    ///
+    /// # Parámetros
+    /// - `id`: identificador con el que se etiquetarán sus lecturas.
+    /// - `sensor`: sensor que implementa [`AsyncSensor`].
+    /// - `interval`: intervalo propio; `None` usa el intervalo global.
+    pub fn add_async_sensor(
+        &mut self,
+        id: impl Into<String>,
+        sensor: BoxedAsyncSensor,
+        interval: Option<Duration>,
+    ) {
+        self.sensors.push(SensorSlot {
+            id: id.into(),
+            sensor,
+            schedule: interval.map(Schedule::EveryInterval),
+            timeout: None,
+            start_offset: Duration::ZERO,
+            seq: 0,
+        });
     }
 
      /// Inicia el ciclo principal del controlador.
     /// 
-    /// Este método es **asíncrono** y corre en un bucle infinito:
-    /// 1. Lee datos de cada sensor.
-    /// 2. Intenta enviar esos datos a través del comunicador.
-    /// 3. Si existen actuadores, les pasa los datos para que actúen.
-    /// 4. Espera el intervalo configurado antes de repetir el ciclo.
-    ///
-    /// El ciclo nunca termina (a menos que el proceso se detenga).
-    
-    pub async fn run(&mut self) {
+    /// Este método es **asíncrono**:
+    /// 1. Agrupa los sensores por intervalo y lanza una tarea por grupo que los
+    ///    lee concurrentemente en cada ciclo y asocia cada valor a su id y marca
+    ///    de tiempo.
+    /// 2. Envía cada lectura recibida a través del comunicador.
+    /// 3. Si existen actuadores, les pasa la lectura para que actúen.
+    ///
+    /// # Parámetros
+    /// - `shutdown`: receptor de un canal `watch`. Cuando su valor pasa a `true`
+    ///   (o el emisor se descarta) el ciclo termina, incluso a mitad de la espera.
+    ///
+    /// Antes de retornar se esperan las tareas de los sensores (que vuelven a
+    /// quedar registrados en el controlador), se entregan las lecturas pendientes
+    /// y las que devuelva [`Sensor::flush`], y se llama a [`Communicator::flush`]
+    /// y a [`Actuator::shutdown`] de cada actuador.
+    ///
+    /// # Ejemplo
+    /// La señal interrumpe la espera entre lecturas: `run` retorna mucho antes
+    /// de que venza el intervalo.
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::traits::actuator::{ActuatorError, ActuatorResult, ActuatorState};
+    /// use iot_framework::core::traits::communicator::CommunicatorError;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::{Actuator, Communicator, SensorReading};
+    ///
+    /// /// Comunicador que anota si se vació.
+    /// struct Flushed(Arc<AtomicBool>);
+    /// impl Communicator for Flushed {
+    ///     type Command = SensorReading;
+    ///     type Response = ();
+    ///     fn send(&mut self, _reading: SensorReading) -> Result<(), CommunicatorError> {
+    ///         Ok(())
+    ///     }
+    ///     fn flush(&mut self) -> Result<(), CommunicatorError> {
+    ///         self.0.store(true, Ordering::SeqCst);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// /// Actuador que anota si se apagó.
+    /// struct Stopped(Arc<AtomicBool>);
+    /// impl Actuator for Stopped {
+    ///     type Command = SensorReading;
+    ///     fn execute(&mut self, _reading: SensorReading) -> Result<ActuatorResult, ActuatorError> {
+    ///         Ok(ActuatorResult::new(ActuatorState::Unknown))
+    ///     }
+    ///     fn shutdown(&mut self) -> Result<(), ActuatorError> {
+    ///         self.0.store(true, Ordering::SeqCst);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let interval = Duration::from_secs(60);
+    /// let (flushed, stopped) = (Arc::new(AtomicBool::new(false)), Arc::new(AtomicBool::new(false)));
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(Flushed(flushed.clone())))
+    ///     .with_interval(interval)
+    ///     .add_sensor("contador", Box::new(CounterSensor::new()))
+    ///     .add_actuator(Box::new(Stopped(stopped.clone())))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// let handle = tokio::spawn(async move { runtime.run(rx).await });
+    /// // Ya se tomó la primera lectura y el runtime espera a la siguiente.
+    /// tokio::time::sleep(Duration::from_secs(1)).await;
+    /// tx.send(true).unwrap();
+    ///
+    /// tokio::time::timeout(interval, handle).await.expect("run no se detuvo").unwrap();
+    /// assert!(flushed.load(Ordering::SeqCst));
+    /// assert!(stopped.load(Ordering::SeqCst));
+    /// # }
+    /// ```
+    pub async fn run(&mut self, mut shutdown: watch::Receiver<bool>) {
+        info!(
+            sensors = self.sensors.len(),
+            actuators = self.actuators.as_ref().map_or(0, Vec::len),
+            "iniciando runtime"
+        );
+        let mut updates = self.updates.take();
         loop {
-            // Lee datos de cada sensor
-            for s in self.sensors.iter_mut() {
-                match s.read() {
-                    // Si no hay errores, envía los datos al comunicador
-                    Ok(output) => {
-                        if let Err(e) = self.communicator.send(output.clone()) {
-                            eprintln!("Error enviando dato: {:?}", e);
-                        }
-                        // Si hay actuadores, ejecútanlos
-                        if let Some(acts) = &mut self.actuators {
-                            for a in acts.iter_mut() {
-                                if let Err(e) = a.execute(output.clone()) {
-                                    eprintln!("Error actuando: {:?}", e);
-                                }
-                            }
+            let pending = self.run_session(&mut shutdown, updates.as_mut(), None).await;
+            if pending.is_empty() {
+                break;
+            }
+            for update in pending {
+                self.apply_update(update).await;
+            }
+        }
+        self.updates = updates;
+        self.shutdown().await;
+        info!("runtime detenido");
+    }
+
+    /// Lee y publica `cycles` veces y retorna, sin esperar una señal de apagado.
+    ///
+    /// Pensado para ejecuciones puntuales (una tarea cron que toma una muestra
+    /// y termina) y para pruebas deterministas. Cada grupo de sensores (ver
+    /// [`RuntimeController`]) completa `cycles` ciclos a su propia cadencia; al
+    /// terminar todos se entregan sus lecturas y, como en [`run`](Self::run), las
+    /// acumuladas en los sensores, se vacía el comunicador y se apagan los
+    /// actuadores. Las
+    /// [`RuntimeUpdate`] recibidas mientras tanto se aplican en la siguiente
+    /// llamada a `run`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::network::null::RecordingCommunicator;
+    /// use iot_framework::SensorOutput;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let sent = RecordingCommunicator::new();
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(sent.clone()))
+    ///     .with_interval(Duration::from_millis(1))
+    ///     .add_sensor("contador", Box::new(CounterSensor::new()))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// runtime.run_for_cycles(5).await;
+    /// assert_eq!(sent.values("contador"), (0..5).map(SensorOutput::Int).collect::<Vec<_>>());
+    ///
+    /// // Los sensores siguen registrados: otra ejecución continúa la cuenta.
+    /// runtime.run_for_cycles(2).await;
+    /// assert_eq!(sent.readings().len(), 7);
+    /// # }
+    /// ```
+    pub async fn run_for_cycles(&mut self, cycles: u64) {
+        info!(sensors = self.sensors.len(), cycles, "iniciando runtime por ciclos");
+        // El emisor se conserva: solo se termina al completar los ciclos.
+        let (_stop, mut shutdown) = watch::channel(false);
+        if cycles > 0 {
+            self.run_session(&mut shutdown, None, Some(cycles)).await;
+        }
+        self.shutdown().await;
+        info!("runtime detenido");
+    }
+
+    /// Ejecuta los sensores actuales hasta la señal de apagado o hasta recibir
+    /// una [`RuntimeUpdate`]. Devuelve las actualizaciones pendientes (vacío si
+    /// el runtime debe terminar).
+    ///
+    /// Con `max_cycles`, cada grupo de sensores se detiene tras ese número de
+    /// ciclos y la sesión termina cuando todos se han detenido.
+    async fn run_session(
+        &mut self,
+        shutdown: &mut watch::Receiver<bool>,
+        mut updates: Option<&mut mpsc::UnboundedReceiver<RuntimeUpdate>>,
+        max_cycles: Option<u64>,
+    ) -> Vec<RuntimeUpdate> {
+        let (tx, mut rx) = batch_channel(self.backpressure, self.channel_capacity);
+        // Señal propia de la sesión: se activa tanto al apagar como al recargar.
+        let (stop_tx, stop_rx) = watch::channel(false);
+        let groups = group_by_schedule(self.sensors.drain(..), self.interval);
+        debug!(groups = groups.len(), "lanzando tareas de sensores");
+        let tasks: Vec<_> = groups
+            .into_iter()
+            .map(|((schedule, start_offset), slots)| {
+                let metrics = Arc::clone(&self.metrics);
+                let cycle = Cycle {
+                    schedule,
+                    start_offset,
+                    read_timeout: self.read_timeout,
+                    range_check: self.range_check,
+                    publish_errors: self.publish_errors,
+                    max_cycles,
+                };
+                let sinks = Sinks {
+                    readings: tx.clone(),
+                    metrics,
+                    cache: self.reading_cache.clone(),
+                };
+                tokio::spawn(poll_sensors(slots, cycle, sinks, stop_rx.clone()))
+            })
+            .collect();
+        drop(tx);
+
+        let mut pending = Vec::new();
+        let mut commands = self.commands.take();
+        // Cierto mientras alguna tarea de sensores puede entregar lotes.
+        let mut open = true;
+        let mut receive_tick = tokio::time::interval(RECEIVE_POLL_INTERVAL);
+        receive_tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        while !*shutdown.borrow() {
+            tokio::select! {
+                batch = rx.recv(&self.metrics), if open => match batch {
+                    Some(batch) => self.dispatch_batch(batch).await,
+                    // Todas las tareas agotaron sus ciclos.
+                    None if max_cycles.is_some() => break,
+                    None => open = false,
+                },
+                _ = receive_tick.tick() => self.poll_commands().await,
+                Some(remote) = next_command(&mut commands) => {
+                    let result = self.route_command(remote.command).await;
+                    // Quien la envió puede haber dejado de esperar la respuesta.
+                    let _ = remote.reply.send(result);
+                }
+                Some(update) = next_update(&mut updates) => {
+                    pending.push(update);
+                    break;
+                }
+                changed = shutdown.changed() => {
+                    // Si el emisor desaparece no habrá más señales: se apaga igual.
+                    if changed.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        self.commands = commands;
+        let _ = stop_tx.send(true);
+
+        // Se recuperan los sensores para aplicar cambios o volver a ejecutarse.
+        // Mientras tanto se siguen repartiendo lotes: con el canal lleno, una
+        // tarea puede estar esperando sitio para entregar el último.
+        let mut joined = join_all(tasks);
+        let results = loop {
+            tokio::select! {
+                results = &mut joined => break results,
+                Some(batch) = rx.recv(&self.metrics) => self.dispatch_batch(batch).await,
+            }
+        };
+        for result in results {
+            match result {
+                Ok(slots) => self.sensors.extend(slots),
+                Err(e) => error!("Tarea de sensor terminó con error: {:?}", e),
+            }
+        }
+        while let Some(batch) = rx.try_recv(&self.metrics) {
+            self.dispatch_batch(batch).await;
+        }
+        if let Some(updates) = updates {
+            while let Ok(update) = updates.try_recv() {
+                pending.push(update);
+            }
+        }
+        pending
+    }
+
+    /// Aplica un cambio de configuración con las tareas de sensores detenidas.
+    async fn apply_update(&mut self, update: RuntimeUpdate) {
+        match update {
+            RuntimeUpdate::Interval(interval) => {
+                info!(interval_ms = interval.as_millis() as u64, "nuevo intervalo global");
+                self.interval = interval;
+            }
+            RuntimeUpdate::SensorInterval { id, interval } => {
+                match self.sensors.iter_mut().find(|slot| slot.id == id) {
+                    Some(slot) => {
+                        info!(sensor = %id, "nuevo intervalo de sensor");
+                        slot.schedule = interval.map(Schedule::EveryInterval);
+                    }
+                    None => warn!(sensor = %id, "Cambio de intervalo para un sensor desconocido"),
+                }
+            }
+            RuntimeUpdate::AddSensor { id, sensor, schedule } => {
+                info!(sensor = %id, "sensor añadido");
+                let mut slot = SensorSlot { id, sensor, schedule, timeout: None, start_offset: Duration::ZERO, seq: 0 };
+                match self.sensors.iter().position(|s| s.id == slot.id) {
+                    // La secuencia continúa para que el receptor no vea duplicados.
+                    Some(pos) => {
+                        slot.seq = self.sensors[pos].seq;
+                        self.sensors[pos] = slot
+                    }
+                    None => self.sensors.push(slot),
+                }
+            }
+            RuntimeUpdate::RemoveSensor(id) => {
+                info!(sensor = %id, "sensor retirado");
+                self.sensors.retain(|slot| slot.id != id);
+            }
+            RuntimeUpdate::ReplaceSensor { id, interval, timeout, build } => {
+                // El sensor anterior se libera antes de construir el nuevo, para
+                // que este pueda reclamar los mismos pines o buses.
+                let position = self.sensors.iter().position(|slot| slot.id == id);
+                // Se conservan la secuencia y el desfase del sensor anterior.
+                let (seq, start_offset) = position
+                    .map(|pos| self.sensors.remove(pos))
+                    .map_or((0, Duration::ZERO), |old| (old.seq, old.start_offset));
+                match build() {
+                    Ok(sensor) => {
+                        info!(sensor = %id, "sensor reconstruido");
+                        let slot = SensorSlot {
+                            id,
+                            sensor: Box::new(BlockingSensor::new(sensor)),
+                            schedule: interval.map(Schedule::EveryInterval),
+                            timeout,
+                            start_offset,
+                            seq,
+                        };
+                        match position {
+                            Some(pos) => self.sensors.insert(pos, slot),
+                            None => self.sensors.push(slot),
                         }
                     }
-                    Err(e) => eprintln!("Error leyendo sensores: {:?}", e),
+                    Err(e) => error!(sensor = %id, "No se pudo reconstruir el sensor: {}", e),
+                }
+            }
+            RuntimeUpdate::ReplaceActuators(build) => {
+                self.shutdown_actuators().await;
+                self.actuators = None;
+                match build() {
+                    Ok(actuators) => {
+                        info!(actuators = actuators.len(), "actuadores reconstruidos");
+                        let slots: Vec<_> = actuators
+                            .into_iter()
+                            .map(|(id, actuator)| ActuatorSlot {
+                                id,
+                                actuator: Box::new(BlockingActuator::new(actuator)),
+                            })
+                            .collect();
+                        self.actuators = if slots.is_empty() { None } else { Some(slots) };
+                    }
+                    Err(e) => error!("No se pudieron reconstruir los actuadores: {}", e),
                 }
             }
-            sleep(self.interval).await;
         }
     }
+
+    /// Devuelve un emisor para enviar [`RuntimeUpdate`]s al runtime mientras
+    /// [`run`](Self::run) está en curso (ver `ConfigWatcher`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio::time::{sleep_until, Instant};
+    /// use iot_framework::core::runtime::{RuntimeController, RuntimeUpdate};
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::{ConsoleCommunicator, SensorOutput};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_secs(5))
+    ///     .add_sensor("s", Box::new(MockSensor::cycling(vec![SensorOutput::Int(1)])))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let updates = runtime.update_sender();
+    /// let metrics = runtime.metrics_handle();
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// let start = Instant::now();
+    /// let running = tokio::spawn(async move {
+    ///     runtime.run(rx).await;
+    ///     runtime
+    /// });
+    ///
+    /// // Con 5 s de intervalo solo hay la lectura inicial.
+    /// sleep_until(start + Duration::from_millis(30)).await;
+    /// assert_eq!(metrics.snapshot().reads_ok, 1);
+    ///
+    /// // El intervalo nuevo se aplica al momento: lecturas a los 30, 50, 70 y 90 ms.
+    /// updates.send(RuntimeUpdate::Interval(Duration::from_millis(20))).unwrap();
+    /// sleep_until(start + Duration::from_millis(95)).await;
+    /// assert_eq!(metrics.snapshot().reads_ok, 5);
+    ///
+    /// tx.send(true).unwrap();
+    /// let runtime = running.await.unwrap();
+    /// assert_eq!(runtime.interval(), Duration::from_millis(20));
+    /// # }
+    /// ```
+    pub fn update_sender(&self) -> mpsc::UnboundedSender<RuntimeUpdate> {
+        self.update_tx.clone()
+    }
+
+    /// Devuelve un [`RuntimeHandle`] para añadir o retirar sensores mientras
+    /// [`run`](Self::run) está en curso.
+    pub fn handle(&self) -> RuntimeHandle {
+        RuntimeHandle {
+            updates: self.update_tx.clone(),
+            commands: self.command_tx.clone(),
+        }
+    }
+
+    /// Estado actual de cada actuador registrado, junto con su id (si lo tiene),
+    /// en orden de registro.
+    pub fn actuator_states(&self) -> Vec<(Option<String>, ActuatorState)> {
+        self.actuators
+            .iter()
+            .flatten()
+            .map(|slot| (slot.id.clone(), slot.actuator.state()))
+            .collect()
+    }
+
+    /// Devuelve la caché con la última lectura válida de cada sensor.
+    ///
+    /// Es la misma caché que actualizan las tareas de sensores: puede guardarse
+    /// antes de llamar a [`run`](Self::run) y consultarse desde otras tareas o
+    /// hilos mientras el runtime está en marcha.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio::time::{sleep_until, Instant};
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::{ConsoleCommunicator, SensorOutput};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(10))
+    ///     .add_sensor("contador", Box::new(CounterSensor::new()))
+    ///     .build()
+    ///     .unwrap();
+    /// let cache = runtime.reading_cache();
+    /// assert!(cache.get("contador").is_none());
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// let start = Instant::now();
+    /// let running = tokio::spawn(async move {
+    ///     runtime.run(rx).await;
+    ///     runtime
+    /// });
+    ///
+    /// // Lecturas a los 0, 10 y 20 ms: la caché guarda la última.
+    /// sleep_until(start + Duration::from_millis(25)).await;
+    /// assert_eq!(cache.get("contador").unwrap().value, SensorOutput::Int(2));
+    /// sleep_until(start + Duration::from_millis(45)).await;
+    /// assert_eq!(cache.get("contador").unwrap().value, SensorOutput::Int(4));
+    ///
+    /// // Con el runtime parado el valor ya no cambia.
+    /// tx.send(true).unwrap();
+    /// let runtime = running.await.unwrap();
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// assert_eq!(cache.get("contador").unwrap().value, SensorOutput::Int(4));
+    /// assert_eq!(runtime.metrics().reads_ok, 5);
+    /// # }
+    /// ```
+    pub fn reading_cache(&self) -> ReadingCache {
+        self.reading_cache.clone()
+    }
+
+    /// Intervalo global actual.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Ids de los sensores registrados, en orden de registro.
+    pub fn sensor_ids(&self) -> Vec<String> {
+        self.sensors.iter().map(|slot| slot.id.clone()).collect()
+    }
+
+    /// Devuelve una copia de las métricas acumuladas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::mock::{FailingSensor, MockSensor};
+    /// use iot_framework::{ConsoleCommunicator, SensorOutput};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(20))
+    ///     .add_sensor("ok", Box::new(MockSensor::cycling(vec![SensorOutput::Int(1)])))
+    ///     .add_sensor("roto", Box::new(FailingSensor::always()))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_millis(70)).await;
+    ///     tx.send(true).unwrap();
+    /// });
+    /// runtime.run(rx).await;
+    ///
+    /// // En 70 ms cada sensor lee cuatro veces: a los 0, 20, 40 y 60 ms.
+    /// let metrics = runtime.metrics();
+    /// assert_eq!(metrics.reads_ok, 4);
+    /// assert_eq!(metrics.reads_err, 4);
+    /// assert_eq!(metrics.sends_ok, 4);
+    /// assert_eq!(metrics.sends_err, 0);
+    /// # }
+    /// ```
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    /// Devuelve los contadores compartidos, para consultarlos mientras
+    /// [`run`](Self::run) está en curso (p. ej. desde un endpoint de métricas).
+    pub fn metrics_handle(&self) -> Arc<RuntimeMetrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Recoge las órdenes pendientes del comunicador y las entrega a sus actuadores.
+    async fn poll_commands(&mut self) {
+        loop {
+            match self.communicator.receive() {
+                Ok(Some(command)) => {
+                    // `route_command` ya registra los errores.
+                    let _ = self.route_command(command).await;
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    error!("Error recibiendo orden: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Ejecuta `command` en cada actuador registrado con su `actuator_id` y
+    /// publica por el comunicador la confirmación de cada uno (ver
+    /// [`ActuatorResult::into_reading`]).
+    ///
+    /// Si varios actuadores comparten id, la orden llega a todos aunque alguno
+    /// falle; se devuelve el último error o, si ninguno falla, el último resultado.
+    async fn route_command(&mut self, command: ActuatorCommand) -> Result<ActuatorResult, CommandError> {
+        let targets: Vec<_> = self
+            .actuators
+            .iter_mut()
+            .flatten()
+            .filter(|slot| slot.id.as_deref() == Some(command.actuator_id.as_str()))
+            .collect();
+        if targets.is_empty() {
+            warn!(actuator = %command.actuator_id, "Orden para un actuador desconocido");
+            return Err(CommandError::UnknownActuator(command.actuator_id));
+        }
+        debug!(actuator = %command.actuator_id, value = ?command.value, "orden remota");
+        let mut outcome = Err(CommandError::UnknownActuator(command.actuator_id.clone()));
+        let mut failed = false;
+        for slot in targets {
+            match slot.actuator.execute(command.clone().into()).await {
+                Ok(result) => {
+                    let ack = result.clone().into_reading(&command.actuator_id);
+                    if let Err(e) = self.communicator.send(ack) {
+                        error!(actuator = %command.actuator_id, "Error enviando confirmación: {}", e);
+                    }
+                    if !failed {
+                        outcome = Ok(result);
+                    }
+                }
+                Err(e) => {
+                    error!(actuator = %command.actuator_id, "Error ejecutando orden: {:?}", e);
+                    let ActuatorError::ExecuteError(message) = e;
+                    outcome = Err(CommandError::Execute(message));
+                    failed = true;
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Reparte, en orden, las lecturas de un ciclo.
+    async fn dispatch_batch(&mut self, batch: Vec<SensorReading>) {
+        for reading in batch {
+            self.dispatch(reading).await;
+        }
+    }
+
+    /// Envía una lectura al comunicador, la persiste si hay almacenamiento
+    /// y, si existen, la pasa a los actuadores.
+    ///
+    /// Las lecturas [`Quality::Bad`] solo se envían y persisten: no cuentan como
+    /// último valor del sensor ni llegan a los actuadores.
+    async fn dispatch(&mut self, reading: SensorReading) {
+        debug!(sensor = %reading.sensor_id, value = ?reading.value, quality = ?reading.quality, "lectura");
+        let bad = reading.quality == Quality::Bad;
+        if !bad {
+            self.metrics.record_value(&reading);
+        }
+        match self.communicator.send(reading.clone()) {
+            Ok(()) => self.metrics.record_send(true),
+            Err(e) => {
+                self.metrics.record_send(false);
+                let kind = if e.is_transient() { "transitorio" } else { "permanente" };
+                error!(sensor = %reading.sensor_id, "Error {} enviando dato: {}", kind, e);
+            }
+        }
+        for alert in self.alerts.check(&reading) {
+            warn!(sensor = %reading.sensor_id, severity = %alert.severity, active = alert.active, "{}", alert);
+            if let Err(e) = self.communicator.send_alert(alert) {
+                error!(sensor = %reading.sensor_id, "Error enviando alerta: {}", e);
+            }
+        }
+        if let Some(storage) = &mut self.storage {
+            if let Err(e) = storage.persist(&reading) {
+                error!(sensor = %reading.sensor_id, "Error guardando dato: {:?}", e);
+            }
+        }
+        // Si hay actuadores, ejecuta los que reciben lecturas de este sensor
+        let routes = &self.routes;
+        if let Some(acts) = self.actuators.as_mut().filter(|_| !bad) {
+            for slot in acts.iter_mut().filter(|slot| routes.accepts(slot.id.as_deref(), &reading.sensor_id)) {
+                if let Err(e) = slot.actuator.execute(reading.clone()).await {
+                    error!(sensor = %reading.sensor_id, "Error actuando: {:?}", e);
+                }
+            }
+        }
+    }
+
+    /// Libera los recursos del runtime al terminar el ciclo principal.
+    ///
+    /// Antes de vaciar el comunicador publica lo que los sensores tengan
+    /// acumulado (ver [`Sensor::flush`]).
+    async fn shutdown(&mut self) {
+        let mut pending = Vec::new();
+        for slot in &mut self.sensors {
+            if let Some(output) = slot.sensor.flush() {
+                slot.seq += 1;
+                let reading = SensorReading::new(slot.id.clone(), output)
+                    .with_unit(slot.sensor.unit())
+                    .with_seq(slot.seq);
+                self.reading_cache.insert(reading.clone());
+                pending.push(reading);
+            }
+        }
+        self.dispatch_batch(pending).await;
+        if let Err(e) = self.communicator.flush() {
+            error!("Error vaciando comunicador: {:?}", e);
+        }
+        self.shutdown_actuators().await;
+    }
+
+    /// Lleva cada actuador a su estado seguro.
+    async fn shutdown_actuators(&mut self) {
+        if let Some(acts) = &mut self.actuators {
+            for slot in acts.iter_mut() {
+                if let Err(e) = slot.actuator.shutdown().await {
+                    error!("Error apagando actuador: {:?}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Intervalo por defecto cuando el builder no recibe `with_interval`.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Errores al construir un [`RuntimeController`].
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+    /// No se definió un comunicador con `with_communicator`.
+    #[error("falta el comunicador (ver `with_communicator`)")]
+    MissingCommunicator,
+}
+
+/// Qué hacen las tareas de sensores cuando el canal hacia el ciclo principal
+/// está lleno (el comunicador es más lento que los sensores, p. ej. con la red
+/// congestionada).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backpressure {
+    /// La tarea espera a que haya sitio: no se pierde ninguna lectura, pero
+    /// los sensores del grupo pierden su cadencia mientras dure la congestión.
+    #[default]
+    Block,
+    /// La tarea nunca espera: se descarta el lote pendiente más antiguo y se
+    /// cuenta en [`MetricsSnapshot::batches_dropped`]. Los sensores conservan su
+    /// cadencia y se publican siempre los datos más recientes.
+    DropOldest,
+}
+
+/// Builder de [`RuntimeController`].
+///
+/// Permite registrar sensores y actuadores de forma encadenada (y condicional)
+/// en lugar de pasar todos los componentes de forma posicional.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::devices::actuators::dummy::DummyActuator;
+/// use iot_framework::ConsoleCommunicator;
+///
+/// let runtime = RuntimeController::builder()
+///     .add_actuator(Box::new(DummyActuator::new()))
+///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+///     .with_interval(Duration::from_secs(2))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct RuntimeControllerBuilder {
+    sensors: Vec<SensorSlot>,
+    actuators: Vec<ActuatorSlot>,
+    routes: ActuatorRoutes,
+    communicator: Option<BoxedCommunicator>,
+    storage: Option<BoxedStorage>,
+    interval: Option<Duration>,
+    read_timeout: Option<Duration>,
+    range_check: bool,
+    publish_errors: bool,
+    backpressure: Backpressure,
+    channel_capacity: Option<usize>,
+    reading_cache: Option<ReadingCache>,
+    alerts: AlertMonitor,
+}
+
+impl RuntimeControllerBuilder {
+    /// Registra un sensor síncrono que usa el intervalo global.
+    pub fn add_sensor(self, id: impl Into<String>, sensor: BoxedSensor) -> Self {
+        self.push_sensor(id.into(), Box::new(BlockingSensor::new(sensor)), None)
+    }
+
+    /// Registra un sensor síncrono con su propio intervalo.
+    ///
+    /// # Ejemplo
+    /// Dos sensores, cada 100 ms y cada 250 ms, durante 1,05 s: ambos leen al
+    /// arrancar y luego cada uno a su ritmo.
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::traits::communicator::CommunicatorError;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::{Communicator, SensorReading};
+    ///
+    /// /// Comunicador que cuenta las lecturas de cada sensor.
+    /// struct Tally(Arc<Mutex<HashMap<String, u32>>>);
+    /// impl Communicator for Tally {
+    ///     type Command = SensorReading;
+    ///     type Response = ();
+    ///     fn send(&mut self, reading: SensorReading) -> Result<(), CommunicatorError> {
+    ///         *self.0.lock().unwrap().entry(reading.sensor_id).or_default() += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let counts = Arc::new(Mutex::new(HashMap::new()));
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(Tally(counts.clone())))
+    ///     .add_sensor_with_interval("rapido", Box::new(CounterSensor::new()), Duration::from_millis(100))
+    ///     .add_sensor_with_interval("lento", Box::new(CounterSensor::new()), Duration::from_millis(250))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// let stop = tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_millis(1050)).await;
+    ///     tx.send(true).unwrap();
+    /// });
+    /// runtime.run(rx).await;
+    /// stop.await.unwrap();
+    ///
+    /// let counts = counts.lock().unwrap();
+    /// assert_eq!(counts["rapido"], 11); // 0, 100, …, 1000 ms
+    /// assert_eq!(counts["lento"], 5); // 0, 250, 500, 750, 1000 ms
+    /// # }
+    /// ```
+    pub fn add_sensor_with_interval(
+        self,
+        id: impl Into<String>,
+        sensor: BoxedSensor,
+        interval: Duration,
+    ) -> Self {
+        let schedule = Schedule::EveryInterval(interval);
+        self.push_sensor(id.into(), Box::new(BlockingSensor::new(sensor)), Some(schedule))
+    }
+
+    /// Registra un sensor síncrono con su propia [`Schedule`], p. ej. una
+    /// expresión cron para leerlo al comienzo de cada minuto.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// # #[cfg(feature = "cron")] {
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::schedule::Schedule;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::ConsoleCommunicator;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// // Al comienzo de cada minuto, alineado con el reloj del sistema (ver
+    /// // `Schedule::next_after` para el cálculo de cada instante).
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .add_sensor_with_schedule("pulso", Box::new(CounterSensor::new()), Schedule::cron("0 * * * * *").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (_tx, rx) = tokio::sync::watch::channel(false);
+    /// runtime.run(rx).await;
+    /// # });
+    /// # }
+    /// ```
+    pub fn add_sensor_with_schedule(
+        self,
+        id: impl Into<String>,
+        sensor: BoxedSensor,
+        schedule: Schedule,
+    ) -> Self {
+        self.push_sensor(id.into(), Box::new(BlockingSensor::new(sensor)), Some(schedule))
+    }
+
+    /// Registra un sensor asíncrono; `interval` en `None` usa el intervalo global.
+    pub fn add_async_sensor(
+        self,
+        id: impl Into<String>,
+        sensor: BoxedAsyncSensor,
+        interval: Option<Duration>,
+    ) -> Self {
+        self.push_sensor(id.into(), sensor, interval.map(Schedule::EveryInterval))
+    }
+
+    /// Registra un actuador síncrono; cada orden se ejecuta en una tarea
+    /// bloqueante (ver [`BlockingActuator`]).
+    pub fn add_actuator(self, actuator: BoxedActuator) -> Self {
+        self.add_async_actuator(Box::new(BlockingActuator::new(actuator)))
+    }
+
+    /// Registra un actuador con un id al que pueden dirigirse órdenes remotas
+    /// ([`ActuatorCommand::actuator_id`]).
+    pub fn add_actuator_with_id(self, id: impl Into<String>, actuator: BoxedActuator) -> Self {
+        self.add_async_actuator_with_id(id, Box::new(BlockingActuator::new(actuator)))
+    }
+
+    /// Registra un actuador asíncrono; el runtime espera a que termine cada
+    /// orden antes de repartir la lectura siguiente.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use async_trait::async_trait;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::traits::actuator::{ActuatorError, ActuatorResult, ActuatorState};
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::{AsyncActuator, ConsoleCommunicator, SensorOutput, SensorReading};
+    ///
+    /// /// Simula un webhook: la orden solo se registra tras esperar la "respuesta".
+    /// struct Webhook(Arc<Mutex<Vec<String>>>);
+    /// #[async_trait]
+    /// impl AsyncActuator for Webhook {
+    ///     type Command = SensorReading;
+    ///     async fn execute(&mut self, command: SensorReading) -> Result<ActuatorResult, ActuatorError> {
+    ///         tokio::time::sleep(Duration::from_millis(5)).await;
+    ///         self.0.lock().unwrap().push(command.sensor_id);
+    ///         Ok(ActuatorResult::new(ActuatorState::Unknown))
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let sent = Arc::new(Mutex::new(Vec::new()));
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(20))
+    ///     .add_sensor("temp", Box::new(MockSensor::cycling(vec![SensorOutput::Float(21.5)])))
+    ///     .add_async_actuator(Box::new(Webhook(Arc::clone(&sent))))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_millis(50)).await;
+    ///     tx.send(true).unwrap();
+    /// });
+    /// runtime.run(rx).await;
+    ///
+    /// // Lecturas a los 0, 20 y 40 ms; cada orden termina antes de la siguiente.
+    /// assert_eq!(*sent.lock().unwrap(), ["temp"; 3]);
+    /// # }
+    /// ```
+    pub fn add_async_actuator(mut self, actuator: BoxedAsyncActuator) -> Self {
+        self.actuators.push(ActuatorSlot { id: None, actuator });
+        self
+    }
+
+    /// Registra un actuador asíncrono con un id para órdenes remotas (ver
+    /// [`add_actuator_with_id`](Self::add_actuator_with_id)).
+    pub fn add_async_actuator_with_id(mut self, id: impl Into<String>, actuator: BoxedAsyncActuator) -> Self {
+        self.actuators.push(ActuatorSlot {
+            id: Some(id.into()),
+            actuator,
+        });
+        self
+    }
+
+    /// Dirige las lecturas del sensor `sensor_id` al actuador `actuator_id`.
+    ///
+    /// Por defecto cada actuador recibe las lecturas de todos los sensores. En
+    /// cuanto un actuador tiene una ruta solo recibe las de los sensores
+    /// enrutados hacia él, así no necesita filtrarlas por su cuenta: el relé
+    /// del ventilador solo ve la temperatura y la bomba solo la humedad del
+    /// suelo. Puede llamarse varias veces para un mismo actuador o sensor. Las
+    /// órdenes remotas (`ActuatorCommand`) no se ven afectadas, y las rutas a
+    /// ids que no existen no tienen efecto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::traits::actuator::{ActuatorError, ActuatorResult, ActuatorState};
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::network::null::NullCommunicator;
+    /// use iot_framework::{Actuator, SensorOutput, SensorReading};
+    ///
+    /// /// Anota el sensor de cada lectura recibida.
+    /// #[derive(Clone, Default)]
+    /// struct Seen(Arc<Mutex<Vec<String>>>);
+    /// impl Actuator for Seen {
+    ///     type Command = SensorReading;
+    ///     fn execute(&mut self, reading: SensorReading) -> Result<ActuatorResult, ActuatorError> {
+    ///         self.0.lock().unwrap().push(reading.sensor_id);
+    ///         Ok(ActuatorResult::new(ActuatorState::Unknown))
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let (fan, pump, logger) = (Seen::default(), Seen::default(), Seen::default());
+    /// let value = |v| Box::new(MockSensor::cycling(vec![SensorOutput::Float(v)]));
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(NullCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(20))
+    ///     .add_sensor("temp", value(29.0))
+    ///     .add_sensor("suelo", value(12.0))
+    ///     .add_sensor("luz", value(300.0))
+    ///     .add_actuator_with_id("ventilador", Box::new(fan.clone()))
+    ///     .add_actuator_with_id("bomba", Box::new(pump.clone()))
+    ///     .add_actuator(Box::new(logger.clone()))
+    ///     .route("temp", "ventilador")
+    ///     .route("suelo", "bomba")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let (tx, rx) = tokio::sync::watch::channel(false);
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_millis(50)).await;
+    ///     tx.send(true).unwrap();
+    /// });
+    /// runtime.run(rx).await;
+    ///
+    /// // Tres ciclos de lecturas: a los 0, 20 y 40 ms.
+    /// let seen = |actuator: &Seen| actuator.0.lock().unwrap().clone();
+    /// assert_eq!(seen(&fan), ["temp"; 3]);
+    /// assert_eq!(seen(&pump), ["suelo"; 3]);
+    /// // Sin rutas, el resto de actuadores sigue recibiendo todas las lecturas.
+    /// assert_eq!(seen(&logger), ["temp", "suelo", "luz"].repeat(3));
+    /// # }
+    /// ```
+    pub fn route(mut self, sensor_id: impl Into<String>, actuator_id: impl Into<String>) -> Self {
+        self.routes.add(sensor_id.into(), actuator_id.into());
+        self
+    }
+
+    /// Define el comunicador (obligatorio).
+    pub fn with_communicator(mut self, communicator: BoxedCommunicator) -> Self {
+        self.communicator = Some(communicator);
+        self
+    }
+
+    /// Define un almacenamiento donde persistir cada lectura (opcional).
+    pub fn with_storage(mut self, storage: BoxedStorage) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Define el intervalo global; por defecto [`DEFAULT_INTERVAL`].
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Define el tiempo máximo de cada lectura de sensor.
+    ///
+    /// Una lectura que lo supera se abandona con `SensorError::Timeout`, se
+    /// cuenta en [`MetricsSnapshot::read_timeouts`] y el ciclo continúa. No se
+    /// aplica a los sensores con intervalo `Duration::ZERO`, cuya lectura espera
+    /// un evento, salvo que se les asigne uno propio con
+    /// [`with_sensor_timeout`](Self::with_sensor_timeout). Por defecto no hay límite.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use async_trait::async_trait;
+    /// use tokio::sync::mpsc;
+    /// use tokio::time::Instant;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::core::traits::sensor::SensorError;
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::{AsyncSensor, ConsoleCommunicator, SensorOutput};
+    ///
+    /// /// Simula un bus bloqueado: espera una respuesta que nunca llega.
+    /// struct Stuck(mpsc::Receiver<SensorOutput>);
+    /// #[async_trait]
+    /// impl AsyncSensor for Stuck {
+    ///     type Output = SensorOutput;
+    ///     async fn read(&mut self) -> Result<SensorOutput, SensorError> {
+    ///         self.0.recv().await.ok_or(SensorError::Disconnected("bus".into()))
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let (_bus, rx) = mpsc::channel(1);
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(10))
+    ///     .with_read_timeout(Duration::from_millis(50))
+    ///     .add_async_sensor("i2c", Box::new(Stuck(rx)), None)
+    ///     .add_sensor("ok", Box::new(MockSensor::cycling(vec![SensorOutput::Int(1)])))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let start = Instant::now();
+    /// runtime.run_for_cycles(3).await;
+    ///
+    /// // Cada ciclo espera el timeout y continúa: lecturas en 0, 60 y 120 ms.
+    /// let metrics = runtime.metrics();
+    /// assert_eq!(metrics.read_timeouts, 3);
+    /// assert_eq!(metrics.reads_ok, 3);
+    /// assert_eq!(start.elapsed(), Duration::from_millis(170));
+    /// # }
+    /// ```
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Activa la validación de rango: cada lectura numérica (`Int`/`Float`) se
+    /// compara con los `min`/`max` de [`Sensor::metadata`] y, si queda fuera, se
+    /// descarta con `SensorError::OutOfRange` en lugar de publicarse. Se cuenta
+    /// como fallo de lectura y en [`MetricsSnapshot::out_of_range`]. Los sensores
+    /// sin rango declarado no se ven afectados. Desactivada por defecto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::{ConsoleCommunicator, SensorKind, SensorMetadata, SensorOutput, Unit};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// // Una sonda desconectada que a veces devuelve -500 °C.
+    /// let probe = MockSensor::cycling(vec![SensorOutput::Float(21.5), SensorOutput::Float(-500.0)])
+    ///     .with_metadata(
+    ///         SensorMetadata::new("DS18B20", SensorKind::Numeric)
+    ///             .with_unit(Some(Unit::Celsius))
+    ///             .with_range(-55.0, 125.0),
+    ///     );
+    ///
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///     .with_interval(Duration::from_millis(10))
+    ///     .with_range_check()
+    ///     .add_sensor("sonda", Box::new(probe))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// runtime.run_for_cycles(10).await;
+    ///
+    /// // La mitad de las lecturas queda fuera de rango y se descarta.
+    /// let metrics = runtime.metrics();
+    /// assert_eq!(metrics.out_of_range, 5);
+    /// assert_eq!(metrics.reads_err, 5);
+    /// assert_eq!(metrics.reads_ok, 5);
+    /// // Solo las lecturas válidas llegan al comunicador.
+    /// assert_eq!(metrics.sends_ok, 5);
+    /// # }
+    /// ```
+    pub fn with_range_check(mut self) -> Self {
+        self.range_check = true;
+        self
+    }
+
+    /// Publica las lecturas fallidas en lugar de omitirlas, para que los
+    /// consumidores distingan un sensor averiado de uno sin datos nuevos.
+    ///
+    /// Cada error de lectura (incluidos los de tiempo máximo y de rango) se
+    /// envía y persiste como una [`SensorReading`] con [`Quality::Bad`] y el
+    /// mensaje del error (su `Display`) en un `SensorOutput::Text`; no llega a los actuadores ni a
+    /// la caché de lecturas. Las lecturas omitidas a propósito (`Warmup`,
+    /// `Suppressed`) no se publican. Desactivado por defecto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::mock::{FailingSensor, MockSensor};
+    /// use iot_framework::network::null::RecordingCommunicator;
+    /// use iot_framework::{Quality, SensorOutput};
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// for publish_errors in [false, true] {
+    ///     let sent = RecordingCommunicator::new();
+    ///     let mut builder = RuntimeController::builder()
+    ///         .with_communicator(Box::new(sent.clone()))
+    ///         .with_interval(Duration::from_millis(10))
+    ///         .add_sensor("ok", Box::new(MockSensor::cycling(vec![SensorOutput::Int(1)])))
+    ///         .add_sensor("roto", Box::new(FailingSensor::always()));
+    ///     if publish_errors {
+    ///         builder = builder.with_publish_errors();
+    ///     }
+    ///     let mut runtime = builder.build().unwrap();
+    ///
+    ///     runtime.run_for_cycles(3).await;
+    ///
+    ///     let sent: Vec<_> = sent
+    ///         .readings()
+    ///         .into_iter()
+    ///         .map(|r| (r.sensor_id, r.quality, r.value))
+    ///         .collect();
+    ///     let ok = ("ok".to_string(), Quality::Good, SensorOutput::Int(1));
+    ///     // El valor es el mensaje del error, no su representación de depuración.
+    ///     let message = SensorOutput::Text("error de lectura: fallo simulado".into());
+    ///     let roto = ("roto".to_string(), Quality::Bad, message);
+    ///     // Sin la opción el sensor roto simplemente no aparece.
+    ///     let cycle = if publish_errors { vec![ok, roto] } else { vec![ok] };
+    ///     assert_eq!(sent, cycle.iter().cycle().take(3 * cycle.len()).cloned().collect::<Vec<_>>());
+    ///     assert_eq!(runtime.metrics().reads_err, 3);
+    /// }
+    /// # }
+    /// ```
+    pub fn with_publish_errors(mut self) -> Self {
+        self.publish_errors = true;
+        self
+    }
+
+    /// Define la [`ReadingCache`] que el runtime actualiza con cada lectura
+    /// válida, justo después de leerla y antes de enviarla. Si no se define se
+    /// crea una vacía (ver [`RuntimeController::reading_cache`]).
+    ///
+    /// Hace falta cuando algún componente necesita la caché antes de construir
+    /// el runtime, como los sensores virtuales registrados en él (ver
+    /// [`VirtualSensor`](crate::devices::sensors::virtual_sensor::VirtualSensor)).
+    pub fn with_reading_cache(mut self, cache: ReadingCache) -> Self {
+        self.reading_cache = Some(cache);
+        self
+    }
+
+    /// Define qué hacen las tareas de sensores cuando el canal hacia el ciclo
+    /// principal está lleno porque el comunicador es más lento que los sensores
+    /// (por defecto [`Backpressure::Block`]).
+    ///
+    /// # Ejemplo
+    /// Un sensor que lee cada 10 ms y un actuador atascado que solo deja pasar
+    /// las lecturas que se le autorizan. Durante 95 ms el ciclo principal
+    /// espera con la primera lectura, y la cola tiene sitio para 4 lotes más:
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use async_trait::async_trait;
+    /// use tokio::sync::Semaphore;
+    /// use iot_framework::core::runtime::{Backpressure, RuntimeController};
+    /// use iot_framework::core::traits::actuator::{ActuatorError, ActuatorResult, ActuatorState};
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::{AsyncActuator, ConsoleCommunicator, SensorOutput, SensorReading};
+    ///
+    /// /// Consume una autorización por lectura y anota su valor.
+    /// struct Gate(Arc<Semaphore>, Arc<Mutex<Vec<i64>>>);
+    /// #[async_trait]
+    /// impl AsyncActuator for Gate {
+    ///     type Command = SensorReading;
+    ///     async fn execute(&mut self, reading: SensorReading) -> Result<ActuatorResult, ActuatorError> {
+    ///         self.0.acquire().await.unwrap().forget();
+    ///         let SensorOutput::Int(n) = reading.value else { unreachable!() };
+    ///         self.1.lock().unwrap().push(n);
+    ///         Ok(ActuatorResult::new(ActuatorState::Unknown))
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// // Política, lecturas que se dejan pasar y las que llegan al actuador.
+    /// let cases = [
+    ///     // El sensor espera a que haya sitio: no se pierde nada, pero solo
+    ///     // lee hasta llenar la cola (la lectura 5 espera su turno).
+    ///     (Backpressure::Block, vec![0, 1, 2, 3, 4, 5]),
+    ///     // El sensor mantiene su cadencia (0..=9) y la cola conserva las 4
+    ///     // más recientes: se descartan las lecturas 1 a 5.
+    ///     (Backpressure::DropOldest, vec![0, 6, 7, 8, 9]),
+    /// ];
+    /// for (policy, expected) in cases {
+    ///     let (permits, sent) = (Arc::new(Semaphore::new(0)), Arc::new(Mutex::new(Vec::new())));
+    ///     let mut runtime = RuntimeController::builder()
+    ///         .with_communicator(Box::new(ConsoleCommunicator::new()))
+    ///         .with_interval(Duration::from_millis(10))
+    ///         .with_channel_capacity(4)
+    ///         .with_backpressure(policy)
+    ///         .add_sensor("contador", Box::new(CounterSensor::new()))
+    ///         .add_async_actuator(Box::new(Gate(permits.clone(), sent.clone())))
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     let (tx, rx) = tokio::sync::watch::channel(false);
+    ///     let release = expected.len();
+    ///     tokio::spawn(async move {
+    ///         tokio::time::sleep(Duration::from_millis(95)).await;
+    ///         permits.add_permits(release);
+    ///         tx.send(true).unwrap();
+    ///     });
+    ///     runtime.run(rx).await;
+    ///
+    ///     let metrics = runtime.metrics();
+    ///     assert_eq!(*sent.lock().unwrap(), expected);
+    ///     assert_eq!(metrics.reads_ok, expected.len() as u64 + metrics.batches_dropped);
+    ///     match policy {
+    ///         Backpressure::Block => assert_eq!(metrics.batches_dropped, 0),
+    ///         Backpressure::DropOldest => assert_eq!(metrics.batches_dropped, 5),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn with_backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Define cuántos lotes pueden quedar pendientes entre las tareas de
+    /// sensores y el ciclo principal (por defecto [`DEFAULT_CHANNEL_CAPACITY`];
+    /// como mínimo 1). Con [`Backpressure::DropOldest`] la capacidad real se
+    /// redondea a la siguiente potencia de dos.
+    pub fn with_channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = Some(capacity.max(1));
+        self
+    }
+
+    /// Añade una regla de alarma evaluada con cada lectura válida.
+    ///
+    /// Cuando la regla pasa a violarse, o vuelve a cumplirse, el runtime
+    /// publica una [`Alert`](crate::core::alert::Alert) con
+    /// [`Communicator::send_alert`]; mientras el estado no cambia no se repite
+    /// (ver [`AlertMonitor`]).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::alert::{AlertRule, Comparison, Severity};
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::mock::MockSensor;
+    /// use iot_framework::network::null::RecordingCommunicator;
+    /// use iot_framework::SensorOutput;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let values = [25.0, 31.0, 32.0, 35.0, 29.0, 28.0].map(SensorOutput::Float).to_vec();
+    /// let sent = RecordingCommunicator::new();
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(sent.clone()))
+    ///     .with_interval(Duration::from_millis(5))
+    ///     .add_sensor("temp", Box::new(MockSensor::cycling(values.clone())))
+    ///     .with_alert_rule(AlertRule::new("temp", Comparison::Above, 30.0, Severity::Critical))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// runtime.run_for_cycles(6).await;
+    ///
+    /// // Seis lecturas, pero solo dos alertas: al superar el umbral y al volver.
+    /// assert_eq!(sent.values("temp"), values);
+    /// assert_eq!(
+    ///     sent.values("alert/temp"),
+    ///     [
+    ///         SensorOutput::Text("[critical] temp = 31 (> 30)".into()),
+    ///         SensorOutput::Text("[critical] temp = 29 (> 30) resuelta".into()),
+    ///     ]
+    /// );
+    /// assert_eq!(sent.readings().len(), 8);
+    /// # }
+    /// ```
+    pub fn with_alert_rule(mut self, rule: AlertRule) -> Self {
+        self.alerts.add(rule);
+        self
+    }
+
+    /// Define el tiempo máximo de lectura del sensor `id`, ya registrado, en
+    /// lugar del global. No tiene efecto si no hay un sensor con ese id.
+    pub fn with_sensor_timeout(mut self, id: &str, timeout: Duration) -> Self {
+        if let Some(slot) = self.sensors.iter_mut().find(|slot| slot.id == id) {
+            slot.timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Retrasa `offset` la primera lectura del sensor `id`, ya registrado, en
+    /// cada ejecución del runtime. Las siguientes mantienen su cadencia desde
+    /// esa primera lectura, así que sensores con el mismo intervalo y desfases
+    /// distintos se reparten a lo largo del intervalo en lugar de leer todos a
+    /// la vez y saturar el bus I2C o SPI compartido. No tiene efecto si no hay
+    /// un sensor con ese id.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio::time::{sleep_until, Instant};
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::network::null::RecordingCommunicator;
+    ///
+    /// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+    /// let sent = RecordingCommunicator::new();
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(sent.clone()))
+    ///     .with_interval(Duration::from_millis(200))
+    ///     .add_sensor("a", Box::new(CounterSensor::new()))
+    ///     .add_sensor("b", Box::new(CounterSensor::new()))
+    ///     .with_sensor_start_offset("b", Duration::from_millis(100))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let start = Instant::now();
+    /// let running = tokio::spawn(async move { runtime.run_for_cycles(2).await });
+    /// let ids = || sent.readings().into_iter().map(|r| r.sensor_id).collect::<Vec<_>>();
+    ///
+    /// // "a" lee al arrancar y "b" medio intervalo después, cada uno a su cadencia.
+    /// sleep_until(start + Duration::from_millis(50)).await;
+    /// assert_eq!(ids(), ["a"]);
+    /// sleep_until(start + Duration::from_millis(150)).await;
+    /// assert_eq!(ids(), ["a", "b"]);
+    /// sleep_until(start + Duration::from_millis(250)).await;
+    /// assert_eq!(ids(), ["a", "b", "a"]);
+    /// running.await.unwrap();
+    /// assert_eq!(ids(), ["a", "b", "a", "b"]);
+    /// assert_eq!(start.elapsed(), Duration::from_millis(300));
+    /// # }
+    /// ```
+    pub fn with_sensor_start_offset(mut self, id: &str, offset: Duration) -> Self {
+        if let Some(slot) = self.sensors.iter_mut().find(|slot| slot.id == id) {
+            slot.start_offset = offset;
+        }
+        self
+    }
+
+    /// Construye el `RuntimeController`.
+    ///
+    /// # Errores
+    /// - [`BuildError::MissingCommunicator`] si no se llamó a `with_communicator`.
+    pub fn build(self) -> Result<RuntimeController, BuildError> {
+        let communicator = self.communicator.ok_or(BuildError::MissingCommunicator)?;
+        let (update_tx, update_rx) = mpsc::unbounded_channel();
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        Ok(RuntimeController {
+            sensors: self.sensors,
+            actuators: if self.actuators.is_empty() { None } else { Some(self.actuators) },
+            routes: self.routes,
+            communicator,
+            storage: self.storage,
+            interval: self.interval.unwrap_or(DEFAULT_INTERVAL),
+            read_timeout: self.read_timeout,
+            range_check: self.range_check,
+            publish_errors: self.publish_errors,
+            backpressure: self.backpressure,
+            channel_capacity: self.channel_capacity.unwrap_or(DEFAULT_CHANNEL_CAPACITY),
+            metrics: Arc::default(),
+            reading_cache: self.reading_cache.unwrap_or_default(),
+            alerts: self.alerts,
+            updates: Some(update_rx),
+            update_tx,
+            commands: Some(command_rx),
+            command_tx,
+        })
+    }
+
+    fn push_sensor(
+        mut self,
+        id: String,
+        sensor: BoxedAsyncSensor,
+        schedule: Option<Schedule>,
+    ) -> Self {
+        self.sensors.push(SensorSlot { id, sensor, schedule, timeout: None, start_offset: Duration::ZERO, seq: 0 });
+        self
+    }
+}
+
+/// Agrupa los sensores por planificación efectiva y desfase inicial,
+/// conservando el orden de registro dentro de cada grupo. Cada sensor con
+/// intervalo `Duration::ZERO` forma su propio grupo: su lectura espera un evento
+/// y bloquearía al resto del ciclo.
+fn group_by_schedule(
+    slots: impl Iterator<Item = SensorSlot>,
+    default_interval: Duration,
+) -> Vec<((Schedule, Duration), Vec<SensorSlot>)> {
+    let mut groups: Vec<((Schedule, Duration), Vec<SensorSlot>)> = Vec::new();
+    for slot in slots {
+        let schedule = slot
+            .schedule
+            .clone()
+            .unwrap_or(Schedule::EveryInterval(default_interval));
+        let key = (schedule, slot.start_offset);
+        match groups
+            .iter_mut()
+            .find(|(k, _)| *k == key && !key.0.is_event_driven())
+        {
+            Some((_, group)) => group.push(slot),
+            None => groups.push((key, vec![slot])),
+        }
+    }
+    groups
+}
+
+/// Parámetros de un grupo de sensores.
+#[derive(Clone)]
+struct Cycle {
+    schedule: Schedule,
+    /// Retraso adicional de la primera lectura.
+    start_offset: Duration,
+    /// Tiempo máximo de lectura para los sensores sin uno propio.
+    read_timeout: Option<Duration>,
+    /// Si se validan las lecturas contra el rango de cada sensor.
+    range_check: bool,
+    /// Si las lecturas fallidas se entregan como [`Quality::Bad`].
+    publish_errors: bool,
+    /// Ciclos tras los que la tarea termina; `None` hasta el apagado.
+    max_cycles: Option<u64>,
+}
+
+/// Destinos de las lecturas de un grupo de sensores.
+struct Sinks {
+    /// Canal hacia el ciclo principal, un lote por ciclo.
+    readings: BatchSender,
+    metrics: Arc<RuntimeMetrics>,
+    /// Caché a actualizar con cada lectura válida.
+    cache: ReadingCache,
+}
+
+/// Crea el canal de lotes entre las tareas de sensores y el ciclo principal.
+fn batch_channel(backpressure: Backpressure, capacity: usize) -> (BatchSender, BatchReceiver) {
+    match backpressure {
+        Backpressure::Block => {
+            let (tx, rx) = mpsc::channel(capacity);
+            (BatchSender::Block(tx), BatchReceiver::Block(rx))
+        }
+        // Un `broadcast` sobrescribe los mensajes más antiguos y el receptor
+        // averigua cuántos se perdió (`Lagged`).
+        Backpressure::DropOldest => {
+            let (tx, rx) = broadcast::channel(capacity);
+            (BatchSender::DropOldest(tx), BatchReceiver::DropOldest(rx))
+        }
+    }
+}
+
+/// Extremo de las tareas de sensores de [`batch_channel`].
+#[derive(Clone)]
+enum BatchSender {
+    Block(mpsc::Sender<Vec<SensorReading>>),
+    DropOldest(broadcast::Sender<Vec<SensorReading>>),
+}
+
+impl BatchSender {
+    /// Entrega un lote según la política del canal. Devuelve `false` si el
+    /// ciclo principal ya no existe.
+    async fn send(&self, batch: Vec<SensorReading>) -> bool {
+        match self {
+            BatchSender::Block(tx) => tx.send(batch).await.is_ok(),
+            BatchSender::DropOldest(tx) => tx.send(batch).is_ok(),
+        }
+    }
+}
+
+/// Extremo del ciclo principal de [`batch_channel`]; cuenta en `metrics` los
+/// lotes descartados.
+enum BatchReceiver {
+    Block(mpsc::Receiver<Vec<SensorReading>>),
+    DropOldest(broadcast::Receiver<Vec<SensorReading>>),
+}
+
+impl BatchReceiver {
+    /// Espera el siguiente lote; `None` cuando terminaron todas las tareas.
+    async fn recv(&mut self, metrics: &RuntimeMetrics) -> Option<Vec<SensorReading>> {
+        match self {
+            BatchReceiver::Block(rx) => rx.recv().await,
+            BatchReceiver::DropOldest(rx) => loop {
+                match rx.recv().await {
+                    Ok(batch) => return Some(batch),
+                    Err(RecvError::Lagged(dropped)) => metrics.record_dropped(dropped),
+                    Err(RecvError::Closed) => return None,
+                }
+            },
+        }
+    }
+
+    /// Devuelve el siguiente lote pendiente sin esperar.
+    fn try_recv(&mut self, metrics: &RuntimeMetrics) -> Option<Vec<SensorReading>> {
+        match self {
+            BatchReceiver::Block(rx) => rx.try_recv().ok(),
+            BatchReceiver::DropOldest(rx) => loop {
+                match rx.try_recv() {
+                    Ok(batch) => return Some(batch),
+                    Err(TryRecvError::Lagged(dropped)) => metrics.record_dropped(dropped),
+                    Err(_) => return None,
+                }
+            },
+        }
+    }
+}
+
+/// Tarea de un grupo de sensores: en cada ciclo los lee concurrentemente,
+/// envía el lote por `readings` en el orden de registro y espera hasta la
+/// siguiente lectura según `schedule`, hasta recibir la señal de apagado o
+/// completar `max_cycles`. Devuelve los sensores al terminar.
+async fn poll_sensors(
+    mut slots: Vec<SensorSlot>,
+    Cycle { schedule, start_offset, read_timeout, range_check, publish_errors, max_cycles }: Cycle,
+    Sinks { readings: tx, metrics, cache }: Sinks,
+    mut shutdown: watch::Receiver<bool>,
+) -> Vec<SensorSlot> {
+    let units: Vec<_> = slots.iter().map(|slot| slot.sensor.unit()).collect();
+    let ranges: Vec<_> = slots
+        .iter()
+        .map(|slot| range_check.then(|| slot.sensor.metadata()))
+        .collect();
+    // Los sensores por eventos esperan indefinidamente a propósito.
+    let default_timeout = read_timeout.filter(|_| !schedule.is_event_driven());
+    // Instante previsto del ciclo actual. Con cron la primera lectura espera a
+    // la siguiente coincidencia, más el desfase del grupo.
+    let mut planned = SystemTime::now();
+    let Some(first) = schedule.first_delay(planned) else {
+        warn!(schedule = %schedule, "La planificación no tiene lecturas");
+        return slots;
+    };
+    let first = first + start_offset;
+    planned += first;
+    // Los intervalos se cuentan con el reloj monótono de tokio: no les afectan
+    // los saltos del reloj del sistema y pueden simularse en pruebas.
+    let mut tick = Instant::now() + first;
+    if !first.is_zero() {
+        tokio::select! {
+            _ = sleep(first) => {}
+            _ = shutdown.changed() => return slots,
+        }
+    }
+    let mut cycle: u64 = 0;
+    while !*shutdown.borrow() {
+        cycle += 1;
+        let span = debug_span!("cycle", schedule = %schedule, cycle);
+        // La lectura puede esperar indefinidamente (p. ej. un `InterruptSensor`
+        // aguardando un flanco), así que también se interrumpe con la señal de apagado.
+        let started = Instant::now();
+        let results = tokio::select! {
+            results = join_all(slots.iter_mut().map(|slot| {
+                let timeout = slot.timeout.or(default_timeout);
+                read_timestamped(slot, timeout)
+            }))
+            .instrument(span.clone()) => results,
+            _ = shutdown.changed() => break,
+        };
+        metrics.record_cycle(started.elapsed());
+        let entered = span.enter();
+        let mut batch = Vec::with_capacity(slots.len());
+        for (((slot, unit), range), (result, timestamp)) in
+            slots.iter_mut().zip(&units).zip(&ranges).zip(results)
+        {
+            let result = result.and_then(check_finite);
+            let result = match range {
+                Some(metadata) => result.and_then(|output| check_range(output, metadata)),
+                None => result,
+            };
+            let failure = match result {
+                Ok(output) => {
+                    metrics.record_read(true);
+                    slot.seq += 1;
+                    let reading = SensorReading {
+                        timestamp,
+                        ..SensorReading::new(slot.id.clone(), output)
+                            .with_unit(*unit)
+                            .with_seq(slot.seq)
+                    };
+                    cache.insert(reading.clone());
+                    batch.push(reading);
+                    continue;
+                }
+                // Lectura aún no válida: se omite sin contarla como fallo.
+                Err(SensorError::Warmup(remaining)) => {
+                    debug!(sensor = %slot.id, remaining_ms = remaining.as_millis() as u64, "sensor calentando");
+                    continue;
+                }
+                Err(SensorError::Suppressed) => continue,
+                Err(e) => e,
+            };
+            metrics.record_read(false);
+            match &failure {
+                SensorError::Timeout(after) => {
+                    metrics.record_timeout();
+                    error!(sensor = %slot.id, timeout_ms = after.as_millis() as u64, "Lectura de sensor sin respuesta")
+                }
+                SensorError::OutOfRange { value, min, max } => {
+                    metrics.record_out_of_range();
+                    warn!(sensor = %slot.id, value, ?min, ?max, "Lectura fuera de rango descartada")
+                }
+                SensorError::Disconnected(path) => {
+                    error!(sensor = %slot.id, "Sensor desconectado ({})", path)
+                }
+                SensorError::NoFix => warn!(sensor = %slot.id, "Receptor sin posición válida"),
+                SensorError::NonFinite(value) => {
+                    warn!(sensor = %slot.id, value, "Lectura no finita descartada")
+                }
+                // El fallo que abrió el circuito ya se registró.
+                SensorError::CircuitOpen(remaining) => {
+                    debug!(sensor = %slot.id, remaining_ms = remaining.as_millis() as u64, "circuito abierto")
+                }
+                e => error!(sensor = %slot.id, "Error leyendo sensor: {}", e),
+            }
+            if publish_errors {
+                slot.seq += 1;
+                batch.push(SensorReading {
+                    timestamp,
+                    ..SensorReading::new(slot.id.clone(), SensorOutput::Text(failure.to_string()))
+                        .with_quality(Quality::Bad)
+                        .with_seq(slot.seq)
+                });
+            }
+        }
+        drop(entered);
+        // El receptor solo desaparece cuando el runtime se detiene.
+        if !batch.is_empty() && !tx.send(batch).await {
+            break;
+        }
+        if max_cycles.is_some_and(|max| cycle >= max) {
+            break;
+        }
+        let delay = match &schedule {
+            // Si el ciclo se retrasó, la siguiente lectura se cuenta desde ahora.
+            Schedule::EveryInterval(interval) => {
+                tick = tick.max(Instant::now()) + *interval;
+                tick.saturating_duration_since(Instant::now())
+            }
+            // Si el temporizador despertó un poco antes de lo previsto, se cuenta
+            // desde el instante previsto para no repetir la misma coincidencia.
+            #[cfg(feature = "cron")]
+            Schedule::Cron(_) => {
+                let now = SystemTime::now();
+                let Some(next) = schedule.next_after(planned.max(now)) else {
+                    warn!(schedule = %schedule, "La planificación no tiene más lecturas");
+                    break;
+                };
+                planned = next;
+                next.duration_since(now).unwrap_or(Duration::ZERO)
+            }
+        };
+        tokio::select! {
+            _ = sleep(delay) => {}
+            changed = shutdown.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+    slots
+}
+
+/// Rechaza los `Float` y campos de `Map` que son `NaN` o infinitos.
+fn check_finite(output: SensorOutput) -> Result<SensorOutput, SensorError> {
+    let non_finite = match &output {
+        SensorOutput::Float(v) => Some(*v).filter(|v| !v.is_finite()),
+        SensorOutput::Map(fields) => fields.values().copied().find(|v| !v.is_finite()),
+        _ => None,
+    };
+    match non_finite {
+        Some(value) => Err(SensorError::NonFinite(value as f64)),
+        None => Ok(output),
+    }
+}
+
+/// Comprueba que una lectura numérica esté dentro del rango de `metadata`;
+/// los valores no numéricos pasan sin cambios.
+fn check_range(output: SensorOutput, metadata: &SensorMetadata) -> Result<SensorOutput, SensorError> {
+    let value = match output {
+        SensorOutput::Int(v) => v as f64,
+        SensorOutput::Float(v) => v as f64,
+        _ => return Ok(output),
+    };
+    if metadata.contains(value) {
+        Ok(output)
+    } else {
+        Err(SensorError::OutOfRange {
+            value,
+            min: metadata.min,
+            max: metadata.max,
+        })
+    }
+}
+
+/// Lee un sensor y registra el instante en que terminó su lectura, de modo que
+/// la marca de tiempo no dependa del sensor más lento del ciclo. Con `timeout`,
+/// una lectura que no termina a tiempo se abandona con `SensorError::Timeout`.
+async fn read_timestamped(
+    slot: &mut SensorSlot,
+    timeout: Option<Duration>,
+) -> (Result<SensorOutput, SensorError>, SystemTime) {
+    let result = match timeout {
+        Some(limit) => tokio::time::timeout(limit, slot.sensor.read())
+            .await
+            .unwrap_or(Err(SensorError::Timeout(limit))),
+        None => slot.sensor.read().await,
+    };
+    (result, SystemTime::now())
+}
+
+/// Espera la siguiente orden de [`RuntimeHandle::command`]; sin receptor,
+/// espera indefinidamente.
+async fn next_command(
+    commands: &mut Option<mpsc::UnboundedReceiver<RemoteCommand>>,
+) -> Option<RemoteCommand> {
+    match commands {
+        Some(commands) => commands.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Espera la siguiente actualización; sin receptor, espera indefinidamente.
+async fn next_update(
+    updates: &mut Option<&mut mpsc::UnboundedReceiver<RuntimeUpdate>>,
+) -> Option<RuntimeUpdate> {
+    match updates {
+        Some(updates) => updates.recv().await,
+        None => std::future::pending().await,
+    }
 }
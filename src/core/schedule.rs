@@ -0,0 +1,114 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+/// Cuándo lee el runtime un sensor.
+///
+/// Los intervalos fijos cuentan desde que arranca el runtime; con la feature
+/// `cron` también pueden usarse expresiones cron para alinear las lecturas con
+/// el reloj ("al comienzo de cada minuto") o limitarlas a ciertas horas.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::core::schedule::Schedule;
+///
+/// let every = Schedule::EveryInterval(Duration::from_secs(5));
+/// let now = UNIX_EPOCH + Duration::from_secs(1_700_000_030);
+/// assert_eq!(every.next_after(now), Some(now + Duration::from_secs(5)));
+///
+/// # #[cfg(feature = "cron")] {
+/// // Segundo 0 de cada minuto: 22:13:50 UTC → 22:14:00 UTC.
+/// let minutely = Schedule::cron("0 * * * * *").unwrap();
+/// assert_eq!(minutely.next_after(now), Some(UNIX_EPOCH + Duration::from_secs(1_700_000_040)));
+/// // Justo en el límite, la siguiente ejecución es la del minuto siguiente.
+/// let boundary = UNIX_EPOCH + Duration::from_secs(1_700_000_040);
+/// assert_eq!(minutely.next_after(boundary), Some(boundary + Duration::from_secs(60)));
+/// assert_eq!(minutely.delay_from(now), Some(Duration::from_secs(10)));
+///
+/// assert!(Schedule::cron("cada minuto").is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Una lectura inmediata y después una cada intervalo. `Duration::ZERO`
+    /// indica un sensor dirigido por eventos, cuyo `read()` espera al siguiente.
+    EveryInterval(Duration),
+    /// Expresión cron de seis o siete campos (`seg min hora día mes día_semana
+    /// [año]`) evaluada en la hora local. La primera lectura espera a la
+    /// siguiente coincidencia. Conviene crearla con [`Schedule::cron`], que
+    /// valida la expresión.
+    #[cfg(feature = "cron")]
+    Cron(String),
+}
+
+/// Expresión cron no válida.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("expresión cron no válida \"{expr}\": {reason}")]
+pub struct ScheduleError {
+    pub expr: String,
+    pub reason: String,
+}
+
+impl Schedule {
+    /// Crea un [`Schedule::Cron`] comprobando que la expresión sea válida.
+    #[cfg(feature = "cron")]
+    pub fn cron(expr: &str) -> Result<Self, ScheduleError> {
+        parse_cron(expr)?;
+        Ok(Schedule::Cron(expr.to_string()))
+    }
+
+    /// Indica si es un sensor dirigido por eventos (`EveryInterval(Duration::ZERO)`).
+    pub fn is_event_driven(&self) -> bool {
+        *self == Schedule::EveryInterval(Duration::ZERO)
+    }
+
+    /// Siguiente instante de lectura estrictamente posterior a `after`.
+    ///
+    /// Devuelve `None` si la expresión cron no es válida o ya no tiene más
+    /// coincidencias (p. ej. un año pasado).
+    pub fn next_after(&self, after: SystemTime) -> Option<SystemTime> {
+        match self {
+            Schedule::EveryInterval(interval) => Some(after + *interval),
+            #[cfg(feature = "cron")]
+            Schedule::Cron(expr) => {
+                let schedule = parse_cron(expr).ok()?;
+                let after: chrono::DateTime<chrono::Local> = after.into();
+                schedule.after(&after).next().map(SystemTime::from)
+            }
+        }
+    }
+
+    /// Tiempo desde `now` hasta la siguiente lectura (ver [`next_after`](Self::next_after)).
+    pub fn delay_from(&self, now: SystemTime) -> Option<Duration> {
+        let next = self.next_after(now)?;
+        Some(next.duration_since(now).unwrap_or(Duration::ZERO))
+    }
+
+    /// Espera antes de la primera lectura: ninguna con un intervalo, hasta la
+    /// siguiente coincidencia con una expresión cron.
+    pub(crate) fn first_delay(&self, now: SystemTime) -> Option<Duration> {
+        if matches!(self, Schedule::EveryInterval(_)) {
+            Some(Duration::ZERO)
+        } else {
+            self.delay_from(now)
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Schedule::EveryInterval(interval) => write!(f, "{}ms", interval.as_millis()),
+            #[cfg(feature = "cron")]
+            Schedule::Cron(expr) => f.write_str(expr),
+        }
+    }
+}
+
+#[cfg(feature = "cron")]
+fn parse_cron(expr: &str) -> Result<cron::Schedule, ScheduleError> {
+    expr.parse().map_err(|e: cron::error::Error| ScheduleError {
+        expr: expr.to_string(),
+        reason: e.to_string(),
+    })
+}
@@ -1,3 +1,13 @@
+use crate::core::{SensorOutput, SensorReading};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use thiserror::Error;
+
+/// Prefijo del id con que se publica la confirmación de una orden como
+/// lectura (`ack/<id del actuador>`).
+pub const ACK_ID_PREFIX: &str = "ack/";
+
 /// Representa un actuador en el sistema (motor, relé, LED, etc.).
 /// 
 /// Un actuador recibe **comandos** y los ejecuta para realizar una acción física.
@@ -13,14 +23,190 @@ pub trait Actuator {
 
     /// Envía un comando al actuador y ejecuta la acción física.
     ///
+    /// Devuelve el estado en que quedó el actuador (ver [`ActuatorResult`]),
+    /// que el runtime publica como confirmación de las órdenes remotas.
+    ///
+    /// # Errores
+    /// Devuelve `ActuatorError::ExecuteError` si el comando falla.
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError>;
+
+    /// Lleva el actuador a un estado seguro antes de apagar el sistema.
+    ///
+    /// El runtime lo invoca al recibir la señal de apagado. La implementación
+    /// por defecto no hace nada.
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        Ok(())
+    }
+
+    /// Estado actual del actuador, para inspeccionarlo o reportarlo.
+    ///
+    /// La implementación por defecto devuelve [`ActuatorState::Unknown`].
+    fn state(&self) -> ActuatorState {
+        ActuatorState::Unknown
+    }
+}
+
+impl<A: Actuator + ?Sized> Actuator for Box<A> {
+    type Command = A::Command;
+
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        (**self).execute(command)
+    }
+
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        (**self).shutdown()
+    }
+
+    fn state(&self) -> ActuatorState {
+        (**self).state()
+    }
+}
+
+/// Variante asíncrona de [`Actuator`] para actuadores cuya acción implica
+/// esperar E/S (un webhook HTTP, publicar una orden por MQTT...) sin bloquear
+/// el runtime de `tokio`.
+///
+/// Los actuadores síncronos existentes se adaptan con [`BlockingActuator`].
+#[async_trait]
+pub trait AsyncActuator: Send {
+    /// Tipo del comando que el actuador acepta.
+    type Command;
+
+    /// Envía un comando al actuador y espera a que termine la acción
+    /// (ver [`Actuator::execute`]).
+    ///
     /// # Errores
     /// Devuelve `ActuatorError::ExecuteError` si el comando falla.
-    fn execute(&mut self, command: Self::Command) -> Result<(), ActuatorError>;
+    async fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError>;
+
+    /// Lleva el actuador a un estado seguro (ver [`Actuator::shutdown`]).
+    async fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        Ok(())
+    }
+
+    /// Estado actual del actuador (ver [`Actuator::state`]).
+    fn state(&self) -> ActuatorState {
+        ActuatorState::Unknown
+    }
+}
+
+/// Adaptador que expone un [`Actuator`] síncrono como [`AsyncActuator`].
+///
+/// Cada orden se ejecuta en `tokio::task::spawn_blocking`, de modo que las
+/// escrituras bloqueantes (GPIO, PWM) no detienen el runtime. El actuador se
+/// comparte con la tarea bloqueante mediante `Arc<Mutex<_>>`.
+pub struct BlockingActuator<A> {
+    inner: Arc<Mutex<A>>,
+}
+
+impl<A> BlockingActuator<A> {
+    /// Envuelve un actuador síncrono.
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Ejecuta `f` sobre el actuador en una tarea bloqueante.
+    async fn run_blocking<F, T>(&self, f: F) -> Result<T, ActuatorError>
+    where
+        A: Send + 'static,
+        F: FnOnce(&mut A) -> Result<T, ActuatorError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let actuator = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || {
+            let mut actuator = actuator
+                .lock()
+                .map_err(|_| ActuatorError::ExecuteError("actuador no disponible".to_string()))?;
+            f(&mut actuator)
+        })
+        .await
+        .map_err(|e| ActuatorError::ExecuteError(format!("orden abortada: {}", e)))?
+    }
+}
+
+#[async_trait]
+impl<A> AsyncActuator for BlockingActuator<A>
+where
+    A: Actuator + Send + 'static,
+    A::Command: Send + 'static,
+{
+    type Command = A::Command;
+
+    async fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        self.run_blocking(move |actuator| actuator.execute(command)).await
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.run_blocking(|actuator| actuator.shutdown()).await
+    }
+
+    fn state(&self) -> ActuatorState {
+        self.inner.lock().map_or(ActuatorState::Unknown, |actuator| actuator.state())
+    }
+}
+
+/// Estado reportado por un actuador (ver [`Actuator::state`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActuatorState {
+    /// El actuador no puede consultar su estado o aún no recibió órdenes.
+    Unknown,
+    /// Estado actual con el mismo formato que las órdenes
+    /// (`Bool` para un relé, `Float` para un ciclo de trabajo...).
+    Known(SensorOutput),
+}
+
+/// Resultado de una orden ejecutada: el estado en que quedó el actuador y
+/// cuándo.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::actuator::{ActuatorResult, ActuatorState};
+/// use iot_framework::SensorOutput;
+///
+/// let result = ActuatorResult::new(ActuatorState::Known(SensorOutput::Bool(true)));
+/// let ack = result.clone().into_reading("riego");
+/// assert_eq!(ack.sensor_id, "ack/riego");
+/// assert_eq!(ack.value, SensorOutput::Bool(true));
+/// assert_eq!(ack.timestamp, result.timestamp);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActuatorResult {
+    /// Estado del actuador tras la orden.
+    pub state: ActuatorState,
+    /// Momento en que terminó la orden.
+    pub timestamp: SystemTime,
+}
+
+impl ActuatorResult {
+    /// Resultado con el estado `state` y el instante actual.
+    pub fn new(state: ActuatorState) -> Self {
+        Self {
+            state,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// Convierte el resultado en la lectura de confirmación del actuador
+    /// `actuator_id`: id `ack/<actuator_id>` y, como valor, el estado
+    /// resultante (`Text("desconocido")` si el actuador no lo reporta).
+    pub fn into_reading(self, actuator_id: &str) -> SensorReading {
+        let value = match self.state {
+            ActuatorState::Known(value) => value,
+            ActuatorState::Unknown => SensorOutput::Text("desconocido".to_string()),
+        };
+        SensorReading {
+            timestamp: self.timestamp,
+            ..SensorReading::new(format!("{}{}", ACK_ID_PREFIX, actuator_id), value)
+        }
+    }
 }
 
 /// Posibles errores que pueden ocurrir al operar un actuador.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum ActuatorError {
     /// Error al ejecutar el comando en el actuador.
+    #[error("error del actuador: {0}")]
     ExecuteError(String),
 }
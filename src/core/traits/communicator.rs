@@ -1,37 +1,144 @@
+use crate::core::alert::Alert;
+use crate::core::types::ActuatorCommand;
+use thiserror::Error;
+
 /// Define un medio de comunicación (ej. consola, MQTT, HTTP).
 ///
 /// Un comunicador envía y recibe mensajes de otros sistemas o de la nube.
+/// Los mensajes recibidos son órdenes para los actuadores ([`ActuatorCommand`]).
 ///
 /// # Associated Types
 /// - `Command`: Tipo de datos enviados.
-/// - `Response`: Tipo de datos recibidos.
+/// - `Response`: Tipo de la respuesta a cada envío.
 pub trait Communicator {
-    /// Tipo del comando que se envía.
+    /// Tipo del comando que se envía. Los comunicadores que usa el runtime
+    /// envían [`SensorReading`](crate::core::SensorReading) (valor junto con el
+    /// id del sensor, la marca de tiempo y la unidad) en lugar del
+    /// `SensorOutput` crudo.
     type Command;
 
-    /// Tipo de la respuesta que se recibe.
+    /// Tipo de la respuesta a cada envío.
     type Response;
 
     /// Envía un comando y devuelve una respuesta.
     ///
     /// # Errores
-    /// - `SendError` si falla el envío.
-    /// - `ExecuteError` si hay fallo interno en la ejecución.
+    /// - `Connection`, `Send` o `Timeout` si falla el envío (transitorios).
+    /// - `Serialization` si el comando no puede codificarse.
+    /// - `Execute` si hay fallo interno en la ejecución.
     fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError>;
 
-    /// Recibe datos del canal de comunicación.
+    /// Envía varios comandos de una vez.
+    ///
+    /// La implementación por defecto llama a `send` con cada uno y se detiene en
+    /// el primer error. Los comunicadores que pueden agrupar mensajes (por
+    /// ejemplo, en un único arreglo JSON) deben sobrescribirla.
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        for command in batch {
+            self.send(command)?;
+        }
+        Ok(())
+    }
+
+    /// Publica una [`Alert`] generada por las reglas de alarma del runtime.
+    ///
+    /// La implementación por defecto la envía con `send` como una lectura más
+    /// (id `alert/<sensor_id>`, ver [`Alert`]). Los comunicadores con un canal
+    /// propio para alertas (como el tópico `<topic>/alerts` de MQTT) deben
+    /// sobrescribirla, y los que envuelven a otro deben reenviarla sin filtrarla.
+    fn send_alert(&mut self, alert: Alert) -> Result<(), CommunicatorError>
+    where
+        Self::Command: From<Alert>,
+    {
+        self.send(alert.into()).map(|_| ())
+    }
+
+    /// Consulta, sin bloquear, si llegó una orden para algún actuador.
+    ///
+    /// El runtime la invoca periódicamente hasta obtener `Ok(None)` y entrega
+    /// cada orden a los actuadores con el id indicado. La implementación por
+    /// defecto devuelve siempre `Ok(None)` (comunicador solo de salida).
     ///
     /// # Errores
-    /// - `ExecuteError` si hay fallo en la lectura.
-    fn receive(&mut self) -> Result<Self::Response, CommunicatorError>;
+    /// - `Serialization` si el mensaje recibido no es una orden válida.
+    /// - `Execute` si hay fallo en la lectura.
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        Ok(None)
+    }
+
+    /// Vacía cualquier dato pendiente antes de apagar el sistema.
+    ///
+    /// El runtime lo invoca al recibir la señal de apagado. La implementación
+    /// por defecto no hace nada; los comunicadores con buffers internos deben
+    /// sobrescribirla.
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        Ok(())
+    }
 }
 
 /// Errores posibles de un comunicador.
-#[derive(Debug)]
+///
+/// Permiten al runtime distinguir fallos transitorios (reintentar más tarde)
+/// de fallos permanentes; ver [`CommunicatorError::is_transient`].
+#[derive(Debug, Error)]
 pub enum CommunicatorError {
+    /// No se pudo establecer o mantener la conexión con el destino.
+    #[error("error de conexión: {0}")]
+    Connection(String),
+
+    /// El mensaje no pudo serializarse al formato de transporte.
+    #[error("error de serialización: {0}")]
+    Serialization(String),
+
     /// Fallo en el envío de datos.
-    SendError(String),
+    #[error("error de envío: {0}")]
+    Send(String),
+
+    /// El destino no respondió a tiempo.
+    #[error("tiempo de espera agotado")]
+    Timeout,
+
+    /// El destino rechazó el mensaje (p. ej. HTTP 4xx): código y detalle.
+    #[error("mensaje rechazado ({0}): {1}")]
+    Client(u16, String),
+
+    /// El destino falló al procesar el mensaje (p. ej. HTTP 5xx): código y detalle.
+    #[error("error del servidor ({0}): {1}")]
+    Server(u16, String),
 
     /// Fallo en la ejecución interna del comunicador.
-    ExecuteError(String),
+    #[error("error interno: {0}")]
+    Execute(String),
+
+    /// Fallaron uno o varios destinos de un comunicador compuesto
+    /// (ver [`MultiCommunicator`](crate::network::multi::MultiCommunicator)):
+    /// nombre de cada destino y su error.
+    #[error("fallaron {} destinos: {}", .0.len(), describe_failures(.0))]
+    Multiple(Vec<(String, CommunicatorError)>),
+}
+
+/// Lista `nombre: error` separada por `; `.
+fn describe_failures(failures: &[(String, CommunicatorError)]) -> String {
+    failures
+        .iter()
+        .map(|(name, e)| format!("{}: {}", name, e))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl CommunicatorError {
+    /// Indica si el error es transitorio y tiene sentido reintentar el envío.
+    ///
+    /// Los errores de conexión, envío, tiempo de espera y 5xx se consideran
+    /// transitorios; los de serialización, 4xx y errores internos, no. Un
+    /// `Multiple` es transitorio solo si lo son todos sus errores.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Multiple(failures) => failures.iter().all(|(_, e)| e.is_transient()),
+            _ => matches!(
+                self,
+                Self::Connection(_) | Self::Send(_) | Self::Timeout | Self::Server(..)
+            ),
+        }
+    }
 }
@@ -1,3 +1,10 @@
+use crate::core::types::{short_type_name, SensorKind, SensorMetadata, Unit};
+use async_trait::async_trait;
+use std::io::{self, ErrorKind};
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::Duration;
+use thiserror::Error;
+
 /// Representa un sensor en el sistema (temperatura, humedad, presión, etc.).
 ///
 /// Un sensor puede ser leído para obtener un valor físico o lógico.
@@ -13,12 +20,276 @@ pub trait Sensor {
     /// # Errores
     /// - `ReadError` si ocurre un fallo en la lectura.
     fn read(&mut self) -> Result<Self::Output, SensorError>;
+
+    /// Unidad de los valores que produce el sensor.
+    ///
+    /// El runtime la consulta al registrar el sensor y la adjunta a cada
+    /// `SensorReading`. Por defecto `None` (valores sin unidad o mixtos).
+    fn unit(&self) -> Option<Unit> {
+        None
+    }
+
+    /// Descripción del sensor: nombre, unidad, rango medible y tipo de valores.
+    ///
+    /// Por defecto usa el nombre del tipo, la unidad de [`unit`](Sensor::unit),
+    /// ningún rango y `SensorKind::Unknown`.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new(short_type_name::<Self>(), SensorKind::Unknown).with_unit(self.unit())
+    }
+
+    /// Entrega lo que el sensor tenga acumulado y aún no haya devuelto (p. ej.
+    /// la ventana a medias de un
+    /// [`AggregatingSensor`](crate::core::decorators::AggregatingSensor)).
+    ///
+    /// El runtime lo llama al apagarse y publica el valor como una lectura
+    /// más. Por defecto `None`.
+    fn flush(&mut self) -> Option<Self::Output> {
+        None
+    }
+}
+
+impl<S: Sensor + ?Sized> Sensor for Box<S> {
+    type Output = S::Output;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        (**self).read()
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        (**self).unit()
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        (**self).metadata()
+    }
+
+    fn flush(&mut self) -> Option<Self::Output> {
+        (**self).flush()
+    }
+}
+
+/// Variante asíncrona de [`Sensor`] para dispositivos cuya lectura implica
+/// esperar E/S (I2C, HTTP, etc.) sin bloquear el runtime de `tokio`.
+///
+/// Los sensores síncronos existentes se adaptan con [`BlockingSensor`].
+#[async_trait]
+pub trait AsyncSensor: Send {
+    /// Tipo de dato devuelto al leer el sensor.
+    type Output;
+
+    /// Lee el valor del sensor de forma asíncrona.
+    ///
+    /// # Errores
+    /// - `ReadError` si ocurre un fallo en la lectura.
+    async fn read(&mut self) -> Result<Self::Output, SensorError>;
+
+    /// Unidad de los valores que produce el sensor (ver [`Sensor::unit`]).
+    fn unit(&self) -> Option<Unit> {
+        None
+    }
+
+    /// Descripción del sensor (ver [`Sensor::metadata`]).
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new(short_type_name::<Self>(), SensorKind::Unknown).with_unit(self.unit())
+    }
+
+    /// Valor acumulado pendiente de entregar (ver [`Sensor::flush`]).
+    fn flush(&mut self) -> Option<Self::Output> {
+        None
+    }
+}
+
+/// Adaptador que expone un [`Sensor`] síncrono como [`AsyncSensor`].
+///
+/// Cada lectura se ejecuta en `tokio::task::spawn_blocking`, de modo que las
+/// lecturas bloqueantes (archivos de `/sys`, GPIO) no detienen el runtime.
+/// El sensor se comparte con la tarea bloqueante mediante `Arc<Mutex<_>>`, por
+/// lo que cancelar la lectura (p. ej. al apagar el runtime) no lo pierde.
+///
+/// La unidad y los metadatos se guardan al envolverlo: mientras una lectura
+/// abandonada por un timeout siga colgada con el sensor bloqueado,
+/// [`unit`](AsyncSensor::unit) y [`metadata`](AsyncSensor::metadata) devuelven
+/// esa copia en lugar de esperar al mutex.
+pub struct BlockingSensor<S> {
+    inner: Arc<Mutex<S>>,
+    unit: Option<Unit>,
+    metadata: SensorMetadata,
+}
+
+impl<S: Sensor> BlockingSensor<S> {
+    /// Envuelve un sensor síncrono.
+    pub fn new(inner: S) -> Self {
+        Self {
+            unit: inner.unit(),
+            metadata: inner.metadata(),
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> AsyncSensor for BlockingSensor<S>
+where
+    S: Sensor + Send + 'static,
+    S::Output: Send + 'static,
+{
+    type Output = S::Output;
+
+    async fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let sensor = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || {
+            // Si una lectura anterior sigue colgada (abandonada por un timeout
+            // del runtime) se falla de inmediato en lugar de acumular hilos esperando.
+            let mut sensor = sensor.try_lock().map_err(|e| match e {
+                TryLockError::WouldBlock => {
+                    SensorError::ReadError("lectura anterior aún en curso".to_string())
+                }
+                TryLockError::Poisoned(_) => SensorError::ReadError("sensor no disponible".to_string()),
+            })?;
+            sensor.read()
+        })
+        .await
+        .map_err(|e| SensorError::ReadError(format!("lectura abortada: {}", e)))?
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        match self.inner.try_lock() {
+            Ok(sensor) => sensor.unit(),
+            Err(_) => self.unit,
+        }
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        match self.inner.try_lock() {
+            Ok(sensor) => sensor.metadata(),
+            Err(_) => self.metadata.clone(),
+        }
+    }
+    /// Con una lectura aún colgada no hay nada que vaciar: devuelve `None`.
+    fn flush(&mut self) -> Option<Self::Output> {
+        self.inner.try_lock().ok()?.flush()
+    }
 }
 
 /// Posibles errores de lectura de un sensor.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum SensorError {
     /// Fallo en la lectura.
+    #[error("error de lectura: {0}")]
     ReadError(String),
+    #[error("error de formato: {0}")]
     ParseError(String),
+    /// El dispositivo no existe en la ruta indicada (se detecta al crearlo).
+    #[error("dispositivo no encontrado: {0}")]
+    NotFound(String),
+    /// El proceso no tiene permisos sobre el dispositivo (p. ej. el usuario no
+    /// pertenece al grupo `gpio`); reintentar no lo soluciona.
+    #[error("permiso denegado: {0}")]
+    PermissionDenied(String),
+    /// Otro error de E/S al acceder al dispositivo.
+    #[error("error de E/S: {0}")]
+    Io(#[source] io::Error),
+    /// El dispositivo estaba presente y desapareció (cable suelto, bus caído).
+    #[error("dispositivo desconectado: {0}")]
+    Disconnected(String),
+    /// El sensor aún se está estabilizando; faltan aproximadamente la duración
+    /// indicada para que sus lecturas sean válidas. El runtime omite la lectura
+    /// sin contarla como fallo.
+    #[error("sensor estabilizándose, faltan {0:?}")]
+    Warmup(Duration),
+    /// Un filtro descartó la lectura a propósito (p. ej. `DeadbandSensor`
+    /// cuando el valor apenas cambió). El runtime la omite sin contarla como fallo.
+    #[error("lectura descartada por un filtro")]
+    Suppressed,
+    /// La lectura no terminó dentro del tiempo máximo indicado.
+    #[error("tiempo de lectura agotado ({0:?})")]
+    Timeout(Duration),
+    /// El sensor falló demasiadas veces seguidas y no se lee durante
+    /// aproximadamente la duración indicada (ver
+    /// [`CircuitBreakerSensor`](crate::core::decorators::CircuitBreakerSensor)).
+    #[error("circuito abierto durante {0:?}")]
+    CircuitOpen(Duration),
+    /// El receptor (p. ej. un GPS) responde pero aún no tiene una posición
+    /// válida; sus coordenadas no deben usarse.
+    #[error("sin posición válida")]
+    NoFix,
+    /// El valor (o un campo de un `Map`) no es finito, p. ej. `NaN` por una
+    /// división por cero en un valor derivado; no tiene representación en JSON.
+    #[error("valor no finito: {0}")]
+    NonFinite(f64),
+    /// El valor cae fuera del rango declarado en [`Sensor::metadata`]
+    /// (p. ej. -500 °C de una sonda desconectada).
+    #[error("valor {value} fuera de rango [{}, {}]", describe_bound(*.min), describe_bound(*.max))]
+    OutOfRange {
+        value: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+}
+
+/// Texto de un límite de [`SensorError::OutOfRange`]; `-` si no lo hay.
+fn describe_bound(bound: Option<f64>) -> String {
+    bound.map_or_else(|| "-".to_string(), |bound| bound.to_string())
+}
+
+impl SensorError {
+    /// Convierte un error de E/S al acceder a `path` en la variante adecuada:
+    /// `NotFound` o `PermissionDenied` con la ruta, o `Io` con el error original.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    /// use iot_framework::core::traits::sensor::SensorError;
+    ///
+    /// let missing = SensorError::from_io(Error::from(ErrorKind::NotFound), "/sys/bus/w1/devices");
+    /// assert!(matches!(missing, SensorError::NotFound(path) if path == "/sys/bus/w1/devices"));
+    ///
+    /// // EACCES, como lo devuelve el sistema operativo.
+    /// let denied = SensorError::from_io(Error::from_raw_os_error(13), "/dev/gpiomem");
+    /// assert!(matches!(&denied, SensorError::PermissionDenied(path) if path == "/dev/gpiomem"));
+    /// assert!(!denied.is_transient());
+    ///
+    /// let interrupted = SensorError::from_io(Error::from(ErrorKind::Interrupted), "/dev/i2c-1");
+    /// assert!(matches!(&interrupted, SensorError::Io(e) if e.kind() == ErrorKind::Interrupted));
+    /// assert!(interrupted.is_transient());
+    /// ```
+    pub fn from_io(error: io::Error, path: impl AsRef<std::path::Path>) -> Self {
+        let path = path.as_ref().display().to_string();
+        match error.kind() {
+            ErrorKind::NotFound => Self::NotFound(path),
+            ErrorKind::PermissionDenied => Self::PermissionDenied(path),
+            _ => Self::Io(error),
+        }
+    }
+
+    /// Indica si el error es transitorio y tiene sentido repetir la lectura
+    /// (ver [`RetrySensor`](crate::core::decorators::RetrySensor)).
+    ///
+    /// No lo son un dispositivo inexistente o sin permisos, un `Io` de entrada
+    /// no válida o no soportada, ni las omisiones deliberadas (`Warmup`,
+    /// `Suppressed`) o un circuito abierto (`CircuitOpen`), que reintentar no
+    /// cambia. El resto, incluidos los fallos de lectura y de formato de una
+    /// trama corrupta, sí.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::NotFound(_)
+            | Self::PermissionDenied(_)
+            | Self::Warmup(_)
+            | Self::Suppressed
+            | Self::CircuitOpen(_) => false,
+            Self::Io(e) => !matches!(
+                e.kind(),
+                ErrorKind::NotFound | ErrorKind::PermissionDenied | ErrorKind::InvalidInput | ErrorKind::Unsupported
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl From<io::Error> for SensorError {
+    /// Conserva el error de E/S tal cual; usa [`SensorError::from_io`] cuando
+    /// se conoce la ruta del dispositivo.
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
 }
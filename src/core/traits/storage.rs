@@ -1,30 +1,24 @@
-/// Define un sistema de almacenamiento (archivo local, base de datos, memoria, etc.).
-///
-/// # Associated Types
-/// - `Data`: Tipo de datos que se guardan/cargan.
-pub trait Storage {
-    /// Tipo de los datos manejados por el almacenamiento.
-    type Data;
+use crate::core::SensorReading;
+use thiserror::Error;
 
-    /// Carga datos desde el almacenamiento.
-    ///
-    /// # Errores
-    /// - `LoadError` si no se puede leer.
-    fn load(&mut self) -> Result<Self::Data, StorageError>;
-
-    /// Guarda datos en el almacenamiento.
+/// Define un sistema de almacenamiento (archivo local, base de datos, memoria, etc.)
+/// donde el runtime persiste las lecturas.
+pub trait Storage {
+    /// Guarda una lectura en el almacenamiento.
     ///
     /// # Errores
     /// - `SaveError` si no se puede guardar.
-    fn save(&mut self, data: Self::Data) -> Result<(), StorageError>;
+    fn persist(&mut self, reading: &SensorReading) -> Result<(), StorageError>;
 }
 
 /// Posibles errores del almacenamiento.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum StorageError {
     /// Error al cargar datos.
+    #[error("error al cargar datos: {0}")]
     LoadError(String),
 
     /// Error al guardar datos.
+    #[error("error al guardar datos: {0}")]
     SaveError(String),
 }
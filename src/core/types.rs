@@ -1,8 +1,695 @@
-#[derive(Debug, Clone)]
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Valor crudo producido por un sensor.
+///
+/// Con la feature `serde` activa se serializa con la representación
+/// *externally tagged* de serde (`{"Float": 21.5}`), salvo `Bytes`, que se
+/// codifica como texto base64 (`{"Bytes": "AQID"}`) en lugar de un arreglo, y
+/// `Timestamp`, que se codifica en milisegundos desde el UNIX epoch
+/// (`{"Timestamp": 1700000000123}`).
+///
+/// `Map` permite que un mismo sensor entregue varios valores con nombre
+/// (por ejemplo, un DHT22 devuelve `{"humidity": 48.0, "temp": 21.3}`). Se usa
+/// un `BTreeMap` para que el orden de las claves sea estable al registrar o
+/// serializar las lecturas.
+///
+/// `Timestamp` representa un instante como dato en sí (relojes, marcas de
+/// vida), distinto del momento en que se tomó la lectura (`SensorReading::timestamp`).
+///
+/// `Json` (solo con la feature `serde`) transporta un valor estructurado
+/// arbitrario (`{"Json": {"gps": {"lat": 40.4}}}`) que los comunicadores
+/// envían tal cual, sin interpretarlo.
+///
+/// El enum es `#[non_exhaustive]`: como `Json` depende de una feature, un
+/// `match` exhaustivo fuera del crate dejaría de compilar en cuanto otra
+/// dependencia activara `serde`, así que los `match` externos necesitan un
+/// brazo `_`.
+///
+/// # Ejemplo
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use iot_framework::SensorOutput;
+///
+/// let json = serde_json::to_string(&SensorOutput::Float(21.5)).unwrap();
+/// assert_eq!(json, r#"{"Float":21.5}"#);
+///
+/// let bytes: SensorOutput = serde_json::from_str(r#"{"Bytes":"AQID"}"#).unwrap();
+/// assert_eq!(bytes, SensorOutput::Bytes(vec![1, 2, 3]));
+///
+/// let at = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1_700_000_000_123);
+/// let json = serde_json::to_string(&SensorOutput::Timestamp(at)).unwrap();
+/// assert_eq!(json, r#"{"Timestamp":1700000000123}"#);
+///
+/// let nested = SensorOutput::Json(serde_json::json!({"fix": {"lat": 40.4, "sats": [3, 7]}}));
+/// let json = serde_json::to_string(&nested).unwrap();
+/// assert_eq!(json, r#"{"Json":{"fix":{"lat":40.4,"sats":[3,7]}}}"#);
+/// assert_eq!(serde_json::from_str::<SensorOutput>(&json).unwrap(), nested);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum SensorOutput {
     Bool(bool),
     Int(i64),
     Float(f32),
     Text(String),
+    #[cfg_attr(feature = "serde", serde(with = "base64_bytes"))]
     Bytes(Vec<u8>),      
+    Map(BTreeMap<String, f32>),
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis"))]
+    Timestamp(SystemTime),
+    #[cfg(feature = "serde")]
+    Json(serde_json::Value),
+}
+
+impl SensorOutput {
+    /// Valor numérico de la lectura, si lo tiene.
+    ///
+    /// `Int` y `Float` se convierten a `f64`, `Bool` cuenta como `0`/`1` y un
+    /// `Text` se interpreta como número si lo es (sin espacios alrededor). El
+    /// resto de variantes (incluidos los `Map`, que tienen varios valores)
+    /// devuelven `None`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::SystemTime;
+    /// use iot_framework::SensorOutput;
+    ///
+    /// assert_eq!(SensorOutput::Int(-7).as_f64(), Some(-7.0));
+    /// assert_eq!(SensorOutput::Float(21.5).as_f64(), Some(21.5));
+    /// assert_eq!(SensorOutput::Bool(true).as_f64(), Some(1.0));
+    /// assert_eq!(SensorOutput::Bool(false).as_f64(), Some(0.0));
+    /// assert_eq!(SensorOutput::Text("1013.25".into()).as_f64(), Some(1013.25));
+    /// assert_eq!(SensorOutput::Text(" 42 ".into()).as_f64(), Some(42.0));
+    /// assert_eq!(SensorOutput::Text("SECO".into()).as_f64(), None);
+    /// assert_eq!(SensorOutput::Bytes(vec![0x2a]).as_f64(), None);
+    /// assert_eq!(SensorOutput::Map([("temp".into(), 21.5)].into()).as_f64(), None);
+    /// assert_eq!(SensorOutput::Timestamp(SystemTime::now()).as_f64(), None);
+    /// # #[cfg(feature = "serde")]
+    /// # assert_eq!(SensorOutput::Json(serde_json::json!(7)).as_f64(), None);
+    /// ```
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            SensorOutput::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+            SensorOutput::Int(v) => Some(*v as f64),
+            SensorOutput::Float(v) => Some(*v as f64),
+            SensorOutput::Text(t) => t.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Valor lógico de la lectura, si lo tiene.
+    ///
+    /// `Bool` tal cual, `Int` y `Float` son `true` si no son cero, y un `Text`
+    /// vale si es `true`/`false` u `on`/`off` (sin distinguir mayúsculas). El
+    /// resto de variantes devuelven `None`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::SensorOutput;
+    ///
+    /// assert_eq!(SensorOutput::Bool(true).as_bool(), Some(true));
+    /// assert_eq!(SensorOutput::Int(0).as_bool(), Some(false));
+    /// assert_eq!(SensorOutput::Float(0.5).as_bool(), Some(true));
+    /// assert_eq!(SensorOutput::Text("ON".into()).as_bool(), Some(true));
+    /// assert_eq!(SensorOutput::Text("false".into()).as_bool(), Some(false));
+    /// assert_eq!(SensorOutput::Text("SECO".into()).as_bool(), None);
+    /// assert_eq!(SensorOutput::Bytes(vec![1]).as_bool(), None);
+    /// ```
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            SensorOutput::Bool(b) => Some(*b),
+            SensorOutput::Int(v) => Some(*v != 0),
+            SensorOutput::Float(v) => Some(*v != 0.0),
+            SensorOutput::Text(t) => match t.trim().to_ascii_lowercase().as_str() {
+                "true" | "on" => Some(true),
+                "false" | "off" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Compara dos lecturas por su valor numérico ([`as_f64`](Self::as_f64)).
+    ///
+    /// Devuelve `None` si alguna no tiene valor numérico (o es `NaN`). A
+    /// diferencia de `==`, no distingue variantes: `Int(2)` y `Float(2.0)` son
+    /// iguales. Para comparar con un número basta con los operadores:
+    /// `SensorOutput` implementa `PartialOrd<f64>`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use iot_framework::SensorOutput;
+    ///
+    /// let temp = SensorOutput::Float(28.5);
+    /// assert_eq!(temp.compare(&SensorOutput::Int(28)), Some(Ordering::Greater));
+    /// assert_eq!(SensorOutput::Int(2).compare(&SensorOutput::Float(2.0)), Some(Ordering::Equal));
+    /// assert_eq!(temp.compare(&SensorOutput::Text("SECO".into())), None);
+    ///
+    /// assert!(temp > 28.0);
+    /// assert!(SensorOutput::Text("12".into()) <= 12.0);
+    /// assert!(!(SensorOutput::Bytes(vec![]) < 1.0) && !(SensorOutput::Bytes(vec![]) >= 1.0));
+    /// ```
+    pub fn compare(&self, other: &SensorOutput) -> Option<Ordering> {
+        self.as_f64()?.partial_cmp(&other.as_f64()?)
+    }
+
+    /// Representa el valor como texto, con `precision` decimales en los números
+    /// de punto flotante (también en cada valor de un `Map`).
+    ///
+    /// Es el formato que usan los comunicadores de texto cuando se les fija una
+    /// precisión. El resto de variantes no dependen de `precision`: `Int` y
+    /// `Bool` tal cual, `Text` sin comillas, `Bytes` en hexadecimal, `Map` como
+    /// `clave=valor` separados por comas, `Timestamp` en milisegundos desde el
+    /// UNIX epoch y `Json` compacto.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use iot_framework::SensorOutput;
+    ///
+    /// let temp = SensorOutput::Float(21.456);
+    /// assert_eq!(temp.format(0), "21");
+    /// assert_eq!(temp.format(2), "21.46");
+    /// assert_eq!(temp.format(4), "21.4560");
+    ///
+    /// let dht = SensorOutput::Map([("humidity".into(), 48.0), ("temp".into(), 21.25)].into());
+    /// assert_eq!(dht.format(0), "humidity=48,temp=21");
+    /// assert_eq!(dht.format(2), "humidity=48.00,temp=21.25");
+    /// assert_eq!(dht.format(4), "humidity=48.0000,temp=21.2500");
+    ///
+    /// // Las variantes no decimales se representan igual con cualquier precisión.
+    /// let at = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    /// for precision in [0, 2, 4] {
+    ///     assert_eq!(SensorOutput::Int(-7).format(precision), "-7");
+    ///     assert_eq!(SensorOutput::Bool(true).format(precision), "true");
+    ///     assert_eq!(SensorOutput::Text("SECO".into()).format(precision), "SECO");
+    ///     assert_eq!(SensorOutput::Bytes(vec![0x0a, 0xff]).format(precision), "0aff");
+    ///     assert_eq!(SensorOutput::Timestamp(at).format(precision), "1700000000123");
+    /// #   #[cfg(feature = "serde")]
+    /// #   assert_eq!(SensorOutput::Json(serde_json::json!({"sats": 7})).format(precision), r#"{"sats":7}"#);
+    /// }
+    /// ```
+    pub fn format(&self, precision: usize) -> String {
+        match self {
+            SensorOutput::Bool(b) => b.to_string(),
+            SensorOutput::Int(v) => v.to_string(),
+            SensorOutput::Float(v) => format!("{:.*}", precision, v),
+            SensorOutput::Text(t) => t.clone(),
+            SensorOutput::Bytes(bytes) => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            SensorOutput::Map(fields) => fields
+                .iter()
+                .map(|(k, v)| format!("{}={:.*}", k, precision, v))
+                .collect::<Vec<_>>()
+                .join(","),
+            SensorOutput::Timestamp(at) => at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0)
+                .to_string(),
+            #[cfg(feature = "serde")]
+            SensorOutput::Json(v) => v.to_string(),
+        }
+    }
+}
+
+/// Igualdad numérica con un `f64`, según [`SensorOutput::as_f64`].
+impl PartialEq<f64> for SensorOutput {
+    fn eq(&self, other: &f64) -> bool {
+        self.as_f64() == Some(*other)
+    }
+}
+
+/// Orden numérico respecto a un `f64`, según [`SensorOutput::as_f64`]; las
+/// lecturas sin valor numérico no son comparables.
+impl PartialOrd<f64> for SensorOutput {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.as_f64()?.partial_cmp(other)
+    }
+}
+
+/// (De)serialización de `Vec<u8>` como cadena base64 estándar.
+#[cfg(feature = "serde")]
+mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Unidad física asociada a una lectura numérica.
+///
+/// Se mantiene separada del valor para que `SensorOutput::Float` siga siendo
+/// procesable (comparaciones, medias, conversiones) y cada comunicador decida
+/// cómo mostrarla. Se serializa con el nombre de la variante (`"Celsius"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Unit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Percent,
+    HectoPascal,
+    Lux,
+    Meter,
+    Centimeter,
+    Millimeter,
+    Ppm,
+    Volt,
+}
+
+impl Unit {
+    /// Símbolo habitual de la unidad (`"°C"`, `"%"`, `"hPa"`...).
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+            Unit::Kelvin => "K",
+            Unit::Percent => "%",
+            Unit::HectoPascal => "hPa",
+            Unit::Lux => "lx",
+            Unit::Meter => "m",
+            Unit::Centimeter => "cm",
+            Unit::Millimeter => "mm",
+            Unit::Ppm => "ppm",
+            Unit::Volt => "V",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Tipo de valores que produce un sensor, según [`SensorMetadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorKind {
+    /// Magnitud continua (`Float`/`Int`): temperatura, distancia, lux...
+    Numeric,
+    /// Dos estados (`Bool`): movimiento, contacto, nivel de un pin.
+    Binary,
+    /// Un conjunto cerrado de estados (`Text`), p. ej. `"HÚMEDO"`/`"SECO"`.
+    Discrete,
+    /// Varias magnitudes con nombre en una lectura (`Map`).
+    Composite,
+    /// Instantes de tiempo (`Timestamp`).
+    Timestamp,
+    /// El sensor no declara qué produce.
+    Unknown,
+}
+
+/// Descripción estática de un sensor: qué mide y en qué rango.
+///
+/// La devuelve [`Sensor::metadata`](crate::core::traits::sensor::Sensor::metadata);
+/// sirve para documentar el despliegue, construir paneles y validar lecturas.
+/// `min` y `max` son los límites físicos del dispositivo en la unidad `unit`
+/// (no umbrales de alarma); `None` indica que no hay límite conocido.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::types::{SensorKind, SensorMetadata};
+/// use iot_framework::Unit;
+///
+/// let meta = SensorMetadata::new("DS18B20", SensorKind::Numeric)
+///     .with_unit(Some(Unit::Celsius))
+///     .with_range(-55.0, 125.0);
+/// assert!(meta.contains(21.5));
+/// assert!(!meta.contains(130.0));
+///
+/// // Sin rango declarado, cualquier valor es aceptable.
+/// assert!(SensorMetadata::new("mock", SensorKind::Unknown).contains(1e9));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorMetadata {
+    /// Nombre del modelo o tipo de sensor (`"DS18B20"`, `"HC-SR04"`...).
+    pub name: String,
+    /// Unidad de los valores numéricos, si la hay.
+    pub unit: Option<Unit>,
+    /// Valor mínimo que puede medir el dispositivo.
+    pub min: Option<f64>,
+    /// Valor máximo que puede medir el dispositivo.
+    pub max: Option<f64>,
+    /// Tipo de valores que produce.
+    pub kind: SensorKind,
+}
+
+impl SensorMetadata {
+    /// Crea metadatos sin unidad ni rango.
+    pub fn new(name: impl Into<String>, kind: SensorKind) -> Self {
+        Self {
+            name: name.into(),
+            unit: None,
+            min: None,
+            max: None,
+            kind,
+        }
+    }
+
+    /// Asigna la unidad.
+    pub fn with_unit(mut self, unit: Option<Unit>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Asigna el rango medible `[min, max]`.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Indica si `value` cae dentro del rango declarado (los límites ausentes
+    /// no restringen).
+    pub fn contains(&self, value: f64) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+/// Último segmento del nombre de `T`, sin ruta de módulos ni genéricos
+/// (`iot_framework::devices::sensors::soil::SoilMoistureSensor<A>` → `SoilMoistureSensor`).
+pub(crate) fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let base = name.split('<').next().unwrap_or(name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+/// Calidad de una [`SensorReading`]: permite distinguir un sensor averiado de
+/// uno que simplemente no tiene datos nuevos.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quality {
+    /// Lectura válida recién tomada.
+    #[default]
+    Good,
+    /// El valor es válido pero no es nuevo (repetido de una lectura anterior).
+    Stale,
+    /// La lectura falló; el valor describe el error en lugar de una medida.
+    Bad,
+}
+
+impl Quality {
+    /// Indica si es [`Quality::Good`].
+    pub fn is_good(&self) -> bool {
+        *self == Quality::Good
+    }
+}
+
+/// Lectura completa producida por el runtime.
+///
+/// Envuelve el valor crudo (`SensorOutput`) junto con el identificador del
+/// sensor que lo produjo y el instante en que se tomó la lectura, de modo que
+/// comunicadores y actuadores sepan **quién** y **cuándo** generó el dato.
+///
+/// Con la feature `serde`, la marca de tiempo se serializa como milisegundos
+/// desde el UNIX epoch, `unit` se omite cuando es `None`, `quality` cuando es
+/// [`Quality::Good`] y `seq` cuando es `0`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorReading {
+    /// Identificador con el que se registró el sensor en el runtime.
+    pub sensor_id: String,
+    /// Momento en que se realizó la lectura.
+    #[cfg_attr(feature = "serde", serde(with = "unix_millis"))]
+    pub timestamp: SystemTime,
+    /// Valor leído del sensor.
+    pub value: SensorOutput,
+    /// Unidad del valor, si el sensor la declara (ver `Sensor::unit`).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub unit: Option<Unit>,
+    /// Calidad de la lectura; el runtime solo produce lecturas distintas de
+    /// [`Quality::Good`] con `publish_errors` activado.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Quality::is_good")
+    )]
+    pub quality: Quality,
+    /// Número de secuencia de la lectura dentro de su sensor: el runtime lo
+    /// empieza en 1 y lo incrementa en cada lectura (también las de error),
+    /// de modo que el receptor pueda descartar duplicados por
+    /// `(sensor_id, seq)`. `0` indica una lectura sin secuencia (creada fuera
+    /// del runtime, confirmaciones de actuadores o alertas).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "is_zero")
+    )]
+    pub seq: u64,
+}
+
+/// Indica si `seq` es `0` (lectura sin secuencia).
+#[cfg(feature = "serde")]
+fn is_zero(seq: &u64) -> bool {
+    *seq == 0
+}
+
+impl SensorReading {
+    /// Crea una lectura con el instante actual como marca de tiempo.
+    pub fn new(sensor_id: impl Into<String>, value: SensorOutput) -> Self {
+        Self {
+            sensor_id: sensor_id.into(),
+            timestamp: SystemTime::now(),
+            value,
+            unit: None,
+            quality: Quality::Good,
+            seq: 0,
+        }
+    }
+
+    /// Asigna la unidad de la lectura.
+    pub fn with_unit(mut self, unit: Option<Unit>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Asigna la calidad de la lectura.
+    pub fn with_quality(mut self, quality: Quality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// Asigna el número de secuencia de la lectura.
+    ///
+    /// # Ejemplo
+    /// El runtime numera las lecturas de cada sensor por separado, aunque se
+    /// lean en el mismo ciclo, y la cuenta continúa entre ejecuciones:
+    /// ```
+    /// use std::time::Duration;
+    /// use iot_framework::core::runtime::RuntimeController;
+    /// use iot_framework::devices::sensors::counter::CounterSensor;
+    /// use iot_framework::network::null::RecordingCommunicator;
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let sent = RecordingCommunicator::new();
+    /// let mut runtime = RuntimeController::builder()
+    ///     .with_communicator(Box::new(sent.clone()))
+    ///     .with_interval(Duration::from_millis(1))
+    ///     .add_sensor("a", Box::new(CounterSensor::new()))
+    ///     .add_sensor("b", Box::new(CounterSensor::new()))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// runtime.run_for_cycles(3).await;
+    /// runtime.run_for_cycles(2).await;
+    /// let sent = sent.readings();
+    /// // Las lecturas de ambos sensores llegan intercaladas...
+    /// let ids: Vec<&str> = sent.iter().map(|r| r.sensor_id.as_str()).collect();
+    /// assert_eq!(ids, ["a", "b"].repeat(5));
+    /// // ...pero cada uno lleva su propia cuenta.
+    /// for id in ["a", "b"] {
+    ///     let seqs: Vec<u64> = sent.iter().filter(|r| r.sensor_id == id).map(|r| r.seq).collect();
+    ///     assert_eq!(seqs, vec![1, 2, 3, 4, 5]);
+    /// }
+    /// # }
+    /// ```
+    pub fn with_seq(mut self, seq: u64) -> Self {
+        self.seq = seq;
+        self
+    }
+
+    /// Convierte la lectura a otra unidad de la misma magnitud.
+    ///
+    /// Soporta temperaturas (Celsius ↔ Fahrenheit ↔ Kelvin) y longitudes
+    /// (metros ↔ centímetros ↔ milímetros); convertir a la
+    /// misma unidad devuelve una copia. Los valores `Int` se convierten a
+    /// `Float`.
+    ///
+    /// # Errores
+    /// - `ConversionError::MissingUnit` si la lectura no declara unidad.
+    /// - `ConversionError::NotNumeric` si el valor no es `Int` ni `Float`.
+    /// - `ConversionError::Incompatible` si las magnitudes difieren.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::types::ConversionError;
+    /// use iot_framework::{SensorOutput, SensorReading, Unit};
+    ///
+    /// let reading = SensorReading::new("t", SensorOutput::Float(100.0)).with_unit(Some(Unit::Celsius));
+    ///
+    /// let kelvin = reading.convert(Unit::Kelvin).unwrap();
+    /// assert_eq!(kelvin.value, SensorOutput::Float(373.15));
+    /// assert_eq!(kelvin.unit, Some(Unit::Kelvin));
+    ///
+    /// // La lluvia de un pluviómetro se mide en milímetros.
+    /// let rain = SensorReading::new("lluvia", SensorOutput::Float(12.5)).with_unit(Some(Unit::Millimeter));
+    /// assert_eq!(rain.convert(Unit::Centimeter).unwrap().value, SensorOutput::Float(1.25));
+    ///
+    /// assert_eq!(
+    ///     reading.convert(Unit::Percent),
+    ///     Err(ConversionError::Incompatible { from: Unit::Celsius, to: Unit::Percent })
+    /// );
+    /// ```
+    pub fn convert(&self, target: Unit) -> Result<SensorReading, ConversionError> {
+        let from = self.unit.ok_or(ConversionError::MissingUnit)?;
+        if from == target {
+            return Ok(self.clone());
+        }
+        let value = match self.value {
+            SensorOutput::Float(v) => v as f64,
+            SensorOutput::Int(v) => v as f64,
+            _ => return Err(ConversionError::NotNumeric),
+        };
+        let converted = convert_temperature(value, from, target)
+            .or_else(|| convert_length(value, from, target))
+            .ok_or(ConversionError::Incompatible { from, to: target })?;
+        Ok(SensorReading {
+            value: SensorOutput::Float(converted as f32),
+            unit: Some(target),
+            ..self.clone()
+        })
+    }
+
+    /// Atajo de [`convert`](Self::convert) a `Unit::Fahrenheit`.
+    pub fn to_fahrenheit(&self) -> Result<SensorReading, ConversionError> {
+        self.convert(Unit::Fahrenheit)
+    }
+
+    /// Devuelve la marca de tiempo en milisegundos desde el UNIX epoch.
+    pub fn timestamp_millis(&self) -> u128 {
+        self.timestamp
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    }
+}
+
+/// Orden remota dirigida a un actuador concreto.
+///
+/// La reciben los comunicadores bidireccionales ([`Communicator::receive`]) y
+/// el runtime la entrega a los actuadores registrados con el mismo
+/// `actuator_id`. Con la feature `serde` su forma JSON es
+/// `{"actuator_id": "riego", "value": {"Bool": true}}`.
+///
+/// [`Communicator::receive`]: crate::core::traits::communicator::Communicator::receive
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActuatorCommand {
+    /// Identificador del actuador destinatario.
+    pub actuator_id: String,
+    /// Valor a aplicar (mismo formato que las lecturas de sensores).
+    pub value: SensorOutput,
+}
+
+impl ActuatorCommand {
+    /// Crea una orden para el actuador `actuator_id`.
+    pub fn new(actuator_id: impl Into<String>, value: SensorOutput) -> Self {
+        Self {
+            actuator_id: actuator_id.into(),
+            value,
+        }
+    }
+}
+
+impl From<ActuatorCommand> for SensorReading {
+    /// Convierte la orden en la lectura que recibe el actuador; el `sensor_id`
+    /// es el id del actuador y la marca de tiempo, la de recepción.
+    fn from(command: ActuatorCommand) -> Self {
+        SensorReading::new(command.actuator_id, command.value)
+    }
+}
+
+/// Convierte `value` entre unidades de temperatura pasando por Celsius.
+/// Devuelve `None` si alguna de las unidades no es de temperatura.
+fn convert_temperature(value: f64, from: Unit, to: Unit) -> Option<f64> {
+    let celsius = match from {
+        Unit::Celsius => value,
+        Unit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+        Unit::Kelvin => value - 273.15,
+        _ => return None,
+    };
+    match to {
+        Unit::Celsius => Some(celsius),
+        Unit::Fahrenheit => Some(celsius * 9.0 / 5.0 + 32.0),
+        Unit::Kelvin => Some(celsius + 273.15),
+        _ => None,
+    }
+}
+
+/// Convierte `value` entre unidades de longitud pasando por metros.
+/// Devuelve `None` si alguna de las unidades no es de longitud.
+fn convert_length(value: f64, from: Unit, to: Unit) -> Option<f64> {
+    let meters = match from {
+        Unit::Meter => value,
+        Unit::Centimeter => value / 100.0,
+        Unit::Millimeter => value / 1000.0,
+        _ => return None,
+    };
+    match to {
+        Unit::Meter => Some(meters),
+        Unit::Centimeter => Some(meters * 100.0),
+        Unit::Millimeter => Some(meters * 1000.0),
+        _ => None,
+    }
+}
+
+/// Errores al convertir una lectura entre unidades.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConversionError {
+    /// La lectura no tiene unidad asociada.
+    #[error("la lectura no tiene unidad")]
+    MissingUnit,
+    /// El valor no es numérico (`Int`/`Float`).
+    #[error("el valor no es numérico")]
+    NotNumeric,
+    /// Las unidades miden magnitudes distintas.
+    #[error("no se puede convertir {from} a {to}")]
+    Incompatible { from: Unit, to: Unit },
+}
+
+/// (De)serialización de `SystemTime` como milisegundos desde el UNIX epoch.
+#[cfg(feature = "serde")]
+pub(crate) mod unix_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    pub fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        serializer.serialize_u64(millis)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+        let millis = u64::deserialize(deserializer)?;
+        Ok(UNIX_EPOCH + Duration::from_millis(millis))
+    }
 }
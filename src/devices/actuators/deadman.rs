@@ -0,0 +1,146 @@
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// DeadmanActuator: avisa cuando un sensor deja de entregar lecturas.
+///
+/// Registra el instante de la última lectura de cada sensor y, si el hueco
+/// supera `timeout`, envía al actuador interno (una sirena, un relé, un
+/// actuador que publica un mensaje...) `Bool(true)` con el id del sensor
+/// callado. El aviso se envía una sola vez por corte; cuando el sensor vuelve
+/// a reportar se envía `Bool(false)`. Detecta sensores congelados que el
+/// `read_timeout` del runtime no ve: los que fallan, siguen en `Warmup` o
+/// dejan de programarse.
+///
+/// Los huecos se comprueban cada vez que llega una lectura de cualquier
+/// sensor; conviene registrar también un sensor de señal de vida (como
+/// [`ClockSensor`](crate::devices::sensors::clock::ClockSensor)) para que la
+/// comprobación ocurra aunque callen todos los demás. Por defecto se vigilan
+/// todos los sensores vistos; con [`watching`](Self::watching) solo los
+/// indicados, que se vigilan desde la creación aunque nunca hayan reportado.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, Instant};
+/// use iot_framework::core::traits::actuator::ActuatorState;
+/// use iot_framework::devices::actuators::deadman::DeadmanActuator;
+/// use iot_framework::devices::actuators::dummy::DummyActuator;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading};
+///
+/// let start = Instant::now();
+/// let at = |secs| start + Duration::from_secs(secs);
+/// let temp = SensorReading::new("temp", SensorOutput::Float(21.5));
+/// let clock = SensorReading::new("reloj", SensorOutput::Bool(true));
+///
+/// let mut alarm = DeadmanActuator::new(DummyActuator::new(), Duration::from_secs(30)).watching(&["temp"]);
+/// alarm.execute_at(temp.clone(), at(0)).unwrap();
+/// assert!(alarm.check_at(at(25)).unwrap().is_empty());
+///
+/// // Pasado el timeout el aviso se envía una sola vez.
+/// assert_eq!(alarm.check_at(at(31)).unwrap(), vec!["temp".to_string()]);
+/// alarm.execute_at(clock.clone(), at(40)).unwrap();
+/// assert!(alarm.check_at(at(60)).unwrap().is_empty());
+/// assert!(alarm.is_alerting("temp"));
+/// assert_eq!(alarm.state(), ActuatorState::Known(SensorOutput::Bool(true)));
+///
+/// // La siguiente lectura cancela el aviso.
+/// alarm.execute_at(temp, at(61)).unwrap();
+/// assert!(!alarm.is_alerting("temp"));
+/// assert_eq!(alarm.state(), ActuatorState::Known(SensorOutput::Bool(false)));
+/// ```
+pub struct DeadmanActuator<A> {
+    inner: A,
+    timeout: Duration,
+    /// Sensores vigilados explícitamente; `None` vigila todos los vistos.
+    watched: Option<Vec<String>>,
+    /// Última lectura de cada sensor y si ya se avisó de su corte.
+    last_seen: HashMap<String, (Instant, bool)>,
+}
+
+impl<A> DeadmanActuator<A>
+where
+    A: Actuator<Command = SensorReading>,
+{
+    /// Crea un DeadmanActuator que avisa a `inner` tras `timeout` sin lecturas.
+    pub fn new(inner: A, timeout: Duration) -> Self {
+        Self {
+            inner,
+            timeout,
+            watched: None,
+            last_seen: HashMap::new(),
+        }
+    }
+
+    /// Vigila solo los sensores `ids`, contando su silencio desde ahora.
+    pub fn watching(mut self, ids: &[&str]) -> Self {
+        let now = Instant::now();
+        self.last_seen = ids.iter().map(|id| (id.to_string(), (now, false))).collect();
+        self.watched = Some(ids.iter().map(|id| id.to_string()).collect());
+        self
+    }
+
+    /// Indica si hay un aviso activo para el sensor `id`.
+    pub fn is_alerting(&self, id: &str) -> bool {
+        self.last_seen.get(id).is_some_and(|(_, alerted)| *alerted)
+    }
+
+    /// Registra `reading` como recibida en `now` y comprueba los huecos.
+    ///
+    /// [`Actuator::execute`] lo invoca con `Instant::now()`; es público para
+    /// poder simular el paso del tiempo.
+    pub fn execute_at(&mut self, reading: SensorReading, now: Instant) -> Result<(), ActuatorError> {
+        let watched = self
+            .watched
+            .as_ref()
+            .is_none_or(|ids| ids.contains(&reading.sensor_id));
+        if watched {
+            let previous = self.last_seen.insert(reading.sensor_id.clone(), (now, false));
+            if matches!(previous, Some((_, true))) {
+                self.inner.execute(SensorReading {
+                    value: SensorOutput::Bool(false),
+                    unit: None,
+                    ..reading
+                })?;
+            }
+        }
+        self.check_at(now).map(|_| ())
+    }
+
+    /// Avisa de los sensores que llevan más de `timeout` sin lecturas en `now`
+    /// y devuelve sus ids. Los que ya tenían un aviso activo no se repiten.
+    pub fn check_at(&mut self, now: Instant) -> Result<Vec<String>, ActuatorError> {
+        let mut fired = Vec::new();
+        for (id, (seen, alerted)) in &mut self.last_seen {
+            if *alerted || now.saturating_duration_since(*seen) <= self.timeout {
+                continue;
+            }
+            self.inner.execute(SensorReading::new(id.clone(), SensorOutput::Bool(true)))?;
+            *alerted = true;
+            fired.push(id.clone());
+        }
+        fired.sort();
+        Ok(fired)
+    }
+}
+
+impl<A> Actuator for DeadmanActuator<A>
+where
+    A: Actuator<Command = SensorReading>,
+{
+    type Command = SensorReading;
+
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        self.execute_at(command, Instant::now())?;
+        Ok(ActuatorResult::new(self.state()))
+    }
+
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.inner.shutdown()
+    }
+
+    /// Estado del actuador interno.
+    fn state(&self) -> ActuatorState {
+        self.inner.state()
+    }
+}
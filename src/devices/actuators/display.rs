@@ -0,0 +1,156 @@
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading, Unit};
+use crate::drivers::i2c::{I2cDriver, I2cWrite};
+use crate::drivers::ssd1306::Ssd1306;
+use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::text::{Baseline, Text};
+
+/// Caracteres por línea con la fuente de 6x10 en 128 píxeles.
+pub const COLUMNS: usize = 21;
+/// Líneas de texto en 64 píxeles; la primera muestra el id del sensor.
+pub const ROWS: usize = 6;
+/// Alto de cada línea en píxeles.
+const LINE_HEIGHT: i32 = 10;
+
+/// Texto con que se muestra un valor en la pantalla, una línea por fila.
+///
+/// Los números llevan un decimal y la unidad a continuación; cada clave de un
+/// `Map` va en su propia línea (`clave: valor`, sin unidad). Las líneas se
+/// recortan a [`COLUMNS`] caracteres y se muestran como mucho `ROWS - 1`,
+/// las que caben bajo el id del sensor.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::actuators::display::display_text;
+/// use iot_framework::{SensorOutput, Unit};
+///
+/// assert_eq!(display_text(&SensorOutput::Float(21.46), Some(Unit::Celsius)), "21.5 °C");
+/// assert_eq!(display_text(&SensorOutput::Int(1013), Some(Unit::HectoPascal)), "1013 hPa");
+/// assert_eq!(display_text(&SensorOutput::Bool(true), None), "true");
+/// assert_eq!(display_text(&SensorOutput::Text("HÚMEDO".into()), None), "HÚMEDO");
+/// assert_eq!(display_text(&SensorOutput::Bytes(vec![0x0a, 0xff]), None), "0aff");
+///
+/// let dht = SensorOutput::Map([("humidity".into(), 48.0), ("temp".into(), 21.25)].into());
+/// assert_eq!(display_text(&dht, Some(Unit::Celsius)), "humidity: 48.0\ntemp: 21.2");
+///
+/// // Lo que no cabe se recorta.
+/// let long = SensorOutput::Text("Estación meteorológica norte".into());
+/// assert_eq!(display_text(&long, None), "Estación meteorológic");
+/// let many = SensorOutput::Map((0..8).map(|i| (format!("s{}", i), i as f32)).collect());
+/// assert_eq!(display_text(&many, None).lines().count(), 5);
+/// ```
+pub fn display_text(value: &SensorOutput, unit: Option<Unit>) -> String {
+    let text = match value {
+        SensorOutput::Int(_) | SensorOutput::Float(_) => match unit {
+            Some(unit) => format!("{} {}", value.format(1), unit.symbol()),
+            None => value.format(1),
+        },
+        SensorOutput::Map(fields) => fields
+            .iter()
+            .map(|(key, v)| format!("{}: {:.1}", key, v))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        other => other.format(1),
+    };
+    text.lines()
+        .take(ROWS - 1)
+        .map(|line| line.chars().take(COLUMNS).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// OledActuator: muestra la última lectura recibida en una pantalla OLED
+/// SSD1306 de 128x64 por I2C.
+///
+/// Cada orden borra la pantalla y dibuja el id del sensor en la primera línea
+/// y su valor ([`display_text`]) debajo. El estado del actuador es el texto
+/// del valor mostrado. Un fallo de escritura en el bus se devuelve como
+/// `ActuatorError::ExecuteError`; al apagar se borra la pantalla.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::actuator::{ActuatorError, ActuatorState};
+/// use iot_framework::devices::actuators::display::OledActuator;
+/// use iot_framework::drivers::i2c::I2cWrite;
+/// use iot_framework::drivers::ssd1306::Ssd1306;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading, Unit};
+///
+/// /// Bus que deja de responder tras `ok` escrituras.
+/// struct FakeBus { ok: usize }
+/// impl I2cWrite for FakeBus {
+///     fn write(&mut self, _bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+///         self.ok = self.ok.checked_sub(1).ok_or("sin ACK")?;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut oled = OledActuator::from_display(Ssd1306::from_bus(FakeBus { ok: 12 }).unwrap());
+/// let reading = SensorReading::new("temp", SensorOutput::Float(21.5)).with_unit(Some(Unit::Celsius));
+/// let result = oled.execute(reading.clone()).unwrap();
+/// assert_eq!(result.state, ActuatorState::Known(SensorOutput::Text("21.5 °C".into())));
+///
+/// // El bus ya no responde.
+/// assert!(matches!(oled.execute(reading), Err(ActuatorError::ExecuteError(_))));
+/// ```
+pub struct OledActuator<I: I2cWrite = I2cDriver> {
+    display: Ssd1306<I>,
+    shown: Option<String>,
+}
+
+impl OledActuator {
+    /// Abre la pantalla en `address` (normalmente
+    /// [`DEFAULT_ADDRESS`](crate::drivers::ssd1306::DEFAULT_ADDRESS)) y la inicializa.
+    pub fn new(address: u16) -> Result<Self, ActuatorError> {
+        let display = Ssd1306::new(address)
+            .map_err(|e| ActuatorError::ExecuteError(format!("ssd1306 init: {}", e)))?;
+        Ok(Self::from_display(display))
+    }
+}
+
+impl<I: I2cWrite> OledActuator<I> {
+    /// Crea el actuador sobre una pantalla ya inicializada.
+    pub fn from_display(display: Ssd1306<I>) -> Self {
+        Self { display, shown: None }
+    }
+
+    /// Borra la pantalla y dibuja `lines`, una por fila.
+    fn draw<'a>(&mut self, lines: impl IntoIterator<Item = &'a str>) -> Result<(), ActuatorError> {
+        let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+        let _ = self.display.clear(BinaryColor::Off);
+        for (row, line) in lines.into_iter().take(ROWS).enumerate() {
+            let origin = Point::new(0, row as i32 * LINE_HEIGHT);
+            let _ = Text::with_baseline(line, origin, style, Baseline::Top).draw(&mut self.display);
+        }
+        self.display
+            .flush()
+            .map_err(|e| ActuatorError::ExecuteError(format!("ssd1306: {}", e)))
+    }
+}
+
+impl<I: I2cWrite> Actuator for OledActuator<I> {
+    type Command = SensorReading;
+
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        let text = display_text(&command.value, command.unit);
+        let header: String = command.sensor_id.chars().take(COLUMNS).collect();
+        self.draw(std::iter::once(header.as_str()).chain(text.lines()))?;
+        self.shown = Some(text);
+        Ok(ActuatorResult::new(self.state()))
+    }
+
+    /// Deja la pantalla en blanco al apagar el sistema.
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.shown = None;
+        self.draw([])
+    }
+
+    fn state(&self) -> ActuatorState {
+        match &self.shown {
+            Some(text) => ActuatorState::Known(SensorOutput::Text(text.clone())),
+            None => ActuatorState::Unknown,
+        }
+    }
+}
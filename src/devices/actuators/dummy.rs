@@ -1,21 +1,48 @@
-use crate::core::traits::actuator::{Actuator, ActuatorError};
-use crate::core::SensorOutput;
-use crate::Sensor;
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading};
 /// Actuador dummy que no hace nada
-pub struct DummyActuator;
+///
+/// Recuerda el último valor recibido y lo reporta como su estado.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::actuator::ActuatorState;
+/// use iot_framework::devices::actuators::dummy::DummyActuator;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading};
+///
+/// let mut dummy = DummyActuator::new();
+/// let result = dummy.execute(SensorReading::new("led", SensorOutput::Int(3))).unwrap();
+/// assert_eq!(result.state, ActuatorState::Known(SensorOutput::Int(3)));
+/// ```
+#[derive(Default)]
+pub struct DummyActuator {
+    last: Option<SensorOutput>,
+}
 
 impl DummyActuator {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 }
 
 impl Actuator for DummyActuator {
-    type Command = SensorOutput;
+    type Command = SensorReading;
 
-    fn execute(&mut self, _command: Self::Command) -> Result<(), ActuatorError> {
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
         // No hacer nada - solo un placeholder
-        println!("[DUMMY ACTUATOR] Datos recibidos pero no se ejecuta ninguna acción");
-        Ok(())
+        println!(
+            "[DUMMY ACTUATOR] [{}] [{}] Datos recibidos pero no se ejecuta ninguna acción",
+            command.timestamp_millis(),
+            command.sensor_id
+        );
+        self.last = Some(command.value);
+        Ok(ActuatorResult::new(self.state()))
+    }
+
+    fn state(&self) -> ActuatorState {
+        match &self.last {
+            Some(value) => ActuatorState::Known(value.clone()),
+            None => ActuatorState::Unknown,
+        }
     }
-}
\ No newline at end of file
+}
@@ -1 +1,7 @@
-pub mod dummy;
\ No newline at end of file
+pub mod deadman;
+#[cfg(feature = "display")]
+pub mod display;
+pub mod dummy;
+pub mod pwm;
+pub mod relay;
+pub mod threshold;
\ No newline at end of file
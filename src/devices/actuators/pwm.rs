@@ -0,0 +1,91 @@
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading};
+use crate::drivers::pwm::{Channel, PwmDriver};
+
+/// Ancho de pulso (µs) correspondiente a 0° en un servo típico (SG90, MG996R).
+pub const SERVO_MIN_PULSE_US: f64 = 500.0;
+/// Ancho de pulso (µs) correspondiente a 180°.
+pub const SERVO_MAX_PULSE_US: f64 = 2500.0;
+/// Ángulo máximo del servo.
+pub const SERVO_MAX_ANGLE: i64 = 180;
+
+/// Convierte un ángulo de servo en ciclo de trabajo para la frecuencia dada.
+///
+/// El ángulo se limita a `0..=180`.
+///
+/// # Ejemplo
+/// A 50 Hz (periodo de 20 ms), 90° equivale a un pulso de 1.5 ms:
+/// ```
+/// use iot_framework::devices::actuators::pwm::angle_to_duty_cycle;
+///
+/// assert!((angle_to_duty_cycle(90, 50.0) - 0.075).abs() < 1e-9);
+/// assert!((angle_to_duty_cycle(0, 50.0) - 0.025).abs() < 1e-9);
+/// assert_eq!(angle_to_duty_cycle(270, 50.0), angle_to_duty_cycle(180, 50.0));
+/// ```
+pub fn angle_to_duty_cycle(angle: i64, frequency: f64) -> f64 {
+    let angle = angle.clamp(0, SERVO_MAX_ANGLE) as f64;
+    let pulse_us = SERVO_MIN_PULSE_US
+        + (SERVO_MAX_PULSE_US - SERVO_MIN_PULSE_US) * angle / SERVO_MAX_ANGLE as f64;
+    (pulse_us * frequency / 1_000_000.0).clamp(0.0, 1.0)
+}
+
+/// PwmActuator: control proporcional mediante PWM por hardware.
+///
+/// Interpreta el valor de la lectura recibida:
+/// - `SensorOutput::Float(x)`: ciclo de trabajo `x` en `0.0..=1.0` (LED atenuable, motor).
+/// - `SensorOutput::Int(angle)`: ángulo de servo en `0..=180`.
+/// - `SensorOutput::Bool(b)`: ciclo de trabajo 1.0 / 0.0.
+///
+/// Los valores fuera de rango se limitan al extremo más cercano en lugar de fallar;
+/// cualquier otro tipo de valor se rechaza con `ActuatorError::ExecuteError`.
+pub struct PwmActuator {
+    pwm: PwmDriver,
+}
+
+impl PwmActuator {
+    /// Crea un PwmActuator en `channel` a `frequency` Hz (50 Hz para servos).
+    pub fn new(channel: Channel, frequency: f64) -> Result<Self, ActuatorError> {
+        let pwm = PwmDriver::new(channel, frequency)
+            .map_err(|e| ActuatorError::ExecuteError(format!("pwm init: {}", e)))?;
+        Ok(Self { pwm })
+    }
+
+    fn set_duty_cycle(&mut self, duty_cycle: f64) -> Result<(), ActuatorError> {
+        self.pwm
+            .set_duty_cycle(duty_cycle)
+            .map_err(|e| ActuatorError::ExecuteError(format!("pwm: {}", e)))
+    }
+}
+
+impl Actuator for PwmActuator {
+    type Command = SensorReading;
+
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        let duty_cycle = match &command.value {
+            SensorOutput::Float(x) if x.is_finite() => (*x as f64).clamp(0.0, 1.0),
+            SensorOutput::Int(angle) => angle_to_duty_cycle(*angle, self.pwm.frequency),
+            SensorOutput::Bool(on) => if *on { 1.0 } else { 0.0 },
+            other => {
+                return Err(ActuatorError::ExecuteError(format!(
+                    "comando no soportado para PWM: {:?}",
+                    other
+                )))
+            }
+        };
+        self.set_duty_cycle(duty_cycle)?;
+        Ok(ActuatorResult::new(self.state()))
+    }
+
+    /// Deja la salida en 0 al apagar el sistema.
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.set_duty_cycle(0.0)
+    }
+
+    /// Ciclo de trabajo actual (0.0–1.0) como `Float`.
+    fn state(&self) -> ActuatorState {
+        match self.pwm.duty_cycle() {
+            Ok(duty_cycle) => ActuatorState::Known(SensorOutput::Float(duty_cycle as f32)),
+            Err(_) => ActuatorState::Unknown,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading};
+use crate::drivers::gpio::{GpioOutput, LevelSink};
+
+/// RelayActuator: energiza o desenergiza un relé conectado a un pin GPIO de salida.
+///
+/// Interpreta el valor de la lectura recibida:
+/// - `SensorOutput::Bool(true)` / `Bool(false)` → energizar / desenergizar.
+/// - `SensorOutput::Text("HÚMEDO")` / `Text("SECO")` (salida de `RainSensor`) → energizar / desenergizar.
+///
+/// Cualquier otro valor se rechaza con `ActuatorError::ExecuteError`.
+///
+/// Su [`state`](Actuator::state) es `Bool(true)` si el relé está energizado,
+/// calculado a partir del nivel real del pin.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::actuator::ActuatorState;
+/// use iot_framework::devices::actuators::relay::RelayActuator;
+/// use iot_framework::drivers::gpio::LevelSink;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading};
+///
+/// struct FakePin(bool);
+/// impl LevelSink for FakePin {
+///     fn write_bool(&mut self, value: bool) { self.0 = value; }
+///     fn is_set_high(&self) -> bool { self.0 }
+/// }
+///
+/// // Placa active low: energizado = pin en LOW.
+/// let mut relay = RelayActuator::from_output(FakePin(false), true);
+/// assert_eq!(relay.state(), ActuatorState::Known(SensorOutput::Bool(false)));
+///
+/// // El resultado confirma el estado real del pin tras la orden.
+/// let result = relay.execute(SensorReading::new("riego", SensorOutput::Bool(true))).unwrap();
+/// assert_eq!(result.state, ActuatorState::Known(SensorOutput::Bool(true)));
+/// assert_eq!(relay.state(), result.state);
+///
+/// relay.execute(SensorReading::new("lluvia", SensorOutput::Text("SECO".into()))).unwrap();
+/// assert_eq!(relay.state(), ActuatorState::Known(SensorOutput::Bool(false)));
+///
+/// // Una orden rechazada no altera el estado.
+/// assert!(relay.execute(SensorReading::new("riego", SensorOutput::Int(3))).is_err());
+/// assert_eq!(relay.state(), ActuatorState::Known(SensorOutput::Bool(false)));
+/// ```
+pub struct RelayActuator<O: LevelSink = GpioOutput> {
+    gpio: O,
+    /// Si el módulo de relé se activa con LOW (true) o con HIGH (false).
+    /// Muchas placas de relés para Raspberry Pi son active low.
+    active_low: bool,
+}
+
+impl RelayActuator {
+    /// Crea un RelayActuator en el pin BCM indicado, inicialmente desenergizado.
+    /// active_low = true si el relé se energiza con el pin en LOW.
+    pub fn new(pin: u8, active_low: bool) -> Result<Self, ActuatorError> {
+        let gpio = GpioOutput::new(pin)
+            .map_err(|e| ActuatorError::ExecuteError(format!("gpio init: {}", e)))?;
+        Ok(Self::from_output(gpio, active_low))
+    }
+}
+
+impl<O: LevelSink> RelayActuator<O> {
+    /// Crea un RelayActuator sobre cualquier salida digital, inicialmente desenergizado.
+    pub fn from_output(gpio: O, active_low: bool) -> Self {
+        let mut relay = Self { gpio, active_low };
+        relay.set_energized(false);
+        relay
+    }
+
+    /// Energiza (`true`) o desenergiza (`false`) el relé respetando `active_low`.
+    fn set_energized(&mut self, on: bool) {
+        self.gpio.write_bool(on != self.active_low);
+    }
+
+    /// Indica si el relé está energizado según el nivel actual del pin.
+    pub fn is_energized(&self) -> bool {
+        self.gpio.is_set_high() != self.active_low
+    }
+}
+
+impl<O: LevelSink> Actuator for RelayActuator<O> {
+    type Command = SensorReading;
+
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        let on = match &command.value {
+            SensorOutput::Bool(b) => *b,
+            SensorOutput::Text(t) if t == "HÚMEDO" => true,
+            SensorOutput::Text(t) if t == "SECO" => false,
+            other => {
+                return Err(ActuatorError::ExecuteError(format!(
+                    "comando no soportado para relé: {:?}",
+                    other
+                )))
+            }
+        };
+        self.set_energized(on);
+        Ok(ActuatorResult::new(self.state()))
+    }
+
+    /// Deja el relé desenergizado al apagar el sistema.
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.set_energized(false);
+        Ok(())
+    }
+
+    fn state(&self) -> ActuatorState {
+        ActuatorState::Known(SensorOutput::Bool(self.is_energized()))
+    }
+}
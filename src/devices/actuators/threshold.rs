@@ -0,0 +1,96 @@
+use crate::core::traits::actuator::{Actuator, ActuatorError, ActuatorResult, ActuatorState};
+use crate::core::{SensorOutput, SensorReading};
+
+/// ThresholdActuator: activa o desactiva otro actuador según umbrales con histéresis.
+///
+/// Envuelve un actuador que entiende `SensorOutput::Bool` (por ejemplo un
+/// `RelayActuator` conectado a un ventilador) y le reenvía:
+/// - `Bool(true)` cuando una lectura numérica supera `high`.
+/// - `Bool(false)` cuando una lectura numérica baja de `low`.
+///
+/// Los valores dentro de la banda `[low, high]` mantienen el estado anterior, lo
+/// que evita que el actuador oscile alrededor de un único umbral. Solo se reenvían
+/// los cambios de estado; las lecturas sin valor numérico
+/// ([`SensorOutput::as_f64`]) se ignoran.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::actuators::dummy::DummyActuator;
+/// use iot_framework::devices::actuators::threshold::ThresholdActuator;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading};
+///
+/// let mut fan = ThresholdActuator::new(DummyActuator::new(), 24.0, 28.0);
+/// let temp = |v| SensorReading::new("temp", SensorOutput::Float(v));
+///
+/// fan.execute(temp(29.0)).unwrap();
+/// assert_eq!(fan.state(), Some(true));
+/// fan.execute(temp(26.0)).unwrap(); // dentro de la banda: sigue encendido
+/// assert_eq!(fan.state(), Some(true));
+/// fan.execute(temp(23.5)).unwrap();
+/// assert_eq!(fan.state(), Some(false));
+/// ```
+pub struct ThresholdActuator<A> {
+    inner: A,
+    low: f64,
+    high: f64,
+    /// Último estado enviado; `None` hasta la primera transición.
+    state: Option<bool>,
+}
+
+impl<A> ThresholdActuator<A>
+where
+    A: Actuator<Command = SensorReading>,
+{
+    /// Crea un ThresholdActuator con la banda de histéresis `[low, high]`.
+    /// Si `low > high` los valores se intercambian.
+    pub fn new(inner: A, low: f64, high: f64) -> Self {
+        let (low, high) = if low <= high { (low, high) } else { (high, low) };
+        Self { inner, low, high, state: None }
+    }
+
+    /// Último estado reenviado al actuador interno (`None` si aún no hubo ninguno).
+    pub fn state(&self) -> Option<bool> {
+        self.state
+    }
+}
+
+impl<A> Actuator for ThresholdActuator<A>
+where
+    A: Actuator<Command = SensorReading>,
+{
+    type Command = SensorReading;
+
+    /// Sin transición el resultado es el estado actual del actuador interno.
+    fn execute(&mut self, command: Self::Command) -> Result<ActuatorResult, ActuatorError> {
+        let Some(value) = command.value.as_f64() else {
+            return Ok(ActuatorResult::new(self.inner.state()));
+        };
+        let next = if value > self.high {
+            true
+        } else if value < self.low {
+            false
+        } else {
+            return Ok(ActuatorResult::new(self.inner.state()));
+        };
+        if self.state == Some(next) {
+            return Ok(ActuatorResult::new(self.inner.state()));
+        }
+
+        let result = self.inner.execute(SensorReading {
+            value: SensorOutput::Bool(next),
+            unit: None,
+            ..command
+        })?;
+        self.state = Some(next);
+        Ok(result)
+    }
+
+    fn shutdown(&mut self) -> Result<(), ActuatorError> {
+        self.inner.shutdown()
+    }
+
+    /// Estado del actuador interno.
+    fn state(&self) -> ActuatorState {
+        self.inner.state()
+    }
+}
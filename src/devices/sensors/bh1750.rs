@@ -0,0 +1,111 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::i2c::I2cDriver;
+use std::thread;
+use std::time::Duration;
+
+/// Dirección I2C con el pin ADDR a GND (0x5C con ADDR a VCC).
+pub const DEFAULT_ADDRESS: u16 = 0x23;
+
+const CMD_POWER_ON: u8 = 0x01;
+/// Modo continuo de alta resolución (1 lx por cuenta con MTreg por defecto).
+const CMD_CONTINUOUS_HIGH_RES: u8 = 0x10;
+/// Bits altos de MTreg: `01000_MT[7:5]`.
+const CMD_MTREG_HIGH: u8 = 0x40;
+/// Bits bajos de MTreg: `011_MT[4:0]`.
+const CMD_MTREG_LOW: u8 = 0x60;
+
+/// Valor por defecto del registro de tiempo de medición.
+pub const DEFAULT_MTREG: u8 = 69;
+/// Rango válido de MTreg según la hoja de datos.
+pub const MTREG_RANGE: std::ops::RangeInclusive<u8> = 31..=254;
+
+/// Tiempo máximo de medición en alta resolución con MTreg por defecto.
+const MEASUREMENT_TIME: Duration = Duration::from_millis(180);
+
+/// Convierte los dos bytes de datos (MSB primero) en lux.
+///
+/// Aplica el factor 1.2 de la hoja de datos y corrige por el MTreg usado:
+/// un MTreg mayor alarga la medición y aumenta la sensibilidad.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::sensors::bh1750::{raw_to_lux, DEFAULT_MTREG};
+///
+/// // 0x83 0x90 = 33680 cuentas → 28066.7 lx con MTreg por defecto.
+/// let lux = raw_to_lux([0x83, 0x90], DEFAULT_MTREG);
+/// assert!((lux - 28066.67).abs() < 0.01);
+///
+/// // Con el doble de MTreg, las mismas cuentas equivalen a la mitad de luz.
+/// assert!((raw_to_lux([0x83, 0x90], 138) - lux / 2.0).abs() < 0.01);
+/// ```
+pub fn raw_to_lux(bytes: [u8; 2], mtreg: u8) -> f32 {
+    let counts = u16::from_be_bytes(bytes) as f32;
+    counts / 1.2 * (DEFAULT_MTREG as f32 / mtreg as f32)
+}
+
+/// `Bh1750` mide iluminancia ambiental con un ROHM BH1750 por I2C.
+///
+/// Se configura en modo continuo de alta resolución y devuelve
+/// `SensorOutput::Float` en lux (`Unit::Lux`).
+pub struct Bh1750 {
+    i2c: I2cDriver,
+    mtreg: u8,
+}
+
+impl Bh1750 {
+    /// Crea un `Bh1750` en la dirección I2C indicada (normalmente [`DEFAULT_ADDRESS`]),
+    /// lo enciende y arranca la medición continua.
+    pub fn new(address: u16) -> Result<Self, SensorError> {
+        let i2c = I2cDriver::new(address)
+            .map_err(|e| SensorError::ReadError(format!("i2c init: {}", e)))?;
+        let mut sensor = Self { i2c, mtreg: DEFAULT_MTREG };
+        sensor.command(CMD_POWER_ON)?;
+        sensor.command(CMD_CONTINUOUS_HIGH_RES)?;
+        thread::sleep(MEASUREMENT_TIME);
+        Ok(sensor)
+    }
+
+    /// Ajusta el registro de tiempo de medición (MTreg) para cambiar la sensibilidad.
+    ///
+    /// Valores altos sirven para luz tenue (más resolución, medición más lenta);
+    /// valores bajos, para luz intensa. Se limita a [`MTREG_RANGE`].
+    pub fn with_measurement_time(mut self, mtreg: u8) -> Result<Self, SensorError> {
+        let mtreg = mtreg.clamp(*MTREG_RANGE.start(), *MTREG_RANGE.end());
+        self.command(CMD_MTREG_HIGH | (mtreg >> 5))?;
+        self.command(CMD_MTREG_LOW | (mtreg & 0x1F))?;
+        self.command(CMD_CONTINUOUS_HIGH_RES)?;
+        self.mtreg = mtreg;
+        thread::sleep(MEASUREMENT_TIME * mtreg as u32 / DEFAULT_MTREG as u32);
+        Ok(self)
+    }
+
+    fn command(&mut self, opcode: u8) -> Result<(), SensorError> {
+        self.i2c
+            .write(&[opcode])
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))
+    }
+}
+
+impl Sensor for Bh1750 {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let mut data = [0u8; 2];
+        self.i2c
+            .read(&mut data)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        Ok(SensorOutput::Float(raw_to_lux(data, self.mtreg)))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Lux)
+    }
+
+    /// De 0 lx a la cuenta máxima (`0xFFFF`) con el MTreg configurado.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("BH1750", SensorKind::Numeric)
+            .with_unit(self.unit())
+            .with_range(0.0, raw_to_lux([0xFF, 0xFF], self.mtreg) as f64)
+    }
+}
@@ -0,0 +1,189 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::Duration;
+
+/// Dirección I2C por defecto (SDO a GND). Con SDO a VCC es `0x77`.
+pub const DEFAULT_ADDRESS: u16 = 0x76;
+/// Valor esperado en el registro `id` (0xD0).
+const CHIP_ID: u8 = 0x58;
+const REG_ID: u8 = 0xD0;
+const REG_CALIB: u8 = 0x88;
+const REG_CTRL_MEAS: u8 = 0xF4;
+const REG_DATA: u8 = 0xF7;
+/// `osrs_t` = x1 (001), `osrs_p` = x1 (001), modo *forced* (01): una medición.
+const CTRL_MEAS_FORCED: u8 = 0b0010_0101;
+/// Tiempo de conversión máximo con sobremuestreo x1 (hoja de datos: 6.4 ms).
+const MEASUREMENT_TIME: Duration = Duration::from_millis(10);
+/// Presión de referencia a nivel del mar para estimar la altitud.
+const SEA_LEVEL_HPA: f64 = 1013.25;
+
+/// Coeficientes de calibración grabados en fábrica (registros 0x88–0x9F).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration {
+    pub dig_t1: u16,
+    pub dig_t2: i16,
+    pub dig_t3: i16,
+    pub dig_p1: u16,
+    pub dig_p2: i16,
+    pub dig_p3: i16,
+    pub dig_p4: i16,
+    pub dig_p5: i16,
+    pub dig_p6: i16,
+    pub dig_p7: i16,
+    pub dig_p8: i16,
+    pub dig_p9: i16,
+}
+
+impl Calibration {
+    /// Interpreta los 24 bytes de calibración (little-endian).
+    pub fn from_bytes(b: &[u8; 24]) -> Self {
+        let u = |i: usize| u16::from_le_bytes([b[i], b[i + 1]]);
+        let s = |i: usize| i16::from_le_bytes([b[i], b[i + 1]]);
+        Self {
+            dig_t1: u(0),
+            dig_t2: s(2),
+            dig_t3: s(4),
+            dig_p1: u(6),
+            dig_p2: s(8),
+            dig_p3: s(10),
+            dig_p4: s(12),
+            dig_p5: s(14),
+            dig_p6: s(16),
+            dig_p7: s(18),
+            dig_p8: s(20),
+            dig_p9: s(22),
+        }
+    }
+
+    /// Aplica la fórmula de compensación de Bosch (versión en coma flotante).
+    ///
+    /// Devuelve `(temperatura °C, presión Pa)` a partir de las lecturas crudas de 20 bits.
+    ///
+    /// # Ejemplo
+    /// Valores de referencia de la hoja de datos (sección 3.12):
+    /// ```
+    /// use iot_framework::devices::sensors::bmp280::Calibration;
+    ///
+    /// let calib = Calibration {
+    ///     dig_t1: 27504, dig_t2: 26435, dig_t3: -1000,
+    ///     dig_p1: 36477, dig_p2: -10685, dig_p3: 3024, dig_p4: 2855, dig_p5: 140,
+    ///     dig_p6: -7, dig_p7: 15500, dig_p8: -14600, dig_p9: 6000,
+    /// };
+    /// let (temp, pressure) = calib.compensate(519888, 415148);
+    /// assert!((temp - 25.08).abs() < 0.01);
+    /// assert!((pressure - 100653.27).abs() < 0.1);
+    /// ```
+    pub fn compensate(&self, adc_t: i32, adc_p: i32) -> (f64, f64) {
+        let (adc_t, adc_p) = (adc_t as f64, adc_p as f64);
+        let t1 = self.dig_t1 as f64;
+
+        let var1 = (adc_t / 16384.0 - t1 / 1024.0) * self.dig_t2 as f64;
+        let var2 = (adc_t / 131072.0 - t1 / 8192.0).powi(2) * self.dig_t3 as f64;
+        let t_fine = var1 + var2;
+        let temp = t_fine / 5120.0;
+
+        let mut var1 = t_fine / 2.0 - 64000.0;
+        let mut var2 = var1 * var1 * self.dig_p6 as f64 / 32768.0;
+        var2 += var1 * self.dig_p5 as f64 * 2.0;
+        var2 = var2 / 4.0 + self.dig_p4 as f64 * 65536.0;
+        var1 = (self.dig_p3 as f64 * var1 * var1 / 524288.0 + self.dig_p2 as f64 * var1) / 524288.0;
+        var1 = (1.0 + var1 / 32768.0) * self.dig_p1 as f64;
+        if var1 == 0.0 {
+            // Evita la división por cero con una calibración inválida.
+            return (temp, 0.0);
+        }
+        let mut p = 1048576.0 - adc_p;
+        p = (p - var2 / 4096.0) * 6250.0 / var1;
+        let var1 = self.dig_p9 as f64 * p * p / 2147483648.0;
+        let var2 = p * self.dig_p8 as f64 / 32768.0;
+        p += (var1 + var2 + self.dig_p7 as f64) / 16.0;
+
+        (temp, p)
+    }
+}
+
+/// Estima la altitud (m) a partir de la presión (hPa) con la fórmula barométrica.
+pub fn altitude_from_hpa(pressure_hpa: f64) -> f64 {
+    44330.0 * (1.0 - (pressure_hpa / SEA_LEVEL_HPA).powf(1.0 / 5.255))
+}
+
+/// `Bmp280` lee presión barométrica y temperatura de un Bosch BMP280 por I2C.
+///
+/// Devuelve un `SensorOutput::Map` con las claves:
+/// - `"pressure"`: presión en hPa.
+/// - `"temp"`: temperatura en °C.
+/// - `"altitude"`: altitud estimada en metros respecto a 1013.25 hPa.
+pub struct Bmp280 {
+    i2c: I2cDriver,
+    calibration: Calibration,
+}
+
+impl Bmp280 {
+    /// Crea un `Bmp280` en la dirección I2C indicada (normalmente [`DEFAULT_ADDRESS`]).
+    ///
+    /// Verifica el identificador del chip y lee la calibración de fábrica.
+    pub fn new(address: u16) -> Result<Self, SensorError> {
+        let i2c = I2cDriver::new(address)
+            .map_err(|e| SensorError::ReadError(format!("i2c init: {}", e)))?;
+
+        let mut id = [0u8; 1];
+        i2c.read_registers(REG_ID, &mut id)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        if id[0] != CHIP_ID {
+            return Err(SensorError::ReadError(format!(
+                "chip id inesperado: 0x{:02X}",
+                id[0]
+            )));
+        }
+
+        let mut raw = [0u8; 24];
+        i2c.read_registers(REG_CALIB, &mut raw)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+
+        Ok(Self {
+            i2c,
+            calibration: Calibration::from_bytes(&raw),
+        })
+    }
+
+    /// Dispara una medición y devuelve `(adc_t, adc_p)` crudos de 20 bits.
+    fn measure_raw(&mut self) -> Result<(i32, i32), SensorError> {
+        self.i2c
+            .write_register(REG_CTRL_MEAS, CTRL_MEAS_FORCED)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        thread::sleep(MEASUREMENT_TIME);
+
+        let mut data = [0u8; 6];
+        self.i2c
+            .read_registers(REG_DATA, &mut data)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        let raw20 = |msb: u8, lsb: u8, xlsb: u8| {
+            ((msb as i32) << 12) | ((lsb as i32) << 4) | ((xlsb as i32) >> 4)
+        };
+        Ok((raw20(data[3], data[4], data[5]), raw20(data[0], data[1], data[2])))
+    }
+}
+
+impl Sensor for Bmp280 {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let (adc_t, adc_p) = self.measure_raw()?;
+        let (temp, pressure_pa) = self.calibration.compensate(adc_t, adc_p);
+        let pressure_hpa = pressure_pa / 100.0;
+        let values = BTreeMap::from([
+            ("pressure".to_string(), pressure_hpa as f32),
+            ("temp".to_string(), temp as f32),
+            ("altitude".to_string(), altitude_from_hpa(pressure_hpa) as f32),
+        ]);
+        Ok(SensorOutput::Map(values))
+    }
+
+    /// Lectura compuesta (`pressure`, `temp` y `altitude`), sin unidad ni rango comunes.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("BMP280", SensorKind::Composite)
+    }
+}
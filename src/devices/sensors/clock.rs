@@ -0,0 +1,45 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use std::time::SystemTime;
+
+/// `ClockSensor` devuelve la hora actual del sistema como
+/// `SensorOutput::Timestamp`.
+///
+/// No mide nada físico: sirve como señal de vida (un panel o un *watchdog*
+/// remoto detecta que el gateway dejó de publicar) y para probar el flujo
+/// completo de lecturas sin hardware.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::SystemTime;
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::clock::ClockSensor;
+/// use iot_framework::{SensorKind, SensorOutput};
+///
+/// let before = SystemTime::now();
+/// let mut clock = ClockSensor::new();
+/// let SensorOutput::Timestamp(at) = clock.read().unwrap() else { panic!("no es un instante") };
+/// assert!(at >= before && at <= SystemTime::now());
+/// assert_eq!(clock.metadata().kind, SensorKind::Timestamp);
+/// ```
+#[derive(Debug, Default)]
+pub struct ClockSensor;
+
+impl ClockSensor {
+    /// Crea un `ClockSensor`.
+    pub fn new() -> Self {
+        ClockSensor
+    }
+}
+
+impl Sensor for ClockSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        Ok(SensorOutput::Timestamp(SystemTime::now()))
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("clock", SensorKind::Timestamp)
+    }
+}
@@ -0,0 +1,134 @@
+use crate::core::runtime::BoxedSensor;
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use std::collections::BTreeMap;
+
+/// Sufijo de la clave con que se marca un sensor interno fallido (`<nombre>.error`).
+pub const ERROR_SUFFIX: &str = ".error";
+
+/// `CompositeSensor` agrupa varios sensores en una única lectura.
+///
+/// Cada `read()` lee todos los sensores internos, en el orden en que se
+/// añadieron, y devuelve un `SensorOutput::Map` con sus valores bajo el nombre
+/// de cada uno: así una estación meteorológica publica temperatura, humedad y
+/// presión en una misma lectura, con una sola marca de tiempo. Los valores
+/// `Int` y `Float` se guardan tal cual, los `Bool` como `0`/`1` y los `Map`
+/// con las claves prefijadas (`<nombre>.<clave>`).
+///
+/// Por defecto, un sensor interno que falla (o que entrega un valor no
+/// numérico) no hace fallar al compuesto: se marca con la clave
+/// `<nombre>.error` a `1` y se siguen leyendo los demás; solo si fallan todos
+/// se devuelve `SensorError::ReadError`. Con [`fail_on_error`](Self::fail_on_error)
+/// el primer fallo se devuelve como error de todo el compuesto.
+///
+/// # Ejemplo
+/// ```
+/// use std::collections::BTreeMap;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::devices::sensors::composite::CompositeSensor;
+/// use iot_framework::devices::sensors::mock::{FailingSensor, MockSensor};
+/// use iot_framework::SensorOutput;
+///
+/// let dht = SensorOutput::Map(BTreeMap::from([("temp".into(), 21.5), ("humidity".into(), 48.0)]));
+/// let mut station = CompositeSensor::new()
+///     .with("dht", Box::new(MockSensor::cycling(vec![dht])))
+///     .with("presion", Box::new(MockSensor::cycling(vec![SensorOutput::Float(1013.2)])))
+///     .with("lluvia", Box::new(MockSensor::cycling(vec![SensorOutput::Bool(true)])));
+///
+/// let expected = BTreeMap::from([
+///     ("dht.humidity".to_string(), 48.0),
+///     ("dht.temp".to_string(), 21.5),
+///     ("lluvia".to_string(), 1.0),
+///     ("presion".to_string(), 1013.2),
+/// ]);
+/// assert_eq!(station.read().unwrap(), SensorOutput::Map(expected));
+///
+/// // Un sensor caído se marca sin perder las demás magnitudes...
+/// let mut station = CompositeSensor::new()
+///     .with("temp", Box::new(MockSensor::cycling(vec![SensorOutput::Int(20)])))
+///     .with("humedad", Box::new(FailingSensor::always()));
+/// let expected = BTreeMap::from([("humedad.error".to_string(), 1.0), ("temp".to_string(), 20.0)]);
+/// assert_eq!(station.read().unwrap(), SensorOutput::Map(expected));
+///
+/// // ...salvo que se pida fallar con él.
+/// let mut station = station.fail_on_error();
+/// assert!(matches!(station.read(), Err(SensorError::ReadError(_))));
+/// ```
+#[derive(Default)]
+pub struct CompositeSensor {
+    sensors: Vec<(String, BoxedSensor)>,
+    fail_on_error: bool,
+}
+
+impl CompositeSensor {
+    /// Crea un `CompositeSensor` sin sensores internos.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Añade `sensor`, cuyos valores se publican bajo `name`.
+    pub fn with(mut self, name: impl Into<String>, sensor: BoxedSensor) -> Self {
+        self.sensors.push((name.into(), sensor));
+        self
+    }
+
+    /// Hace que el fallo de cualquier sensor interno falle todo el compuesto.
+    pub fn fail_on_error(mut self) -> Self {
+        self.fail_on_error = true;
+        self
+    }
+
+    /// Número de sensores internos.
+    pub fn len(&self) -> usize {
+        self.sensors.len()
+    }
+
+    /// Indica si no hay sensores internos.
+    pub fn is_empty(&self) -> bool {
+        self.sensors.is_empty()
+    }
+}
+
+/// Añade a `values` el valor `output` de `name`, si es numérico.
+fn merge(values: &mut BTreeMap<String, f32>, name: &str, output: SensorOutput) -> Result<(), SensorError> {
+    let value = match output {
+        SensorOutput::Int(v) => v as f32,
+        SensorOutput::Float(v) => v,
+        SensorOutput::Bool(v) => if v { 1.0 } else { 0.0 },
+        SensorOutput::Map(map) => {
+            values.extend(map.into_iter().map(|(key, v)| (format!("{}.{}", name, key), v)));
+            return Ok(());
+        }
+        other => return Err(SensorError::ParseError(format!("valor no numérico: {:?}", other))),
+    };
+    values.insert(name.to_string(), value);
+    Ok(())
+}
+
+impl Sensor for CompositeSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let mut values = BTreeMap::new();
+        let mut failures = Vec::new();
+        for (name, sensor) in &mut self.sensors {
+            let result = sensor.read().and_then(|output| merge(&mut values, name, output));
+            if let Err(e) = result {
+                if self.fail_on_error {
+                    return Err(SensorError::ReadError(format!("{}: {:?}", name, e)));
+                }
+                values.insert(format!("{}{}", name, ERROR_SUFFIX), 1.0);
+                failures.push(format!("{}: {:?}", name, e));
+            }
+        }
+        if !self.sensors.is_empty() && failures.len() == self.sensors.len() {
+            return Err(SensorError::ReadError(failures.join("; ")));
+        }
+        Ok(SensorOutput::Map(values))
+    }
+
+    /// Lectura compuesta, sin unidad ni rango comunes.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("composite", SensorKind::Composite)
+    }
+}
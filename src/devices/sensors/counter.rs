@@ -0,0 +1,60 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+
+/// `CounterSensor` devuelve un `SensorOutput::Int` que aumenta en 1 con cada
+/// lectura.
+///
+/// Útil para comprobar el flujo de extremo a extremo (huecos o duplicados en
+/// la secuencia revelan lecturas perdidas o repetidas) y como señal de vida.
+/// El valor es monótono: al llegar a `i64::MAX` se mantiene.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::counter::CounterSensor;
+/// use iot_framework::SensorOutput;
+///
+/// let mut counter = CounterSensor::new();
+/// assert_eq!(counter.read().unwrap(), SensorOutput::Int(0));
+/// assert_eq!(counter.read().unwrap(), SensorOutput::Int(1));
+///
+/// let mut near_end = CounterSensor::starting_at(i64::MAX - 1);
+/// assert_eq!(near_end.read().unwrap(), SensorOutput::Int(i64::MAX - 1));
+/// assert_eq!(near_end.read().unwrap(), SensorOutput::Int(i64::MAX));
+/// assert_eq!(near_end.read().unwrap(), SensorOutput::Int(i64::MAX));
+/// assert_eq!(near_end.metadata().min, Some((i64::MAX - 1) as f64));
+/// ```
+#[derive(Debug, Default)]
+pub struct CounterSensor {
+    start: i64,
+    next: i64,
+}
+
+impl CounterSensor {
+    /// Crea un contador que empieza en 0.
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Crea un contador cuya primera lectura es `start`.
+    pub fn starting_at(start: i64) -> Self {
+        Self { start, next: start }
+    }
+}
+
+impl Sensor for CounterSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let value = self.next;
+        self.next = self.next.saturating_add(1);
+        Ok(SensorOutput::Int(value))
+    }
+
+    /// Sin máximo; el mínimo es el valor inicial.
+    fn metadata(&self) -> SensorMetadata {
+        let mut metadata = SensorMetadata::new("counter", SensorKind::Numeric);
+        metadata.min = Some(self.start as f64);
+        metadata
+    }
+}
@@ -0,0 +1,139 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use rppal::gpio::{Bias, Gpio, IoPin, Level, Mode};
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Duración del pulso bajo con el que el host solicita una medición (≥ 1 ms en el DHT22).
+const START_SIGNAL: Duration = Duration::from_micros(1_100);
+/// Tiempo máximo de espera por cada flanco antes de abortar la lectura.
+const EDGE_TIMEOUT: Duration = Duration::from_micros(200);
+/// Umbral entre un bit `0` (~26-28 µs en alto) y un bit `1` (~70 µs en alto).
+const BIT_THRESHOLD_US: u32 = 50;
+/// Bits de datos por trama: humedad (16) + temperatura (16) + checksum (8).
+const FRAME_BITS: usize = 40;
+
+/// `Dht22` lee temperatura y humedad de un sensor DHT22/AM2302 por bit-banging.
+///
+/// Devuelve un `SensorOutput::Map` con las claves `"temp"` (°C) y `"humidity"` (%).
+///
+/// # Sensibilidad de tiempos
+/// El protocolo de un solo cable codifica cada bit en la duración de un pulso
+/// alto (~27 µs para `0`, ~70 µs para `1`). En Linux el proceso puede ser
+/// desalojado por el planificador durante la captura, lo que produce tramas
+/// corruptas: en ese caso el checksum falla y se devuelve `SensorError::ReadError`.
+/// Se recomienda envolver el sensor en un `RetrySensor` y no leerlo más de una
+/// vez cada 2 s (el DHT22 no entrega mediciones nuevas más rápido).
+pub struct Dht22 {
+    pin: IoPin,
+}
+
+impl Dht22 {
+    /// Crea un `Dht22` en el pin BCM indicado.
+    pub fn new(pin: u8) -> Result<Self, SensorError> {
+        let gpio = Gpio::new().map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        let mut pin = gpio
+            .get(pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?
+            .into_io(Mode::Input);
+        pin.set_bias(Bias::PullUp);
+        Ok(Self { pin })
+    }
+
+    /// Envía la señal de inicio y captura la duración (µs) de cada pulso alto.
+    ///
+    /// El primer pulso corresponde a la respuesta del sensor (~80 µs) y los
+    /// 40 siguientes a los bits de datos.
+    fn capture_pulses(&mut self) -> Result<Vec<u32>, SensorError> {
+        self.pin.set_mode(Mode::Output);
+        self.pin.set_low();
+        thread::sleep(START_SIGNAL);
+        self.pin.set_high();
+        self.pin.set_mode(Mode::Input);
+
+        // El sensor baja la línea para responder.
+        self.wait_for(Level::Low)?;
+
+        let mut pulses = Vec::with_capacity(FRAME_BITS + 1);
+        for _ in 0..=FRAME_BITS {
+            self.wait_for(Level::High)?;
+            let start = Instant::now();
+            self.wait_for(Level::Low)?;
+            pulses.push(start.elapsed().as_micros() as u32);
+        }
+        Ok(pulses)
+    }
+
+    /// Espera activamente a que el pin alcance `level`.
+    fn wait_for(&self, level: Level) -> Result<(), SensorError> {
+        let start = Instant::now();
+        while self.pin.read() != level {
+            if start.elapsed() > EDGE_TIMEOUT {
+                return Err(SensorError::ReadError("DHT22 no responde".to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Convierte la duración de los pulsos altos capturados en los 5 bytes de la trama.
+///
+/// Acepta la captura con o sin el pulso de respuesta inicial: siempre se
+/// decodifican los últimos 40 pulsos.
+pub fn decode_pulses(pulses: &[u32]) -> Result<[u8; 5], SensorError> {
+    if pulses.len() < FRAME_BITS {
+        return Err(SensorError::ReadError(format!(
+            "trama incompleta: {} pulsos",
+            pulses.len()
+        )));
+    }
+    let mut frame = [0u8; 5];
+    for (i, &width) in pulses[pulses.len() - FRAME_BITS..].iter().enumerate() {
+        if width > BIT_THRESHOLD_US {
+            frame[i / 8] |= 0x80 >> (i % 8);
+        }
+    }
+    Ok(frame)
+}
+
+/// Valida el checksum de una trama y devuelve `(temperatura °C, humedad %)`.
+///
+/// # Ejemplo
+/// Trama de referencia de la hoja de datos (65.2 %RH, 35.1 °C):
+/// ```
+/// use iot_framework::devices::sensors::dht22::parse_frame;
+///
+/// let (temp, hum) = parse_frame([0x02, 0x8C, 0x01, 0x5F, 0xEE]).unwrap();
+/// assert_eq!((temp, hum), (35.1, 65.2));
+/// ```
+pub fn parse_frame(frame: [u8; 5]) -> Result<(f32, f32), SensorError> {
+    let sum = frame[..4].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+    if sum != frame[4] {
+        return Err(SensorError::ReadError("checksum DHT22 inválido".to_string()));
+    }
+    let humidity = u16::from_be_bytes([frame[0], frame[1]]) as f32 / 10.0;
+    // El bit más significativo de la temperatura indica el signo.
+    let magnitude = u16::from_be_bytes([frame[2] & 0x7f, frame[3]]) as f32 / 10.0;
+    let temp = if frame[2] & 0x80 != 0 { -magnitude } else { magnitude };
+    Ok((temp, humidity))
+}
+
+impl Sensor for Dht22 {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let pulses = self.capture_pulses()?;
+        let (temp, humidity) = parse_frame(decode_pulses(&pulses)?)?;
+        let values = BTreeMap::from([
+            ("temp".to_string(), temp),
+            ("humidity".to_string(), humidity),
+        ]);
+        Ok(SensorOutput::Map(values))
+    }
+
+    /// Lectura compuesta (`temp` y `humidity`), sin unidad ni rango comunes.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("DHT22", SensorKind::Composite)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::adc::Adc;
+use std::time::{Duration, Instant};
+
+/// Curva de sensibilidad `ppm = a * (Rs/R0)^b` de un sensor MQ, ajustada a
+/// partir de la gráfica log-log de su hoja de datos para un gas concreto.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GasCurve {
+    pub a: f64,
+    pub b: f64,
+}
+
+impl GasCurve {
+    /// MQ-2 frente a GLP.
+    pub const MQ2_LPG: GasCurve = GasCurve { a: 574.25, b: -2.222 };
+    /// MQ-2 frente a humo.
+    pub const MQ2_SMOKE: GasCurve = GasCurve { a: 3616.1, b: -2.675 };
+    /// MQ-135 frente a CO₂.
+    pub const MQ135_CO2: GasCurve = GasCurve { a: 110.47, b: -2.862 };
+
+    /// Estima la concentración en ppm para la relación `Rs/R0` dada.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::devices::sensors::gas::GasCurve;
+    ///
+    /// let curve = GasCurve { a: 100.0, b: -2.0 };
+    /// assert_eq!(curve.ppm(1.0), 100.0); // en aire limpio Rs = R0
+    /// assert_eq!(curve.ppm(0.5), 400.0); // menos resistencia → más gas
+    /// assert!((GasCurve::MQ135_CO2.ppm(1.0) - 110.47).abs() < 1e-9);
+    /// ```
+    pub fn ppm(&self, ratio: f64) -> f64 {
+        self.a * ratio.powf(self.b)
+    }
+}
+
+/// Resistencia de carga por defecto de los módulos MQ comerciales (10 kΩ).
+pub const DEFAULT_LOAD_OHMS: f64 = 10_000.0;
+
+/// Calcula la resistencia del sensor `Rs` a partir de la lectura del ADC.
+///
+/// El módulo es un divisor de tensión: la salida vale `Vc * RL / (Rs + RL)`,
+/// por lo que `Rs = RL * (max - raw) / raw`. Devuelve `None` si `raw` es 0
+/// (resistencia infinita: sensor desconectado o sin alimentar).
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::sensors::gas::rs_from_raw;
+///
+/// // A media escala, Rs = RL.
+/// assert_eq!(rs_from_raw(512, 1024, 10_000.0), Some(10_000.0));
+/// assert_eq!(rs_from_raw(256, 1024, 10_000.0), Some(30_000.0));
+/// assert_eq!(rs_from_raw(0, 1024, 10_000.0), None);
+/// ```
+pub fn rs_from_raw(raw: u16, max: u16, load_ohms: f64) -> Option<f64> {
+    if raw == 0 {
+        return None;
+    }
+    Some(load_ohms * (max as f64 - raw as f64) / raw as f64)
+}
+
+/// `GasSensor` estima la concentración de un gas con un sensor de la serie MQ
+/// (MQ-2, MQ-135...) conectado a un canal de un [`Adc`].
+///
+/// Calcula `Rs` con [`rs_from_raw`], la normaliza con `R0` (la resistencia del
+/// sensor en aire limpio, obtenida al calibrarlo) y aplica la [`GasCurve`]
+/// configurada. Devuelve `SensorOutput::Float` en ppm (`Unit::Ppm`).
+///
+/// El elemento calefactor necesita un tiempo de calentamiento antes de dar
+/// valores válidos: hasta que pasa `warmup` desde la creación, `read()` devuelve
+/// `SensorError::Warmup` con el tiempo restante y el runtime omite la lectura.
+///
+/// # Ejemplo
+/// ```
+/// use std::error::Error;
+/// use std::time::Duration;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::devices::sensors::gas::{GasCurve, GasSensor};
+/// use iot_framework::drivers::adc::Adc;
+/// use iot_framework::{SensorOutput, Unit};
+///
+/// struct FakeAdc(u16);
+/// impl Adc for FakeAdc {
+///     fn max_value(&self) -> u16 { 1024 }
+///     fn read_raw(&mut self, _channel: u8) -> Result<u16, Box<dyn Error>> { Ok(self.0) }
+/// }
+///
+/// let curve = GasCurve { a: 100.0, b: -2.0 };
+/// // Rs = 10 kΩ con R0 = 20 kΩ → Rs/R0 = 0.5 → 400 ppm.
+/// let mut ready = GasSensor::new(FakeAdc(512), 0, curve, 20_000.0, Duration::ZERO);
+/// assert_eq!(ready.read().unwrap(), SensorOutput::Float(400.0));
+///
+/// let mut cold = GasSensor::new(FakeAdc(512), 0, curve, 20_000.0, Duration::from_secs(60));
+/// assert!(matches!(cold.read(), Err(SensorError::Warmup(_))));
+///
+/// let meta = ready.metadata();
+/// assert_eq!(meta.unit, Some(Unit::Ppm));
+/// assert_eq!((meta.min, meta.max), (Some(0.0), None));
+/// ```
+pub struct GasSensor<A: Adc> {
+    adc: A,
+    channel: u8,
+    curve: GasCurve,
+    r0_ohms: f64,
+    load_ohms: f64,
+    ready_at: Instant,
+}
+
+impl<A: Adc> GasSensor<A> {
+    /// Crea un `GasSensor` sobre el canal `channel` de `adc`.
+    ///
+    /// # Parámetros
+    /// - `curve`: curva del gas a medir (ver las constantes de [`GasCurve`]).
+    /// - `r0_ohms`: resistencia del sensor en aire limpio.
+    /// - `warmup`: tiempo de calentamiento desde ahora (24–48 h para la primera
+    ///   puesta en marcha, ~3 min en arranques posteriores según el fabricante).
+    pub fn new(adc: A, channel: u8, curve: GasCurve, r0_ohms: f64, warmup: Duration) -> Self {
+        Self {
+            adc,
+            channel,
+            curve,
+            r0_ohms,
+            load_ohms: DEFAULT_LOAD_OHMS,
+            ready_at: Instant::now() + warmup,
+        }
+    }
+
+    /// Cambia la resistencia de carga del módulo (por defecto [`DEFAULT_LOAD_OHMS`]).
+    pub fn with_load_resistance(mut self, load_ohms: f64) -> Self {
+        self.load_ohms = load_ohms;
+        self
+    }
+}
+
+impl<A: Adc> Sensor for GasSensor<A> {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let now = Instant::now();
+        if now < self.ready_at {
+            return Err(SensorError::Warmup(self.ready_at - now));
+        }
+        let raw = self
+            .adc
+            .read_raw(self.channel)
+            .map_err(|e| SensorError::ReadError(format!("adc: {}", e)))?;
+        let rs = rs_from_raw(raw, self.adc.max_value(), self.load_ohms)
+            .ok_or_else(|| SensorError::ReadError("salida nula del sensor de gas".to_string()))?;
+        let ppm = self.curve.ppm(rs / self.r0_ohms);
+        Ok(SensorOutput::Float(ppm as f32))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Ppm)
+    }
+
+    /// Concentración en ppm, sin máximo: la curva se extrapola fuera del
+    /// rango calibrado del fabricante.
+    fn metadata(&self) -> SensorMetadata {
+        let mut metadata = SensorMetadata::new("MQ-gas", SensorKind::Numeric).with_unit(self.unit());
+        metadata.min = Some(0.0);
+        metadata
+    }
+}
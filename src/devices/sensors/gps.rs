@@ -0,0 +1,280 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use crate::drivers::nmea;
+use rppal::uart::{Parity, Queue, Uart};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read};
+use std::time::Duration;
+
+/// Velocidad habitual de los módulos GPS (NEO-6M, etc.).
+pub const DEFAULT_BAUD_RATE: u32 = 9600;
+
+/// Tiempo máximo sin recibir bytes antes de dar la lectura por fallida.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+/// Sentencias leídas como máximo en cada lectura; a 1 Hz un módulo emite unas
+/// 6-10 por segundo (GGA, RMC, GSA, GSV...).
+const MAX_SENTENCES: usize = 32;
+
+/// Posición extraída de una sentencia NMEA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsFix {
+    /// Latitud en grados decimales (negativa al sur).
+    pub latitude: f64,
+    /// Longitud en grados decimales (negativa al oeste).
+    pub longitude: f64,
+    /// Altitud sobre el nivel del mar en metros (solo `GGA`).
+    pub altitude: Option<f32>,
+    /// Calidad del fix según `GGA` (1 = GPS, 2 = DGPS, 4 = RTK...); `None` en `RMC`.
+    pub fix_quality: Option<u8>,
+    /// Satélites usados en el cálculo (solo `GGA`).
+    pub satellites: Option<u8>,
+}
+
+/// `Map` con las claves `"lat"`, `"lon"` y, si se conocen, `"altitude"`,
+/// `"fix_quality"` y `"satellites"`.
+impl From<GpsFix> for SensorOutput {
+    fn from(fix: GpsFix) -> Self {
+        let mut fields = BTreeMap::from([
+            ("lat".to_string(), fix.latitude as f32),
+            ("lon".to_string(), fix.longitude as f32),
+        ]);
+        let optional = [
+            ("altitude", fix.altitude),
+            ("fix_quality", fix.fix_quality.map(f32::from)),
+            ("satellites", fix.satellites.map(f32::from)),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                fields.insert(key.to_string(), value);
+            }
+        }
+        SensorOutput::Map(fields)
+    }
+}
+
+/// Interpreta una sentencia NMEA `GGA` o `RMC` (de cualquier emisor: `$GP`,
+/// `$GN`, `$GL`...).
+///
+/// # Retorna
+/// - `Ok(Some(fix))` con la posición.
+/// - `Ok(None)` si es otra sentencia (`GSV`, `VTG`...) o una línea que no es NMEA.
+/// - `Err(SensorError::NoFix)` si el receptor indica que no tiene posición.
+/// - `Err(SensorError::ParseError)` si el checksum no coincide o falta (ver
+///   [`nmea::validate_checksum`]) o faltan campos.
+///
+/// # Ejemplo
+/// Sentencias capturadas de un receptor:
+/// ```
+/// use iot_framework::core::traits::sensor::SensorError;
+/// use iot_framework::devices::sensors::gps::parse_sentence;
+///
+/// let gga = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+/// let fix = parse_sentence(gga).unwrap().unwrap();
+/// assert!((fix.latitude - 48.1173).abs() < 1e-6 && (fix.longitude - 11.516_667).abs() < 1e-6);
+/// assert_eq!((fix.altitude, fix.fix_quality, fix.satellites), (Some(545.4), Some(1), Some(8)));
+///
+/// // Sur y oeste son negativos; `$GN` combina varias constelaciones.
+/// let gga = "$GNGGA,101530.00,4024.99960,N,00342.22200,W,2,11,0.85,657.3,M,51.2,M,,*53";
+/// assert!((parse_sentence(gga).unwrap().unwrap().longitude + 3.703_7).abs() < 1e-6);
+///
+/// let rmc = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+/// assert_eq!(parse_sentence(rmc).unwrap().unwrap().altitude, None);
+///
+/// // Sin fix: calidad 0 en GGA, estado `V` en RMC.
+/// assert!(matches!(parse_sentence("$GPGGA,,,,,,0,00,99.99,,,,,,*48"), Err(SensorError::NoFix)));
+/// assert!(matches!(parse_sentence("$GPRMC,,V,,,,,,,,,,N*53"), Err(SensorError::NoFix)));
+///
+/// // Un carácter alterado por ruido en la línea serie.
+/// let noisy = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.9,M,46.9,M,,*47";
+/// assert!(matches!(parse_sentence(noisy), Err(SensorError::ParseError(_))));
+///
+/// let gsv = "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74";
+/// assert_eq!(parse_sentence(gsv).unwrap(), None);
+/// ```
+pub fn parse_sentence(line: &str) -> Result<Option<GpsFix>, SensorError> {
+    let line = line.trim();
+    let Some(body) = line.strip_prefix('$') else {
+        return Ok(None);
+    };
+    if !nmea::validate_checksum(body) {
+        return Err(SensorError::ParseError(format!("checksum NMEA inválido: {}", line)));
+    }
+    // `validate_checksum` garantiza que hay un `*`.
+    let payload = body.rsplit_once('*').map_or(body, |(payload, _)| payload);
+
+    let fields: Vec<&str> = payload.split(',').collect();
+    let missing = || SensorError::ParseError(format!("sentencia NMEA incompleta: {}", line));
+    let field = |i: usize| fields.get(i).copied().ok_or_else(missing);
+    if fields[0].ends_with("GGA") {
+        let quality: u8 = field(6)?.parse().unwrap_or(0);
+        if quality == 0 {
+            return Err(SensorError::NoFix);
+        }
+        Ok(Some(GpsFix {
+            latitude: coordinate(field(2)?, field(3)?)?,
+            longitude: coordinate(field(4)?, field(5)?)?,
+            altitude: field(9)?.parse().ok(),
+            fix_quality: Some(quality),
+            satellites: field(7)?.parse().ok(),
+        }))
+    } else if fields[0].ends_with("RMC") {
+        if field(2)? != "A" {
+            return Err(SensorError::NoFix);
+        }
+        Ok(Some(GpsFix {
+            latitude: coordinate(field(3)?, field(4)?)?,
+            longitude: coordinate(field(5)?, field(6)?)?,
+            altitude: None,
+            fix_quality: None,
+            satellites: None,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Convierte `gggmm.mmmm` y su hemisferio en grados decimales.
+fn coordinate(value: &str, hemisphere: &str) -> Result<f64, SensorError> {
+    let invalid = || SensorError::ParseError(format!("coordenada NMEA inválida: {} {}", value, hemisphere));
+    let dot = value.find('.').unwrap_or(value.len());
+    if dot < 2 {
+        return Err(invalid());
+    }
+    let degrees: f64 = value[..dot - 2].parse().map_err(|_| invalid())?;
+    let minutes: f64 = value[dot - 2..].parse().map_err(|_| invalid())?;
+    let decimal = degrees + minutes / 60.0;
+    match hemisphere {
+        "N" | "E" => Ok(decimal),
+        "S" | "W" => Ok(-decimal),
+        _ => Err(invalid()),
+    }
+}
+
+/// Puerto UART expuesto como `Read` (rppal no lo implementa).
+struct UartReader(Uart);
+
+impl Read for UartReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf).map_err(io::Error::other)
+    }
+}
+
+/// Origen de las sentencias.
+enum Source {
+    Uart(BufReader<UartReader>),
+    Reader(BufReader<Box<dyn Read + Send>>),
+}
+
+/// `GpsSensor` lee la posición de un módulo GPS que emite NMEA 0183 por un
+/// puerto serie.
+///
+/// Cada lectura descarta lo que el puerto acumuló desde la anterior y lee
+/// sentencias hasta encontrar un `GGA` (con altitud y calidad del fix); si solo
+/// llega un `RMC` válido se usa este. Las sentencias corruptas (checksum que no
+/// coincide) se descartan. Devuelve el `SensorOutput::Map` de [`GpsFix`]; si el
+/// receptor aún no tiene posición devuelve `SensorError::NoFix` en lugar de
+/// coordenadas a cero.
+///
+/// # Ejemplo
+/// ```
+/// use std::io::Cursor;
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::devices::sensors::gps::GpsSensor;
+/// use iot_framework::SensorOutput;
+///
+/// let capture = "$GPRMC,,V,,,,,,,,,,N*53\r\n\
+///                $GPGGA,,,,,,0,00,99.99,,,,,,*48\r\n\
+///                $GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74\r\n\
+///                $GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.9,M,46.9,M,,*47\r\n\
+///                $GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+/// let mut gps = GpsSensor::from_reader(Cursor::new(capture));
+///
+/// assert!(matches!(gps.read(), Err(SensorError::NoFix)));
+/// assert!(matches!(gps.read(), Err(SensorError::NoFix)));
+/// // La sentencia corrupta se descarta y se usa la siguiente.
+/// let SensorOutput::Map(fix) = gps.read().unwrap() else { panic!() };
+/// assert_eq!((fix["altitude"], fix["satellites"]), (545.4, 8.0));
+/// // Sin más datos la lectura falla.
+/// assert!(matches!(gps.read(), Err(SensorError::ReadError(_))));
+/// ```
+pub struct GpsSensor {
+    source: Source,
+}
+
+impl GpsSensor {
+    /// Abre el puerto `path` (p. ej. `"/dev/serial0"`) a `baud_rate` baudios
+    /// (normalmente [`DEFAULT_BAUD_RATE`]), en modo 8N1.
+    pub fn new(path: &str, baud_rate: u32) -> Result<Self, SensorError> {
+        let mut uart = Uart::with_path(path, baud_rate, Parity::None, 8, 1)
+            .map_err(|e| SensorError::ReadError(format!("{}: {}", path, e)))?;
+        uart.set_read_mode(0, READ_TIMEOUT)
+            .map_err(|e| SensorError::ReadError(format!("{}: {}", path, e)))?;
+        Ok(Self {
+            source: Source::Uart(BufReader::new(UartReader(uart))),
+        })
+    }
+
+    /// Lee las sentencias de cualquier `Read` (un archivo de captura, un
+    /// pseudo-terminal...). El final de los datos se trata como un puerto sin
+    /// respuesta.
+    pub fn from_reader(reader: impl Read + Send + 'static) -> Self {
+        let reader: Box<dyn Read + Send> = Box::new(reader);
+        Self {
+            source: Source::Reader(BufReader::new(reader)),
+        }
+    }
+
+    /// Descarta las sentencias antiguas que el puerto acumuló entre lecturas.
+    fn discard_pending(&mut self) {
+        if let Source::Uart(reader) = &mut self.source {
+            let buffered = reader.buffer().len();
+            reader.consume(buffered);
+            let _ = reader.get_ref().0.flush(Queue::Input);
+        }
+    }
+
+    fn read_line(&mut self, line: &mut Vec<u8>) -> io::Result<usize> {
+        match &mut self.source {
+            Source::Uart(reader) => reader.read_until(b'\n', line),
+            Source::Reader(reader) => reader.read_until(b'\n', line),
+        }
+    }
+}
+
+impl Sensor for GpsSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.discard_pending();
+        let mut fallback = None;
+        let mut last_error = None;
+        let mut line = Vec::new();
+        for _ in 0..MAX_SENTENCES {
+            line.clear();
+            let read = self
+                .read_line(&mut line)
+                .map_err(|e| SensorError::ReadError(format!("gps: {}", e)))?;
+            if read == 0 {
+                break;
+            }
+            match parse_sentence(&String::from_utf8_lossy(&line)) {
+                Ok(Some(fix)) if fix.fix_quality.is_some() => return Ok(fix.into()),
+                Ok(Some(fix)) => fallback = Some(fix),
+                Ok(None) => {}
+                Err(SensorError::NoFix) => return Err(SensorError::NoFix),
+                // Sentencia corrupta: se descarta y se espera la siguiente.
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match (fallback, last_error) {
+            (Some(fix), _) => Ok(fix.into()),
+            (None, Some(e)) => Err(e),
+            (None, None) => Err(SensorError::ReadError("gps: sin sentencias GGA ni RMC".to_string())),
+        }
+    }
+
+    /// Lectura compuesta (`lat`, `lon`, `altitude`...), sin unidad ni rango comunes.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("GPS", SensorKind::Composite)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::traits::sensor::{AsyncSensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use crate::drivers::gpio::{GpioDriver, Trigger};
+use async_trait::async_trait;
+use tokio::sync::mpsc::Receiver;
+
+/// InterruptSensor: emite una lectura cada vez que cambia un pin digital.
+///
+/// En lugar de muestrear el pin cada intervalo, usa las interrupciones
+/// asíncronas de rppal, de modo que no se pierden eventos breves (pulsos,
+/// detectores de movimiento) ni se desperdicia CPU.
+///
+/// Cada llamada a `read()` espera al siguiente flanco y devuelve
+/// `SensorOutput::Bool` con el nivel resultante (true = HIGH). Debe registrarse
+/// en el runtime con `RuntimeController::add_async_sensor` y un intervalo
+/// `Duration::ZERO`. Los flancos pendientes se guardan en una cola acotada
+/// (ver [`GpioDriver::edge_events`]).
+///
+/// # Ejemplo
+/// ```
+/// # #[cfg(feature = "mock")] {
+/// use iot_framework::core::traits::sensor::AsyncSensor;
+/// use iot_framework::devices::sensors::interrupt::InterruptSensor;
+/// use iot_framework::drivers::gpio::{set_mock_level, Trigger};
+/// use iot_framework::SensorOutput;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// // El pin simulado arranca en HIGH (pull-up).
+/// let mut button = InterruptSensor::new(5, Trigger::Both).unwrap();
+/// set_mock_level(5, false);
+/// set_mock_level(5, true);
+/// set_mock_level(5, false);
+/// for expected in [false, true, false] {
+///     assert_eq!(button.read().await.unwrap(), SensorOutput::Bool(expected));
+/// }
+/// # });
+/// # }
+/// ```
+pub struct InterruptSensor {
+    /// Se conserva el driver: al descartarlo rppal elimina la interrupción.
+    _gpio: GpioDriver,
+    events: Receiver<bool>,
+}
+
+impl InterruptSensor {
+    /// Crea un InterruptSensor en el pin BCM indicado para los flancos `trigger`.
+    pub fn new(pin: u8, trigger: Trigger) -> Result<Self, SensorError> {
+        let mut gpio = GpioDriver::new(pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        let events = gpio
+            .edge_events(trigger)
+            .map_err(|e| SensorError::ReadError(format!("interrupción: {}", e)))?;
+        Ok(Self { _gpio: gpio, events })
+    }
+}
+
+#[async_trait]
+impl AsyncSensor for InterruptSensor {
+    type Output = SensorOutput;
+
+    async fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.events
+            .recv()
+            .await
+            .map(SensorOutput::Bool)
+            .ok_or_else(|| SensorError::ReadError("interrupción GPIO cerrada".to_string()))
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("gpio-interrupt", SensorKind::Binary)
+    }
+}
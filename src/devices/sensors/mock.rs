@@ -0,0 +1,156 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::types::short_type_name;
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Qué hace un [`MockSensor`] al agotar su secuencia de valores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockEnd {
+    /// Vuelve a empezar desde el primer valor.
+    Cycle,
+    /// Devuelve `SensorError::ReadError` en cada lectura posterior.
+    Error,
+}
+
+/// `MockSensor` devuelve, en orden, los valores de una secuencia predefinida.
+///
+/// Pensado como doble de prueba para ejercitar el runtime y los decoradores
+/// sin hardware. Con una secuencia vacía cada lectura falla.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::mock::{MockEnd, MockSensor};
+/// use iot_framework::SensorOutput;
+///
+/// let mut sensor = MockSensor::new(vec![SensorOutput::Int(1), SensorOutput::Int(2)], MockEnd::Error);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Int(1));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Int(2));
+/// assert!(sensor.read().is_err());
+/// ```
+pub struct MockSensor {
+    values: Vec<SensorOutput>,
+    next: usize,
+    end: MockEnd,
+    metadata: Option<SensorMetadata>,
+}
+
+impl MockSensor {
+    /// Crea un `MockSensor` con la secuencia `values` y el comportamiento `end` al agotarla.
+    pub fn new(values: Vec<SensorOutput>, end: MockEnd) -> Self {
+        Self { values, next: 0, end, metadata: None }
+    }
+
+    /// Atajo para una secuencia que se repite indefinidamente.
+    pub fn cycling(values: Vec<SensorOutput>) -> Self {
+        Self::new(values, MockEnd::Cycle)
+    }
+
+    /// Declara los metadatos (y con ellos la unidad) que informará el sensor,
+    /// para simular un dispositivo concreto.
+    pub fn with_metadata(mut self, metadata: SensorMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Número de lecturas realizadas hasta ahora.
+    pub fn reads(&self) -> usize {
+        self.next
+    }
+}
+
+impl Sensor for MockSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let index = match self.end {
+            MockEnd::Cycle if !self.values.is_empty() => self.next % self.values.len(),
+            _ => self.next,
+        };
+        self.next += 1;
+        self.values
+            .get(index)
+            .cloned()
+            .ok_or_else(|| SensorError::ReadError("secuencia simulada agotada".to_string()))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.metadata.as_ref().and_then(|m| m.unit)
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.metadata
+            .clone()
+            .unwrap_or_else(|| SensorMetadata::new(short_type_name::<Self>(), SensorKind::Unknown))
+    }
+}
+
+/// `FailingSensor` devuelve un valor fijo o un `SensorError` a demanda.
+///
+/// El fallo puede programarse para las próximas `n` lecturas con
+/// [`fail_next`](Self::fail_next) o activarse desde fuera mediante el
+/// interruptor compartido de [`switch`](Self::switch), útil cuando el sensor ya
+/// se entregó al runtime.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::decorators::RetrySensor;
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::mock::FailingSensor;
+/// use iot_framework::SensorOutput;
+/// use std::time::Duration;
+///
+/// let flaky = FailingSensor::new(SensorOutput::Float(21.0)).fail_next(2);
+/// let mut sensor = RetrySensor::new(flaky, 3, Duration::ZERO);
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(21.0));
+/// ```
+pub struct FailingSensor {
+    value: SensorOutput,
+    pending_failures: u32,
+    failing: Arc<AtomicBool>,
+}
+
+impl FailingSensor {
+    /// Crea un sensor sano que devuelve `value` en cada lectura.
+    pub fn new(value: SensorOutput) -> Self {
+        Self {
+            value,
+            pending_failures: 0,
+            failing: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Crea un sensor que falla siempre (hasta que se desactive su interruptor).
+    pub fn always() -> Self {
+        let sensor = Self::new(SensorOutput::Bool(false));
+        sensor.failing.store(true, Ordering::SeqCst);
+        sensor
+    }
+
+    /// Hace que las próximas `n` lecturas fallen.
+    pub fn fail_next(mut self, n: u32) -> Self {
+        self.pending_failures = n;
+        self
+    }
+
+    /// Interruptor compartido: mientras valga `true`, cada lectura falla.
+    pub fn switch(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.failing)
+    }
+}
+
+impl Sensor for FailingSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        if self.pending_failures > 0 {
+            self.pending_failures -= 1;
+            return Err(SensorError::ReadError("fallo simulado".to_string()));
+        }
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(SensorError::ReadError("fallo simulado".to_string()));
+        }
+        Ok(self.value.clone())
+    }
+}
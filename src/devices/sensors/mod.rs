@@ -1,3 +1,21 @@
 pub mod simulated_sensor;
+pub mod mock;
 pub mod rain;
-pub mod temperature;
\ No newline at end of file
+pub mod rain_gauge;
+pub mod temperature;
+pub mod dht22;
+pub mod bmp280;
+pub mod bh1750;
+pub mod interrupt;
+pub mod motion;
+pub mod ultrasonic;
+pub mod soil;
+pub mod gas;
+pub mod clock;
+pub mod counter;
+pub mod virtual_sensor;
+pub mod pulse;
+pub mod sht31;
+pub mod gps;
+pub mod composite;
+pub mod replay;
@@ -0,0 +1,115 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use crate::drivers::debounce::LevelSource;
+use crate::drivers::gpio::{GpioDriver, Trigger};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// MotionSensor: detector de movimiento PIR (HC-SR501, AM312...).
+///
+/// Devuelve `SensorOutput::Bool(true)` mientras el módulo indica movimiento
+/// (salida en HIGH) y durante la ventana de retención (`hold`) posterior a la
+/// última detección, de modo que un disparo breve entre dos lecturas no se
+/// reduzca a un único `true` perdido. Sin retención, la salida sigue al pin.
+///
+/// Modos de funcionamiento:
+/// - **Sondeo** ([`MotionSensor::new`]): solo se observa el pin al leer.
+/// - **Interrupción** ([`MotionSensor::with_interrupts`]): cada flanco de subida
+///   se registra en segundo plano, así que incluso un pulso más corto que el
+///   intervalo de lectura abre la ventana de retención.
+///
+/// # Ejemplo
+/// ```
+/// use std::cell::Cell;
+/// use std::time::Duration;
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::motion::MotionSensor;
+/// use iot_framework::drivers::debounce::LevelSource;
+/// use iot_framework::{SensorKind, SensorOutput};
+///
+/// struct Pir(Cell<bool>);
+/// impl LevelSource for Pir {
+///     fn read_bool(&self) -> bool {
+///         self.0.replace(false) // un único pulso
+///     }
+/// }
+///
+/// let mut sensor = MotionSensor::from_source(Pir(Cell::new(true))).with_hold(Duration::from_millis(100));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Bool(true));
+/// // El pin ya volvió a LOW, pero la ventana de retención sigue abierta.
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Bool(true));
+/// std::thread::sleep(Duration::from_millis(150));
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Bool(false));
+/// assert_eq!(sensor.metadata().kind, SensorKind::Binary);
+/// ```
+pub struct MotionSensor<L = GpioDriver> {
+    source: L,
+    hold: Duration,
+    /// Instante de la última detección; en modo interrupción lo actualiza también
+    /// el callback de rppal.
+    last_motion: Arc<Mutex<Option<Instant>>>,
+}
+
+impl MotionSensor<GpioDriver> {
+    /// Crea un MotionSensor en modo sondeo sobre el pin BCM indicado.
+    pub fn new(pin: u8) -> Result<Self, SensorError> {
+        let gpio = GpioDriver::new(pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        Ok(Self::from_source(gpio))
+    }
+
+    /// Crea un MotionSensor que registra los flancos de subida mediante
+    /// interrupciones, sin perder pulsos entre lecturas.
+    pub fn with_interrupts(pin: u8) -> Result<Self, SensorError> {
+        let mut sensor = Self::new(pin)?;
+        let last_motion = Arc::clone(&sensor.last_motion);
+        sensor
+            .source
+            .on_edge(Trigger::RisingEdge, move |_| {
+                if let Ok(mut last) = last_motion.lock() {
+                    *last = Some(Instant::now());
+                }
+            })
+            .map_err(|e| SensorError::ReadError(format!("interrupción: {}", e)))?;
+        Ok(sensor)
+    }
+}
+
+impl<L: LevelSource> MotionSensor<L> {
+    /// Crea un MotionSensor sobre cualquier fuente de nivel (HIGH = movimiento).
+    pub fn from_source(source: L) -> Self {
+        Self {
+            source,
+            hold: Duration::ZERO,
+            last_motion: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Mantiene la salida en `true` durante `hold` tras la última detección.
+    pub fn with_hold(mut self, hold: Duration) -> Self {
+        self.hold = hold;
+        self
+    }
+}
+
+impl<L: LevelSource> Sensor for MotionSensor<L> {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let now = Instant::now();
+        let active = self.source.read_bool();
+        let mut last = self
+            .last_motion
+            .lock()
+            .map_err(|_| SensorError::ReadError("estado de movimiento no disponible".to_string()))?;
+        if active {
+            *last = Some(now);
+        }
+        let held = last.is_some_and(|t| now.duration_since(t) < self.hold);
+        Ok(SensorOutput::Bool(active || held))
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("PIR", SensorKind::Binary)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::gpio::{EdgeSource, GpioDriver, Trigger};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// PulseCounter: cuenta los pulsos de un caudalímetro o un medidor de energía.
+///
+/// Cada flanco que coincide con `trigger` incrementa un contador atómico desde
+/// el hilo de interrupciones, así que no se pierden pulsos entre lecturas.
+/// `read()` devuelve los pulsos acumulados desde la lectura anterior y pone el
+/// contador a cero: con un intervalo fijo, el valor es proporcional al caudal.
+///
+/// Sin calibrar la lectura es `SensorOutput::Int` con los pulsos; con
+/// [`with_calibration`](Self::with_calibration) es `SensorOutput::Float` en
+/// unidades de ingeniería (litros, kWh...).
+///
+/// # Ejemplo
+/// ```
+/// use std::error::Error;
+/// use std::sync::{Arc, Mutex};
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::pulse::PulseCounter;
+/// use iot_framework::drivers::gpio::{EdgeSource, Trigger};
+/// use iot_framework::SensorOutput;
+///
+/// /// Pin simulado: `pulse()` invoca el callback como lo haría una interrupción.
+/// #[derive(Clone, Default)]
+/// struct FakePin(Arc<Mutex<Option<Box<dyn FnMut(bool) + Send>>>>);
+/// impl FakePin {
+///     fn pulse(&self) {
+///         (self.0.lock().unwrap().as_mut().unwrap())(false)
+///     }
+/// }
+/// impl EdgeSource for FakePin {
+///     fn on_edge<C>(&mut self, _: Trigger, callback: C) -> Result<(), Box<dyn Error>>
+///     where
+///         C: FnMut(bool) + Send + 'static,
+///     {
+///         *self.0.lock().unwrap() = Some(Box::new(callback));
+///         Ok(())
+///     }
+/// }
+///
+/// let pin = FakePin::default();
+/// let mut meter = PulseCounter::from_source(pin.clone(), Trigger::FallingEdge).unwrap();
+/// for _ in 0..3 {
+///     pin.pulse();
+/// }
+/// assert_eq!(meter.read().unwrap(), SensorOutput::Int(3));
+/// // El contador se reinicia con cada lectura.
+/// assert_eq!(meter.read().unwrap(), SensorOutput::Int(0));
+///
+/// // Flancos desde varios hilos a la vez.
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| {
+///         let pin = pin.clone();
+///         std::thread::spawn(move || (0..250).for_each(|_| pin.pulse()))
+///     })
+///     .collect();
+/// threads.into_iter().for_each(|t| t.join().unwrap());
+/// assert_eq!(meter.read().unwrap(), SensorOutput::Int(1000));
+///
+/// // Caudalímetro YF-S201: unos 450 pulsos por litro.
+/// let mut meter = meter.with_calibration(450.0, None);
+/// (0..900).for_each(|_| pin.pulse());
+/// assert_eq!(meter.read().unwrap(), SensorOutput::Float(2.0));
+/// ```
+pub struct PulseCounter<E = GpioDriver> {
+    /// Se conserva la fuente: al descartar un `GpioDriver` rppal elimina la interrupción.
+    _source: E,
+    pulses: Arc<AtomicU64>,
+    /// Pulsos por unidad de ingeniería; `None` devuelve los pulsos crudos.
+    pulses_per_unit: Option<f32>,
+    unit: Option<Unit>,
+}
+
+impl PulseCounter<GpioDriver> {
+    /// Crea un PulseCounter en el pin BCM indicado, contando los flancos `trigger`
+    /// (normalmente `Trigger::FallingEdge` con salidas de colector abierto).
+    pub fn new(pin: u8, trigger: Trigger) -> Result<Self, SensorError> {
+        let gpio = GpioDriver::new(pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        Self::from_source(gpio, trigger)
+    }
+}
+
+impl<E: EdgeSource> PulseCounter<E> {
+    /// Crea un PulseCounter sobre cualquier fuente de flancos.
+    pub fn from_source(mut source: E, trigger: Trigger) -> Result<Self, SensorError> {
+        let pulses = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&pulses);
+        source
+            .on_edge(trigger, move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .map_err(|e| SensorError::ReadError(format!("interrupción: {}", e)))?;
+        Ok(Self {
+            _source: source,
+            pulses,
+            pulses_per_unit: None,
+            unit: None,
+        })
+    }
+}
+
+impl<E> PulseCounter<E> {
+    /// Convierte los pulsos a unidades de ingeniería: cada lectura devuelve
+    /// `pulsos / pulses_per_unit` (p. ej. litros con un caudalímetro de 450
+    /// pulsos por litro, o kWh con un medidor de 1000 imp/kWh).
+    pub fn with_calibration(mut self, pulses_per_unit: f32, unit: Option<Unit>) -> Self {
+        self.pulses_per_unit = Some(pulses_per_unit);
+        self.unit = unit;
+        self
+    }
+
+    /// Pulsos acumulados desde la última lectura, sin reiniciar el contador.
+    pub fn pending(&self) -> u64 {
+        self.pulses.load(Ordering::Relaxed)
+    }
+}
+
+impl<E> Sensor for PulseCounter<E> {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let pulses = self.pulses.swap(0, Ordering::Relaxed);
+        Ok(match self.pulses_per_unit {
+            Some(factor) => SensorOutput::Float(pulses as f32 / factor),
+            None => SensorOutput::Int(pulses as i64),
+        })
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    /// Sin máximo: depende del caudal y del intervalo de lectura.
+    fn metadata(&self) -> SensorMetadata {
+        let mut metadata = SensorMetadata::new("pulse-counter", SensorKind::Numeric).with_unit(self.unit);
+        metadata.min = Some(0.0);
+        metadata
+    }
+}
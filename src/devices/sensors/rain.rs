@@ -1,26 +1,63 @@
 use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::drivers::debounce::{DebouncedInput, LevelSource};
 use crate::drivers::gpio::GpioDriver;
-use crate::core::SensorOutput;
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use std::time::Duration;
 
 /// RainSensor: interpreta la salida digital (DO) del módulo de lluvia.
 /// Atención: muchos módulos DO = LOW cuando está mojado (active low).
-pub struct RainSensor {
-    gpio: GpioDriver,
+///
+/// Solo distingue mojado/seco; para medir la cantidad de lluvia está
+/// [`RainGauge`](crate::devices::sensors::rain_gauge::RainGauge).
+pub struct RainSensor<L = GpioDriver> {
+    /// Pin de entrada; sin antirrebote salvo que se active con `with_debounce`.
+    gpio: DebouncedInput<L>,
     /// Si el módulo está activo en LOW (true) o en HIGH (false).
     /// Muchos módulos usan active_low = true por defecto.
     active_low: bool,
 }
 
-impl RainSensor {
+impl RainSensor<GpioDriver> {
     /// Crea un RainSensor en el pin BCM indicado.
     /// active_low = true si DO = LOW cuando hay agua (común).
+    ///
+    /// # Errores
+    /// - `SensorError::PermissionDenied` si no hay acceso a `/dev/gpiomem`.
+    /// - `SensorError::NotFound` si el pin no existe en esta placa.
+    /// - `SensorError::ReadError` si el pin está en uso o la placa no se reconoce.
     pub fn new(pin: u8, active_low: bool) -> Result<Self, SensorError> {
-        let gpio = GpioDriver::new(pin).map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
-        Ok(Self { gpio, active_low })
+        let gpio = GpioDriver::new(pin).map_err(|e| gpio_init_error(pin, e))?;
+        Ok(Self::from_source(gpio, active_low))
     }
 }
 
-impl Sensor for RainSensor {
+/// Traduce un error de rppal al iniciar el pin en el `SensorError` adecuado.
+pub(crate) fn gpio_init_error(pin: u8, error: Box<dyn std::error::Error>) -> SensorError {
+    match error.downcast::<rppal::gpio::Error>() {
+        Ok(error) => match *error {
+            rppal::gpio::Error::PermissionDenied(path) => SensorError::PermissionDenied(path),
+            rppal::gpio::Error::PinNotAvailable(pin) => SensorError::NotFound(format!("GPIO {}", pin)),
+            rppal::gpio::Error::Io(e) => SensorError::from_io(e, format!("GPIO {}", pin)),
+            other => SensorError::ReadError(format!("gpio init: {}", other)),
+        },
+        Err(other) => SensorError::ReadError(format!("gpio init: {}", other)),
+    }
+}
+
+impl<L: LevelSource> RainSensor<L> {
+    /// Crea un RainSensor sobre cualquier fuente de nivel.
+    pub fn from_source(source: L, active_low: bool) -> Self {
+        Self { gpio: DebouncedInput::passthrough(source), active_low }
+    }
+
+    /// Activa el antirrebote: exige `samples` lecturas iguales separadas por `spacing`.
+    pub fn with_debounce(mut self, samples: u32, spacing: Duration) -> Self {
+        self.gpio.set_debounce(samples, spacing);
+        self
+    }
+}
+
+impl<L: LevelSource> Sensor for RainSensor<L> {
     type Output = SensorOutput; // true = MOJADO, false = SECO
 
     fn read(&mut self) -> Result<Self::Output, SensorError> {
@@ -32,4 +69,29 @@ impl Sensor for RainSensor {
             if wet { "HÚMEDO".to_string() } else { "SECO".to_string() }
         ))
     }
+
+    /// Dos estados, `"HÚMEDO"` o `"SECO"`.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::Sensor;
+    /// use iot_framework::devices::sensors::rain::RainSensor;
+    /// use iot_framework::drivers::debounce::LevelSource;
+    /// use iot_framework::{SensorKind, SensorOutput};
+    ///
+    /// struct Pin(bool);
+    /// impl LevelSource for Pin {
+    ///     fn read_bool(&self) -> bool { self.0 }
+    /// }
+    ///
+    /// let mut sensor = RainSensor::from_source(Pin(false), true);
+    /// assert_eq!(sensor.read().unwrap(), SensorOutput::Text("HÚMEDO".into()));
+    ///
+    /// let meta = sensor.metadata();
+    /// assert_eq!(meta.kind, SensorKind::Discrete);
+    /// assert_eq!((meta.unit, meta.min, meta.max), (None, None, None));
+    /// ```
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("rain", SensorKind::Discrete)
+    }
 }
@@ -0,0 +1,196 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::gpio::{EdgeSource, GpioDriver, Trigger};
+use crate::devices::sensors::rain::gpio_init_error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Lluvia por vuelco de los pluviómetros de balancín más comunes (Misol
+/// WH-SP-RG, Davis, SparkFun SEN-15901): 0.2794 mm (0.011").
+pub const DEFAULT_MM_PER_TIP: f32 = 0.2794;
+
+/// Tiempo mínimo entre vuelcos por defecto. El interruptor reed rebota durante
+/// unos milisegundos, mientras que incluso con lluvia torrencial el balancín
+/// tarda más de un segundo en volcar de nuevo.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// RainGauge: estima la intensidad de lluvia con un pluviómetro de balancín.
+///
+/// Cada vuelco del balancín cierra un interruptor reed y produce un flanco en
+/// el pin; los flancos que llegan antes de `debounce` desde el último vuelco
+/// contado se consideran rebotes y se descartan. `read()` devuelve los
+/// milímetros de lluvia (`SensorOutput::Float`, `Unit::Millimeter`) caídos
+/// desde la lectura anterior, `vuelcos * mm_per_tip`: con un intervalo fijo,
+/// la lectura es proporcional a la intensidad.
+///
+/// Para saber solo si llueve, está [`RainSensor`](crate::devices::sensors::rain::RainSensor).
+///
+/// # Ejemplo
+/// ```
+/// use std::error::Error;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::{Duration, Instant};
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::rain_gauge::RainGauge;
+/// use iot_framework::drivers::gpio::{EdgeSource, Trigger};
+/// use iot_framework::SensorOutput;
+///
+/// /// Pin simulado: `tip()` invoca el callback como lo haría una interrupción.
+/// #[derive(Clone, Default)]
+/// struct FakePin(Arc<Mutex<Option<Box<dyn FnMut(bool) + Send>>>>);
+/// impl FakePin {
+///     fn tip(&self) {
+///         (self.0.lock().unwrap().as_mut().unwrap())(false)
+///     }
+/// }
+/// impl EdgeSource for FakePin {
+///     fn on_edge<C>(&mut self, _: Trigger, callback: C) -> Result<(), Box<dyn Error>>
+///     where
+///         C: FnMut(bool) + Send + 'static,
+///     {
+///         *self.0.lock().unwrap() = Some(Box::new(callback));
+///         Ok(())
+///     }
+/// }
+///
+/// let pin = FakePin::default();
+/// let mut gauge = RainGauge::from_source(pin.clone(), 0.5)
+///     .unwrap()
+///     .with_debounce(Duration::from_millis(5));
+///
+/// // Un flanco del pin cuenta como vuelco.
+/// pin.tip();
+/// assert_eq!(gauge.tips(), 1);
+///
+/// // Tres vuelcos más, cada uno con dos rebotes del reed a 1 y 2 ms.
+/// let start = Instant::now() + Duration::from_secs(1);
+/// for tip in 0..3 {
+///     let at = start + Duration::from_millis(10 * tip);
+///     for bounce in 0..3 {
+///         gauge.tip_at(at + Duration::from_millis(bounce));
+///     }
+/// }
+/// assert_eq!(gauge.read().unwrap(), SensorOutput::Float(2.0));
+/// // Cada lectura cuenta desde la anterior; el total se conserva.
+/// assert_eq!(gauge.read().unwrap(), SensorOutput::Float(0.0));
+/// assert_eq!(gauge.total_mm(), 2.0);
+/// ```
+pub struct RainGauge<E = GpioDriver> {
+    /// Se conserva la fuente: al descartar un `GpioDriver` rppal elimina la interrupción.
+    _source: E,
+    /// Cuenta de vuelcos, compartida con el callback de la interrupción.
+    counter: Arc<TipCounter>,
+    mm_per_tip: f32,
+    /// Vuelcos ya entregados en lecturas anteriores.
+    read_tips: u64,
+}
+
+/// Vuelcos contados y antirrebote, compartidos entre el sensor y el callback.
+struct TipCounter {
+    tips: AtomicU64,
+    /// Tiempo mínimo entre vuelcos, en microsegundos.
+    debounce_us: AtomicU64,
+    /// Instante del último vuelco contado.
+    last_tip: Mutex<Option<Instant>>,
+}
+
+impl TipCounter {
+    /// Cuenta un flanco ocurrido en `now` salvo que sea un rebote del anterior.
+    fn edge_at(&self, now: Instant) {
+        let debounce = Duration::from_micros(self.debounce_us.load(Ordering::Relaxed));
+        let mut last_tip = self.last_tip.lock().unwrap_or_else(|e| e.into_inner());
+        if last_tip.is_some_and(|last| now.saturating_duration_since(last) < debounce) {
+            return;
+        }
+        *last_tip = Some(now);
+        self.tips.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl RainGauge<GpioDriver> {
+    /// Crea un RainGauge en el pin BCM indicado, con `mm_per_tip` milímetros
+    /// por vuelco (ver [`DEFAULT_MM_PER_TIP`]). Cuenta los flancos de bajada:
+    /// el reed conecta el pin (con pull-up) a masa al volcar.
+    ///
+    /// # Errores
+    /// - `SensorError::PermissionDenied` si no hay acceso a `/dev/gpiomem`.
+    /// - `SensorError::NotFound` si el pin no existe en esta placa.
+    /// - `SensorError::ReadError` si el pin está en uso, la placa no se
+    ///   reconoce o no se pudo registrar la interrupción.
+    pub fn new(pin: u8, mm_per_tip: f32) -> Result<Self, SensorError> {
+        let gpio = GpioDriver::new(pin).map_err(|e| gpio_init_error(pin, e))?;
+        Self::from_source(gpio, mm_per_tip)
+    }
+}
+
+impl<E: EdgeSource> RainGauge<E> {
+    /// Crea un RainGauge sobre cualquier fuente de flancos.
+    pub fn from_source(mut source: E, mm_per_tip: f32) -> Result<Self, SensorError> {
+        let counter = Arc::new(TipCounter {
+            tips: AtomicU64::new(0),
+            debounce_us: AtomicU64::new(DEFAULT_DEBOUNCE.as_micros() as u64),
+            last_tip: Mutex::new(None),
+        });
+        let edges = Arc::clone(&counter);
+        source
+            .on_edge(Trigger::FallingEdge, move |_| edges.edge_at(Instant::now()))
+            .map_err(|e| SensorError::ReadError(format!("interrupción: {}", e)))?;
+        Ok(Self {
+            _source: source,
+            counter,
+            mm_per_tip,
+            read_tips: 0,
+        })
+    }
+}
+
+impl<E> RainGauge<E> {
+    /// Cambia el tiempo mínimo entre vuelcos (por defecto [`DEFAULT_DEBOUNCE`]).
+    pub fn with_debounce(self, debounce: Duration) -> Self {
+        self.counter.debounce_us.store(debounce.as_micros() as u64, Ordering::Relaxed);
+        self
+    }
+
+    /// Cuenta un flanco como si llegara en `at`; se descarta si cae dentro
+    /// del antirrebote del último vuelco contado.
+    ///
+    /// El callback de la interrupción lo invoca con `Instant::now()`; es
+    /// público para poder simular vuelcos con instantes conocidos.
+    pub fn tip_at(&self, at: Instant) {
+        self.counter.edge_at(at);
+    }
+
+    /// Vuelcos contados desde la creación.
+    pub fn tips(&self) -> u64 {
+        self.counter.tips.load(Ordering::Relaxed)
+    }
+
+    /// Lluvia acumulada desde la creación, en milímetros.
+    pub fn total_mm(&self) -> f32 {
+        self.tips() as f32 * self.mm_per_tip
+    }
+}
+
+impl<E> Sensor for RainGauge<E> {
+    type Output = SensorOutput;
+
+    /// Milímetros de lluvia desde la lectura anterior.
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let tips = self.tips();
+        let new_tips = tips - self.read_tips;
+        self.read_tips = tips;
+        Ok(SensorOutput::Float(new_tips as f32 * self.mm_per_tip))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Millimeter)
+    }
+
+    /// Sin máximo: depende de la intensidad y del intervalo de lectura.
+    fn metadata(&self) -> SensorMetadata {
+        let mut metadata = SensorMetadata::new("rain-gauge", SensorKind::Numeric).with_unit(self.unit());
+        metadata.min = Some(0.0);
+        metadata
+    }
+}
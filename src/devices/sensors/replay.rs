@@ -0,0 +1,376 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, SensorReading, Unit};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// `ReplaySensor` vuelve a emitir, en orden, lecturas grabadas de un sensor
+/// respetando el tiempo que pasó entre ellas.
+///
+/// Sirve para depurar y hacer demostraciones sin hardware: las lecturas
+/// históricas recorren el runtime como si fueran en vivo, de modo que alertas,
+/// umbrales y actuadores se pueden probar con datos reales. Se cargan desde un
+/// CSV con el formato de `CsvCommunicator` (ver [`from_csv`](Self::from_csv)),
+/// desde JSON con la feature `serde` (ver [`from_json`](Self::from_json)) o
+/// desde lecturas ya en memoria.
+///
+/// La primera lectura se emite de inmediato y cada una de las siguientes
+/// cuando pasa, desde la primera, la diferencia entre sus marcas de tiempo
+/// dividida por la velocidad ([`with_speed`](Self::with_speed); `1.0`, tiempo
+/// real, por defecto). `read()` bloquea hasta entonces, así que conviene
+/// registrarlo con intervalo `Duration::ZERO` (dirigido por eventos); las
+/// lecturas salen con la marca de tiempo del momento en que se reproducen.
+/// Al terminar, cada lectura devuelve `SensorError::ReadError` salvo con
+/// [`with_loop`](Self::with_loop), que vuelve a empezar.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, Instant, UNIX_EPOCH};
+/// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+/// use iot_framework::devices::sensors::replay::ReplaySensor;
+/// use iot_framework::SensorOutput;
+///
+/// let dir = std::env::temp_dir().join(format!("replay-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("grabacion.csv");
+/// std::fs::write(
+///     &path,
+///     "timestamp,sensor_id,seq,value\n\
+///      1000,temp,1,21.5\n\
+///      1000,hum,1,48\n\
+///      3000,temp,2,22.0\n\
+///      4000,temp,3,\"alto, revisar\"\n",
+/// )
+/// .unwrap();
+///
+/// // Diez veces más rápido: 2 s grabados son 200 ms de reproducción.
+/// let mut temp = ReplaySensor::from_csv(&path, "temp").unwrap().with_speed(10.0);
+/// assert_eq!(temp.remaining(), 3);
+///
+/// let start = Instant::now();
+/// let at = |ms| start + Duration::from_millis(ms);
+/// assert_eq!(temp.read_at(at(0)).unwrap(), SensorOutput::Float(21.5));
+/// assert!(matches!(temp.read_at(at(150)), Err(SensorError::Suppressed)));
+/// assert_eq!(temp.next_due(), Some(at(200)));
+/// assert_eq!(temp.read_at(at(200)).unwrap(), SensorOutput::Float(22.0));
+/// assert_eq!(temp.read_at(at(300)).unwrap(), SensorOutput::Text("alto, revisar".into()));
+/// assert!(matches!(temp.read_at(at(400)), Err(SensorError::ReadError(_))));
+///
+/// // Con `with_loop` la grabación vuelve a empezar al terminar.
+/// let mut hum = ReplaySensor::from_csv(&path, "hum").unwrap().with_loop();
+/// assert_eq!(hum.read_at(at(0)).unwrap(), SensorOutput::Int(48));
+/// assert_eq!(hum.read_at(at(10)).unwrap(), SensorOutput::Int(48));
+///
+/// // Lo que escribe `CsvCommunicator` se reproduce con la misma variante.
+/// # #[cfg(feature = "csv")] {
+/// use iot_framework::network::csv::CsvCommunicator;
+/// use iot_framework::{Communicator, SensorReading};
+///
+/// let recorded = dir.join("csv.csv");
+/// let mut csv = CsvCommunicator::new(&recorded, None).unwrap();
+/// let values = [SensorOutput::Float(22.0), SensorOutput::Int(22), SensorOutput::Float(-0.5)];
+/// for value in &values {
+///     let mut reading = SensorReading::new("temp", value.clone());
+///     reading.timestamp = UNIX_EPOCH;
+///     csv.send(reading).unwrap();
+/// }
+/// csv.flush().unwrap();
+/// let mut temp = ReplaySensor::from_csv(&recorded, "temp").unwrap().with_speed(f64::INFINITY);
+/// let replayed: Vec<_> = values.iter().map(|_| temp.read().unwrap()).collect();
+/// assert_eq!(replayed, values);
+/// # }
+///
+/// // Los CSV sin la columna `seq` también se aceptan.
+/// let old = dir.join("antigua.csv");
+/// std::fs::write(&old, "timestamp,sensor_id,value\n1000,temp,21.5\n").unwrap();
+/// let mut temp = ReplaySensor::from_csv(&old, "temp").unwrap();
+/// assert_eq!(temp.read().unwrap(), SensorOutput::Float(21.5));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub struct ReplaySensor {
+    /// Lecturas grabadas, ordenadas por marca de tiempo.
+    records: Vec<(SystemTime, SensorOutput)>,
+    next: usize,
+    speed: f64,
+    looping: bool,
+    unit: Option<Unit>,
+    /// Instante en que se emitió la primera lectura de la pasada actual.
+    started: Option<Instant>,
+}
+
+impl ReplaySensor {
+    /// Crea un `ReplaySensor` con las lecturas `records` (marca de tiempo y
+    /// valor), que se ordenan por marca de tiempo conservando el orden de las
+    /// que coinciden.
+    pub fn new(mut records: Vec<(SystemTime, SensorOutput)>) -> Self {
+        records.sort_by_key(|(timestamp, _)| *timestamp);
+        Self { records, next: 0, speed: 1.0, looping: false, unit: None, started: None }
+    }
+
+    /// Crea un `ReplaySensor` con las lecturas de `sensor_id` entre `readings`;
+    /// la unidad es la de la primera que la declare.
+    pub fn from_readings(readings: impl IntoIterator<Item = SensorReading>, sensor_id: &str) -> Self {
+        let mut unit = None;
+        let records = readings
+            .into_iter()
+            .filter(|reading| reading.sensor_id == sensor_id)
+            .map(|reading| {
+                unit = unit.or(reading.unit);
+                (reading.timestamp, reading.value)
+            })
+            .collect();
+        Self::new(records).with_unit(unit)
+    }
+
+    /// Carga las lecturas de `sensor_id` de un CSV `timestamp,sensor_id,seq,value`
+    /// (marca de tiempo en milisegundos desde el UNIX epoch, cabecera
+    /// opcional), como los que escribe `CsvCommunicator`. También acepta los
+    /// archivos `timestamp,sensor_id,value` anteriores a la columna `seq`, que
+    /// se ignora al reproducir.
+    ///
+    /// El CSV no guarda la variante de cada valor, así que se deduce del texto:
+    /// `true`/`false` → `Bool`, un entero → `Int`, otro número (`CsvCommunicator`
+    /// escribe los `Float` siempre con punto decimal, `22.0`) → `Float`, pares
+    /// `clave=valor` separados por `;` → `Map` y el resto `Text` (los `Bytes`
+    /// y `Timestamp` grabados vuelven como texto base64 y como entero).
+    ///
+    /// # Errores
+    /// - `NotFound`, `PermissionDenied` o `Io` si no se puede leer el archivo.
+    /// - `ParseError` si una fila no tiene tres o cuatro columnas o su marca de
+    ///   tiempo no es un entero.
+    pub fn from_csv(path: impl AsRef<Path>, sensor_id: &str) -> Result<Self, SensorError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| SensorError::from_io(e, path))?;
+        let mut records = Vec::new();
+        for (row, fields) in csv_rows(&text).into_iter().enumerate() {
+            if row == 0 && fields.first().is_some_and(|field| field == "timestamp") {
+                continue;
+            }
+            let (timestamp, id, value) = match <[String; 4]>::try_from(fields) {
+                Ok([timestamp, id, _seq, value]) => (timestamp, id, value),
+                Err(fields) => match <[String; 3]>::try_from(fields) {
+                    Ok([timestamp, id, value]) => (timestamp, id, value),
+                    Err(fields) => {
+                        return Err(SensorError::ParseError(format!(
+                            "{}: fila {} con {} columnas (se esperaban 3 o 4)",
+                            path.display(),
+                            row + 1,
+                            fields.len()
+                        )))
+                    }
+                },
+            };
+            if id != sensor_id {
+                continue;
+            }
+            let millis: u64 = timestamp.trim().parse().map_err(|_| {
+                SensorError::ParseError(format!(
+                    "{}: fila {}: marca de tiempo no válida \"{}\"",
+                    path.display(),
+                    row + 1,
+                    timestamp
+                ))
+            })?;
+            records.push((UNIX_EPOCH + Duration::from_millis(millis), parse_value(&value)));
+        }
+        Ok(Self::new(records))
+    }
+
+    /// Carga las lecturas de `sensor_id` de un archivo JSON con un array de
+    /// [`SensorReading`] o una lectura por línea (JSON Lines), en el formato
+    /// que publican los comunicadores. Conserva la variante de cada valor y la
+    /// unidad.
+    ///
+    /// # Errores
+    /// - `NotFound`, `PermissionDenied` o `Io` si no se puede leer el archivo.
+    /// - `ParseError` si el contenido no son lecturas válidas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::Sensor;
+    /// use iot_framework::devices::sensors::replay::ReplaySensor;
+    /// use iot_framework::{SensorOutput, Unit};
+    ///
+    /// let path = std::env::temp_dir().join(format!("replay-doc-{}.jsonl", std::process::id()));
+    /// std::fs::write(
+    ///     &path,
+    ///     r#"{"sensor_id":"temp","timestamp":2000,"value":{"Float":22.0},"unit":"Celsius"}
+    /// {"sensor_id":"temp","timestamp":1000,"value":{"Float":21.5},"unit":"Celsius"}
+    /// {"sensor_id":"puerta","timestamp":1500,"value":{"Bool":true}}
+    /// "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut temp = ReplaySensor::from_json(&path, "temp").unwrap().with_speed(f64::INFINITY);
+    /// assert_eq!(temp.unit(), Some(Unit::Celsius));
+    /// assert_eq!(temp.read().unwrap(), SensorOutput::Float(21.5));
+    /// assert_eq!(temp.read().unwrap(), SensorOutput::Float(22.0));
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(path: impl AsRef<Path>, sensor_id: &str) -> Result<Self, SensorError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| SensorError::from_io(e, path))?;
+        let invalid = |e: serde_json::Error| SensorError::ParseError(format!("{}: {}", path.display(), e));
+        let readings: Vec<SensorReading> = if text.trim_start().starts_with('[') {
+            serde_json::from_str(&text).map_err(invalid)?
+        } else {
+            text.lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()
+                .map_err(invalid)?
+        };
+        Ok(Self::from_readings(readings, sensor_id))
+    }
+
+    /// Multiplica la velocidad de reproducción: `2.0` emite al doble de ritmo
+    /// y `f64::INFINITY` sin esperas. Los valores no positivos o `NaN` se
+    /// tratan como `1.0` (tiempo real).
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = if speed > 0.0 { speed } else { 1.0 };
+        self
+    }
+
+    /// Vuelve a empezar por la primera lectura al terminar, en lugar de fallar.
+    pub fn with_loop(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Declara la unidad de las lecturas reproducidas.
+    pub fn with_unit(mut self, unit: Option<Unit>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Lecturas que faltan por emitir en la pasada actual.
+    pub fn remaining(&self) -> usize {
+        self.records.len() - self.next
+    }
+
+    /// Instante en que toca la siguiente lectura, o `None` si la reproducción
+    /// aún no empezó o ya terminó.
+    pub fn next_due(&self) -> Option<Instant> {
+        let started = self.started?;
+        self.offset(self.next).map(|offset| started + offset)
+    }
+
+    /// Emite la siguiente lectura como si la lectura ocurriera en `now`:
+    /// `SensorError::Suppressed` si aún no toca.
+    ///
+    /// [`Sensor::read`] lo invoca con `Instant::now()` tras esperar a
+    /// [`next_due`](Self::next_due); es público para poder recorrer la
+    /// grabación con instantes conocidos.
+    pub fn read_at(&mut self, now: Instant) -> Result<SensorOutput, SensorError> {
+        if self.next >= self.records.len() {
+            if !self.looping || self.records.is_empty() {
+                return Err(SensorError::ReadError("reproducción terminada".to_string()));
+            }
+            self.next = 0;
+            self.started = None;
+        }
+        let started = *self.started.get_or_insert(now);
+        if let Some(offset) = self.offset(self.next) {
+            if now < started + offset {
+                return Err(SensorError::Suppressed);
+            }
+        }
+        let value = self.records[self.next].1.clone();
+        self.next += 1;
+        Ok(value)
+    }
+
+    /// Tiempo de reproducción desde la primera lectura hasta la `index`.
+    fn offset(&self, index: usize) -> Option<Duration> {
+        let (first, _) = self.records.first()?;
+        let (timestamp, _) = self.records.get(index)?;
+        let recorded = timestamp.duration_since(*first).unwrap_or(Duration::ZERO);
+        Some(Duration::try_from_secs_f64(recorded.as_secs_f64() / self.speed).unwrap_or(Duration::MAX))
+    }
+}
+
+impl Sensor for ReplaySensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        if let Some(due) = self.next_due() {
+            let now = Instant::now();
+            if due > now {
+                thread::sleep(due - now);
+            }
+        }
+        self.read_at(Instant::now())
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("replay", SensorKind::Unknown).with_unit(self.unit)
+    }
+}
+
+/// Separa `text` en filas y campos CSV, deshaciendo el entrecomillado RFC 4180
+/// (un campo entre comillas puede contener comas y saltos de línea). Las
+/// líneas vacías se ignoran.
+fn csv_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                if !row.is_empty() || !field.is_empty() {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+            }
+            c => field.push(c),
+        }
+    }
+    if !row.is_empty() || !field.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Deduce la variante de un valor grabado en CSV a partir de su texto.
+fn parse_value(text: &str) -> SensorOutput {
+    match text {
+        "true" => return SensorOutput::Bool(true),
+        "false" => return SensorOutput::Bool(false),
+        _ => {}
+    }
+    if let Ok(v) = text.parse::<i64>() {
+        return SensorOutput::Int(v);
+    }
+    if let Ok(v) = text.parse::<f32>() {
+        return SensorOutput::Float(v);
+    }
+    let fields: Option<BTreeMap<String, f32>> = text
+        .split(';')
+        .map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            Some((key.to_string(), value.parse().ok()?))
+        })
+        .collect();
+    match fields {
+        Some(fields) if !fields.is_empty() => SensorOutput::Map(fields),
+        _ => SensorOutput::Text(text.to_string()),
+    }
+}
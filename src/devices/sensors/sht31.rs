@@ -0,0 +1,117 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput};
+use crate::drivers::i2c::I2cDriver;
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::Duration;
+
+/// Dirección I2C con el pin ADDR a GND (0x45 con ADDR a VCC).
+pub const DEFAULT_ADDRESS: u16 = 0x44;
+
+/// Medición única de alta repetibilidad, sin *clock stretching*.
+const CMD_MEASURE_HIGH_REP: [u8; 2] = [0x24, 0x00];
+/// Duración máxima de una medición de alta repetibilidad (15.5 ms).
+const MEASUREMENT_TIME: Duration = Duration::from_millis(16);
+
+/// Polinomio del CRC-8 del SHT3x: x⁸ + x⁵ + x⁴ + 1.
+const CRC_POLYNOMIAL: u8 = 0x31;
+/// Valor inicial del CRC-8 del SHT3x.
+const CRC_INIT: u8 = 0xFF;
+
+/// CRC-8 de Sensirion con el que el SHT31 protege cada palabra de 16 bits.
+///
+/// # Ejemplo
+/// Valor de referencia de la hoja de datos (tabla 19):
+/// ```
+/// use iot_framework::devices::sensors::sht31::crc8;
+///
+/// assert_eq!(crc8(&[0xBE, 0xEF]), 0x92);
+/// ```
+pub fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(CRC_INIT, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ CRC_POLYNOMIAL
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Valida el CRC de cada palabra de una medición y devuelve
+/// `(temperatura °C, humedad %)`.
+///
+/// La trama son 6 bytes: temperatura (2) + CRC, humedad (2) + CRC.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::sensor::SensorError;
+/// use iot_framework::devices::sensors::sht31::parse_measurement;
+///
+/// // 0x6666 → 25 °C, 0x8000 → 50 %.
+/// let (temp, hum) = parse_measurement([0x66, 0x66, 0x93, 0x80, 0x00, 0xA2]).unwrap();
+/// assert!((temp - 25.0).abs() < 0.01 && (hum - 50.0).abs() < 0.01);
+///
+/// // Un bit alterado en la transferencia se rechaza.
+/// let corrupt = parse_measurement([0x66, 0x67, 0x93, 0x80, 0x00, 0xA2]);
+/// assert!(matches!(corrupt, Err(SensorError::ReadError(_))));
+/// ```
+pub fn parse_measurement(frame: [u8; 6]) -> Result<(f32, f32), SensorError> {
+    let word = |chunk: &[u8], name: &str| {
+        if crc8(&chunk[..2]) != chunk[2] {
+            return Err(SensorError::ReadError(format!("CRC SHT31 inválido ({})", name)));
+        }
+        Ok(u16::from_be_bytes([chunk[0], chunk[1]]) as f32 / 65535.0)
+    };
+    let temp = -45.0 + 175.0 * word(&frame[..3], "temperatura")?;
+    let humidity = 100.0 * word(&frame[3..], "humedad")?;
+    Ok((temp, humidity))
+}
+
+/// `Sht31` lee temperatura y humedad de un Sensirion SHT31 por I2C.
+///
+/// Cada lectura lanza una medición única de alta repetibilidad, espera a que
+/// termine y valida el CRC de los 6 bytes recibidos; una transferencia
+/// corrupta devuelve `SensorError::ReadError`.
+///
+/// Devuelve un `SensorOutput::Map` con las claves `"temp"` (°C) y `"humidity"` (%),
+/// igual que [`Dht22`](crate::devices::sensors::dht22::Dht22).
+pub struct Sht31 {
+    i2c: I2cDriver,
+}
+
+impl Sht31 {
+    /// Crea un `Sht31` en la dirección I2C indicada (normalmente [`DEFAULT_ADDRESS`]).
+    pub fn new(address: u16) -> Result<Self, SensorError> {
+        let i2c = I2cDriver::new(address)
+            .map_err(|e| SensorError::ReadError(format!("i2c init: {}", e)))?;
+        Ok(Self { i2c })
+    }
+}
+
+impl Sensor for Sht31 {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.i2c
+            .write(&CMD_MEASURE_HIGH_REP)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        thread::sleep(MEASUREMENT_TIME);
+        let mut frame = [0u8; 6];
+        self.i2c
+            .read(&mut frame)
+            .map_err(|e| SensorError::ReadError(format!("i2c: {}", e)))?;
+        let (temp, humidity) = parse_measurement(frame)?;
+        let values = BTreeMap::from([
+            ("temp".to_string(), temp),
+            ("humidity".to_string(), humidity),
+        ]);
+        Ok(SensorOutput::Map(values))
+    }
+
+    /// Lectura compuesta (`temp` y `humidity`), sin unidad ni rango comunes.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("SHT31", SensorKind::Composite)
+    }
+}
@@ -1,6 +1,7 @@
 use rand::Rng;
 use std::time::{SystemTime, UNIX_EPOCH};
 use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata};
 
 #[derive(Debug, Clone)]
 pub struct SensorData {
@@ -15,6 +16,7 @@ pub struct SensorData {
 /// y devuelve marcas de tiempo actuales.  
 /// Se utiliza principalmente en entornos de desarrollo y simulación
 /// para verificar la lógica del sistema sin requerir hardware real.
+#[derive(Default)]
 pub struct SimulatedSensor;
 
 impl SimulatedSensor {
@@ -56,4 +58,19 @@ impl Sensor for SimulatedSensor {
             timestamp: self.read_timestamp(),
         })
     }
+
+    /// Lectura compuesta de temperatura y humedad (`SensorData`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::Sensor;
+    /// use iot_framework::{SensorKind, SimulatedSensor};
+    ///
+    /// let meta = SimulatedSensor::new().metadata();
+    /// assert_eq!(meta.name, "simulated");
+    /// assert_eq!(meta.kind, SensorKind::Composite);
+    /// ```
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("simulated", SensorKind::Composite)
+    }
 }
\ No newline at end of file
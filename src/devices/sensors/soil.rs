@@ -0,0 +1,115 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::adc::Adc;
+
+/// Convierte una lectura cruda del ADC en humedad de suelo (0–100 %).
+///
+/// La correspondencia es lineal entre los puntos de calibración `dry_raw`
+/// (sensor al aire o en tierra seca, 0 %) y `wet_raw` (sensor en agua, 100 %).
+/// Funciona en ambos sentidos: los sensores capacitivos bajan su salida al
+/// humedecerse (`dry_raw > wet_raw`) y los resistivos la suben. Los valores
+/// fuera de la calibración se limitan a 0 % o 100 %.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::sensors::soil::raw_to_percent;
+///
+/// // Resistivo: seco = 200, mojado = 800.
+/// assert_eq!(raw_to_percent(500, 200, 800), 50.0);
+/// // Capacitivo (rango invertido): seco = 850, mojado = 350.
+/// assert_eq!(raw_to_percent(475, 850, 350), 75.0);
+/// // Fuera de rango se limita.
+/// assert_eq!(raw_to_percent(900, 850, 350), 0.0);
+/// assert_eq!(raw_to_percent(100, 850, 350), 100.0);
+/// ```
+pub fn raw_to_percent(raw: u16, dry_raw: u16, wet_raw: u16) -> f32 {
+    let span = wet_raw as f32 - dry_raw as f32;
+    if span == 0.0 {
+        return 0.0;
+    }
+    ((raw as f32 - dry_raw as f32) / span * 100.0).clamp(0.0, 100.0)
+}
+
+/// `SoilMoistureSensor` mide la humedad del suelo con una sonda analógica
+/// (capacitiva o resistiva) conectada a un canal de un [`Adc`], como el
+/// [`Mcp3008`](crate::drivers::mcp3008::Mcp3008).
+///
+/// Devuelve `SensorOutput::Float` en porcentaje (`Unit::Percent`) según los
+/// puntos de calibración de la sonda (ver [`raw_to_percent`]).
+///
+/// # Ejemplo
+/// ```
+/// use std::error::Error;
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::soil::SoilMoistureSensor;
+/// use iot_framework::drivers::adc::Adc;
+/// use iot_framework::{SensorOutput, Unit};
+///
+/// struct FakeAdc(u16);
+/// impl Adc for FakeAdc {
+///     fn max_value(&self) -> u16 { 1023 }
+///     fn read_raw(&mut self, _channel: u8) -> Result<u16, Box<dyn Error>> { Ok(self.0) }
+/// }
+///
+/// let mut sensor = SoilMoistureSensor::new(FakeAdc(600), 0, 850, 350).unwrap();
+/// assert_eq!(sensor.read().unwrap(), SensorOutput::Float(50.0));
+///
+/// let meta = sensor.metadata();
+/// assert_eq!(meta.unit, Some(Unit::Percent));
+/// assert_eq!((meta.min, meta.max), (Some(0.0), Some(100.0)));
+///
+/// // Los puntos de calibración deben ser distintos.
+/// assert!(SoilMoistureSensor::new(FakeAdc(0), 0, 500, 500).is_err());
+/// ```
+pub struct SoilMoistureSensor<A: Adc> {
+    adc: A,
+    channel: u8,
+    dry_raw: u16,
+    wet_raw: u16,
+}
+
+impl<A: Adc> SoilMoistureSensor<A> {
+    /// Crea un sensor sobre el canal `channel` de `adc` con su calibración.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::ReadError)` si `dry_raw == wet_raw` o alguno supera
+    ///   el máximo del ADC.
+    pub fn new(adc: A, channel: u8, dry_raw: u16, wet_raw: u16) -> Result<Self, SensorError> {
+        if dry_raw == wet_raw {
+            return Err(SensorError::ReadError(
+                "calibración inválida: dry_raw y wet_raw deben ser distintos".to_string(),
+            ));
+        }
+        let max = adc.max_value();
+        if dry_raw > max || wet_raw > max {
+            return Err(SensorError::ReadError(format!(
+                "calibración inválida: el ADC solo llega a {}",
+                max
+            )));
+        }
+        Ok(Self { adc, channel, dry_raw, wet_raw })
+    }
+}
+
+impl<A: Adc> Sensor for SoilMoistureSensor<A> {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let raw = self
+            .adc
+            .read_raw(self.channel)
+            .map_err(|e| SensorError::ReadError(format!("adc: {}", e)))?;
+        Ok(SensorOutput::Float(raw_to_percent(raw, self.dry_raw, self.wet_raw)))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Percent)
+    }
+
+    /// De 0 % a 100 %; [`raw_to_percent`] limita los valores a ese rango.
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("soil-moisture", SensorKind::Numeric)
+            .with_unit(self.unit())
+            .with_range(0.0, 100.0)
+    }
+}
@@ -1,6 +1,14 @@
 use crate::core::traits::sensor::{Sensor, SensorError};
 use std::fs;
-use crate::core::SensorOutput;
+use std::io::ErrorKind;
+use std::path::Path;
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+
+/// Directorio donde el kernel expone los dispositivos OneWire.
+pub const W1_DEVICES_DIR: &str = "/sys/bus/w1/devices";
+
+/// Prefijo (código de familia) de los DS18B20 en el bus OneWire.
+const DS18B20_FAMILY: &str = "28-";
 
 
 /// Representa un **sensor de temperatura** que obtiene datos
@@ -23,19 +31,124 @@ impl Temperature {
     /// - `Err(SensorError)` en caso de error (aunque aquí en realidad siempre devuelve `Ok`,
     ///   el `Result` está para mantener consistencia y permitir validaciones futuras).
     pub fn new(device_id: &str) -> Result<Self, SensorError> {
+        Self::from_path(format!("{}/{}/w1_slave", W1_DEVICES_DIR, device_id))
+    }
+
+    /// Detecta todos los DS18B20 conectados al bus OneWire.
+    ///
+    /// Recorre [`W1_DEVICES_DIR`] y crea un `Temperature` por cada entrada `28-*`,
+    /// ordenados por id. Ver [`Temperature::discover_in`].
+    pub fn discover() -> Result<Vec<Temperature>, SensorError> {
+        Self::discover_in(W1_DEVICES_DIR)
+    }
+
+    /// Igual que [`Temperature::discover`], pero explorando `dir`.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::NotFound(dir))` si el directorio no existe (módulo
+    ///   `w1-gpio` no cargado).
+    /// - `Err(SensorError::PermissionDenied(dir))` si no puede leerse.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::devices::sensors::temperature::Temperature;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("w1-discover-{}", std::process::id()));
+    /// for entry in ["28-00000b0e60f1", "28-0000075a1c2d", "w1_bus_master1", "10-000802b4d1e2"] {
+    ///     std::fs::create_dir_all(dir.join(entry)).unwrap();
+    /// }
+    ///
+    /// let found = Temperature::discover_in(&dir).unwrap();
+    /// let ids: Vec<_> = found.iter().map(|t| t.device_id().unwrap()).collect();
+    /// assert_eq!(ids, ["28-0000075a1c2d", "28-00000b0e60f1"]);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn discover_in(dir: impl AsRef<Path>) -> Result<Vec<Temperature>, SensorError> {
+        let dir = dir.as_ref();
+        let entries = fs::read_dir(dir).map_err(|e| SensorError::from_io(e, dir))?;
+        let mut ids: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with(DS18B20_FAMILY))
+            .collect();
+        ids.sort();
+        ids.into_iter()
+            .map(|id| Self::from_path(dir.join(id).join("w1_slave").to_string_lossy()))
+            .collect()
+    }
+
+    /// Igual que [`Temperature::new`], pero comprueba que el archivo `w1_slave` exista.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::NotFound(ruta))` si el dispositivo no está presente
+    ///   (id incorrecto, bus OneWire deshabilitado).
+    pub fn new_validated(device_id: &str) -> Result<Self, SensorError> {
+        let sensor = Self::new(device_id)?;
+        sensor.validate()?;
+        Ok(sensor)
+    }
+
+    /// Crea un sensor que lee directamente el archivo `path` con formato `w1_slave`.
+    ///
+    /// Útil para dispositivos montados en rutas no estándar o para pruebas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::SensorError;
+    /// use iot_framework::devices::sensors::temperature::Temperature;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("w1-doc-{}", std::process::id()));
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let path = dir.join("w1_slave");
+    /// std::fs::write(&path, "t=21000").unwrap();
+    ///
+    /// let present = Temperature::from_path(path.to_string_lossy()).unwrap();
+    /// assert!(present.validate().is_ok());
+    ///
+    /// let missing = Temperature::from_path(dir.join("nada").to_string_lossy()).unwrap();
+    /// assert!(matches!(missing.validate(), Err(SensorError::NotFound(_))));
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn from_path(path: impl Into<String>) -> Result<Self, SensorError> {
         Ok(Self {
-            device_path: format!("/sys/bus/w1/devices/{}/w1_slave", device_id),
+            device_path: path.into(),
         })
     }
+
+    /// Identificador OneWire del dispositivo (nombre del directorio que contiene
+    /// `w1_slave`), útil para registrar cada sensor descubierto en el runtime.
+    pub fn device_id(&self) -> Option<&str> {
+        Path::new(&self.device_path)
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .and_then(|name| name.to_str())
+    }
+
+    /// Comprueba que el archivo del dispositivo exista.
+    ///
+    /// # Retorna
+    /// - `Err(SensorError::NotFound(ruta))` si no existe.
+    pub fn validate(&self) -> Result<(), SensorError> {
+        if Path::new(&self.device_path).exists() {
+            Ok(())
+        } else {
+            Err(SensorError::NotFound(self.device_path.clone()))
+        }
+    }
     
     /// Lee directamente el archivo `w1_slave` que contiene la salida cruda del sensor.
     ///
     /// # Retorna
     /// - `Ok(String)` con el contenido del archivo.
-    /// - `Err(SensorError::ReadError)` si ocurre un problema al leer.
+    /// - `Err(SensorError::Disconnected)` si el archivo ya no existe (el kernel
+    ///   lo retira al desconectarse el sensor).
+    /// - `Err(SensorError::PermissionDenied)` si el proceso no puede leerlo.
+    /// - `Err(SensorError::Io)` si ocurre otro problema al leer.
     fn read_temp_raw(&self) -> Result<String, SensorError> {
-        fs::read_to_string(&self.device_path)
-            .map_err(|e| SensorError::ReadError(format!("Error leyendo archivo: {}", e)))
+        fs::read_to_string(&self.device_path).map_err(|e| match e.kind() {
+            ErrorKind::NotFound => SensorError::Disconnected(self.device_path.clone()),
+            _ => SensorError::from_io(e, &self.device_path),
+        })
     }
 }
 
@@ -47,30 +160,106 @@ impl Sensor for Temperature {
     ///
     /// Flujo:
     /// 1. Llama a `read_temp_raw` para obtener los datos crudos del archivo.
-    /// 2. Busca la cadena `"t="`, que es donde el kernel expone el valor en miligrados Celsius.
-    /// 3. Convierte ese valor a `f32` y lo pasa de **miligrados** a **grados Celsius** dividiendo entre 1000.
-    /// 4. Devuelve el resultado formateado como `SensorOutput::Text("XX.XX °C")`.
+    /// 2. Pasa el contenido a [`parse_w1_slave`], que valida el CRC y extrae la temperatura.
+    /// 3. Devuelve el valor numérico como `SensorOutput::Float`; la unidad
+    ///    (`Unit::Celsius`) se declara aparte mediante `Sensor::unit`.
     ///
     /// # Retorna
-    /// - `Ok(SensorOutput::Text)` con la temperatura en grados Celsius.
-    /// - `Err(SensorError::ReadError)` si el formato no es el esperado o si ocurre un fallo en el parseo.
+    /// - `Ok(SensorOutput::Float)` con la temperatura en grados Celsius.
+    /// - `Err(SensorError::ReadError)` si el CRC falla.
+    /// - `Err(SensorError::ParseError)` si el formato no es el esperado.
+    /// - `Err(SensorError::Disconnected)`, `PermissionDenied` o `Io` si no
+    ///   puede leerse el archivo (ver `read_temp_raw`).
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::{Sensor, SensorError};
+    /// use iot_framework::devices::sensors::temperature::Temperature;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("w1-read-{}", std::process::id()));
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let path = dir.join("w1_slave");
+    ///
+    /// let mut sensor = Temperature::from_path(path.to_string_lossy()).unwrap();
+    /// assert!(matches!(sensor.read(), Err(SensorError::Disconnected(_))));
+    ///
+    /// std::fs::write(&path, "crc=57 YES\nsin temperatura\n").unwrap();
+    /// let err = sensor.read().unwrap_err();
+    /// assert!(matches!(err, SensorError::ParseError(_)));
+    /// assert!(err.is_transient());
+    ///
+    /// // Un directorio en lugar del archivo: error de E/S genérico.
+    /// let mut sensor = Temperature::from_path(dir.to_string_lossy()).unwrap();
+    /// assert!(matches!(sensor.read(), Err(SensorError::Io(_))));
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
     fn read(&mut self) -> Result<Self::Output, SensorError> {
-        // 1. Leer datos crudos del archivo
         let data = self.read_temp_raw()?;
-        // 2. Buscar la posición del texto "t=" en la salida
-        if let Some(eq_pos) = data.find("t=") {
-            // Extraer el número crudo después de "t="
-            let temp_str = &data[eq_pos + 2..].trim();
-            // 3. Parsear el valor crudo a `f32` y dividir entre 1000
-            let temp_c = temp_str
-                .parse::<f32>()
-                .map_err(|e| SensorError::ReadError(format!("parse: {}", e)))?
-                / 1000.0;
-            // 4. Retornar el valor ya convertido y formateado
-            Ok(SensorOutput::Text(format!("{:.2} °C", temp_c)))
-        } else {
-            Err(SensorError::ReadError("Formato inesperado en w1_slave".to_string()))
-        }
+        parse_w1_slave(&data).map(SensorOutput::Float)
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Celsius)
+    }
+
+    /// DS18B20: de -55 °C a 125 °C según la hoja de datos.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::sensor::Sensor;
+    /// use iot_framework::devices::sensors::temperature::Temperature;
+    /// use iot_framework::{SensorKind, Unit};
+    ///
+    /// let meta = Temperature::from_path("/sys/bus/w1/devices/28-000005e2fdc3/w1_slave").unwrap().metadata();
+    /// assert_eq!(meta.name, "DS18B20");
+    /// assert_eq!(meta.unit, Some(Unit::Celsius));
+    /// assert_eq!((meta.min, meta.max), (Some(-55.0), Some(125.0)));
+    /// assert_eq!(meta.kind, SensorKind::Numeric);
+    /// ```
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("DS18B20", SensorKind::Numeric)
+            .with_unit(self.unit())
+            .with_range(-55.0, 125.0)
     }
 }
 
+/// Interpreta el contenido de un archivo `w1_slave` y devuelve la temperatura en °C.
+///
+/// El kernel escribe dos líneas: la primera termina en `YES` si el CRC de la
+/// trama es válido (`NO` si está corrupta) y la segunda contiene `t=` seguido de
+/// la temperatura en miligrados Celsius.
+///
+/// # Retorna
+/// - `Err(SensorError::ReadError("CRC failed"))` si la primera línea no termina en `YES`.
+/// - `Err(SensorError::ParseError)` si falta `t=` o el valor no es numérico.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::sensors::temperature::parse_w1_slave;
+///
+/// let ok = "72 01 4b 46 7f ff 0e 10 57 : crc=57 YES\n72 01 4b 46 7f ff 0e 10 57 t=23125\n";
+/// assert_eq!(parse_w1_slave(ok).unwrap(), 23.125);
+///
+/// // Trama corrupta capturada de un cable largo: el kernel marca el CRC con NO.
+/// let bad = "50 05 4b 46 7f ff 0c 10 1c : crc=c8 NO\n50 05 4b 46 7f ff 0c 10 1c t=85000\n";
+/// assert!(parse_w1_slave(bad).is_err());
+/// ```
+pub fn parse_w1_slave(data: &str) -> Result<f32, SensorError> {
+    // 1. La primera línea indica si el CRC es válido
+    let crc_line = data.lines().next().unwrap_or_default();
+    if !crc_line.trim_end().ends_with("YES") {
+        return Err(SensorError::ReadError("CRC failed".to_string()));
+    }
+    // 2. Buscar la posición del texto "t=" en la salida
+    let eq_pos = data
+        .find("t=")
+        .ok_or_else(|| SensorError::ParseError("Formato inesperado en w1_slave".to_string()))?;
+    // Extraer el número crudo después de "t="
+    let temp_str = data[eq_pos + 2..].trim();
+    // 3. Parsear el valor crudo a `f32` y dividir entre 1000
+    let temp_c = temp_str
+        .parse::<f32>()
+        .map_err(|e| SensorError::ParseError(format!("parse: {}", e)))?
+        / 1000.0;
+    Ok(temp_c)
+}
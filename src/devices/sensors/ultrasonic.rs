@@ -0,0 +1,108 @@
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, Unit};
+use crate::drivers::gpio::{GpioDriver, GpioOutput};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Velocidad del sonido en el aire a ~20 °C, en cm/µs.
+const SPEED_OF_SOUND_CM_PER_US: f32 = 0.0343;
+
+/// Alcance máximo del HC-SR04 según su hoja de datos (cm).
+pub const MAX_RANGE_CM: f32 = 400.0;
+
+/// Tiempo de espera por defecto: ida y vuelta del alcance máximo más margen.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(30);
+
+/// Convierte la duración del pulso de eco en distancia (cm).
+///
+/// El pulso cubre la ida y la vuelta del sonido, por lo que se divide entre dos.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::devices::sensors::ultrasonic::echo_to_distance_cm;
+///
+/// // 1 ms de eco ≈ 17.15 cm.
+/// let cm = echo_to_distance_cm(Duration::from_micros(1000));
+/// assert!((cm - 17.15).abs() < 0.01);
+/// ```
+pub fn echo_to_distance_cm(echo: Duration) -> f32 {
+    echo.as_micros() as f32 * SPEED_OF_SOUND_CM_PER_US / 2.0
+}
+
+/// UltrasonicSensor: medidor de distancia HC-SR04 (trig + echo).
+///
+/// Cada lectura emite un pulso de 10 µs en `trig` y cronometra, con espera
+/// activa, cuánto tiempo permanece en HIGH el pin `echo`. Devuelve la distancia
+/// como `SensorOutput::Float` en centímetros (`Unit::Centimeter`).
+///
+/// # Tiempo de espera
+/// Si el eco no empieza o no termina antes de `timeout` (por defecto
+/// [`DEFAULT_TIMEOUT`]) la lectura devuelve `SensorError::ReadError` en lugar
+/// de quedarse bloqueada: sin obstáculo al alcance el módulo puede no responder.
+///
+/// **Atención:** el pin `echo` del HC-SR04 entrega 5 V; use un divisor de
+/// tensión antes de conectarlo a la Raspberry Pi.
+pub struct UltrasonicSensor {
+    trig: GpioOutput,
+    echo: GpioDriver,
+    timeout: Duration,
+}
+
+impl UltrasonicSensor {
+    /// Crea un UltrasonicSensor con los pines BCM de disparo y eco.
+    pub fn new(trig_pin: u8, echo_pin: u8) -> Result<Self, SensorError> {
+        let trig = GpioOutput::new(trig_pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        let echo = GpioDriver::new(echo_pin)
+            .map_err(|e| SensorError::ReadError(format!("gpio init: {}", e)))?;
+        Ok(Self { trig, echo, timeout: DEFAULT_TIMEOUT })
+    }
+
+    /// Cambia el tiempo máximo de espera de cada fase del eco.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Espera activamente a que `echo` tome el nivel `level`; devuelve el instante.
+    fn wait_for(&self, level: bool, since: Instant) -> Result<Instant, SensorError> {
+        loop {
+            let now = Instant::now();
+            if self.echo.read_bool() == level {
+                return Ok(now);
+            }
+            if now.duration_since(since) > self.timeout {
+                return Err(SensorError::ReadError("sin eco del sensor ultrasónico".to_string()));
+            }
+        }
+    }
+}
+
+impl Sensor for UltrasonicSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        self.trig.set_low();
+        thread::sleep(Duration::from_micros(2));
+        self.trig.set_high();
+        thread::sleep(Duration::from_micros(10));
+        self.trig.set_low();
+
+        let start = self.wait_for(true, Instant::now())?;
+        let end = self.wait_for(false, start)?;
+        let distance = echo_to_distance_cm(end.duration_since(start));
+        Ok(SensorOutput::Float(distance.min(MAX_RANGE_CM)))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Centimeter)
+    }
+
+    /// HC-SR04: de 0 cm a [`MAX_RANGE_CM`] (las lecturas se limitan a ese máximo).
+    fn metadata(&self) -> SensorMetadata {
+        SensorMetadata::new("HC-SR04", SensorKind::Numeric)
+            .with_unit(self.unit())
+            .with_range(0.0, MAX_RANGE_CM as f64)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::core::cache::ReadingCache;
+use crate::core::traits::sensor::{Sensor, SensorError};
+use crate::core::{SensorKind, SensorMetadata, SensorOutput, SensorReading, Unit};
+use std::time::Duration;
+
+/// Función que calcula el valor derivado a partir de las últimas lecturas de las
+/// entradas, en el orden en que se declararon.
+pub type ComputeFn = Box<dyn FnMut(&[SensorReading]) -> Result<SensorOutput, SensorError> + Send>;
+
+/// `VirtualSensor` calcula un valor a partir de las lecturas de otros sensores,
+/// sin hardware propio.
+///
+/// Se registra en el runtime como cualquier otro sensor, pero en lugar de leer
+/// un dispositivo toma la última lectura de cada entrada de una
+/// [`ReadingCache`] (la misma que se pasa a
+/// [`RuntimeControllerBuilder::with_reading_cache`](crate::core::runtime::RuntimeControllerBuilder::with_reading_cache))
+/// y la pasa a una función. Mientras alguna entrada no tenga lecturas, `read()`
+/// devuelve `SensorError::Warmup` y el runtime la omite sin contarla como fallo.
+///
+/// Como los sensores de un mismo ciclo se leen a la vez, el valor se calcula con
+/// las lecturas del ciclo anterior; para reducir el desfase conviene darle un
+/// intervalo propio más corto que el de sus entradas.
+///
+/// # Ejemplo
+/// ```
+/// use std::collections::BTreeMap;
+/// use std::time::Duration;
+/// use iot_framework::core::cache::ReadingCache;
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::devices::sensors::mock::MockSensor;
+/// use iot_framework::devices::sensors::virtual_sensor::VirtualSensor;
+/// use iot_framework::{ConsoleCommunicator, SensorOutput};
+///
+/// # #[tokio::main] async fn main() {
+/// let dht = SensorOutput::Map(BTreeMap::from([("temp".into(), 20.0), ("humidity".into(), 50.0)]));
+/// let cache = ReadingCache::new();
+/// let mut runtime = RuntimeController::builder()
+///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+///     .with_interval(Duration::from_millis(10))
+///     .with_reading_cache(cache.clone())
+///     .add_sensor("dht", Box::new(MockSensor::cycling(vec![dht])))
+///     .add_sensor("rocio", Box::new(VirtualSensor::dew_point(cache.clone(), "dht")))
+///     .build()
+///     .unwrap();
+///
+/// let (tx, rx) = tokio::sync::watch::channel(false);
+/// tokio::spawn(async move {
+///     tokio::time::sleep(Duration::from_millis(100)).await;
+///     tx.send(true).unwrap();
+/// });
+/// runtime.run(rx).await;
+///
+/// // Las lecturas del sensor virtual también quedan en la caché.
+/// let SensorOutput::Float(dew) = cache.get("rocio").unwrap().value else { panic!() };
+/// assert!((dew - 9.26).abs() < 0.01);
+/// # }
+/// ```
+pub struct VirtualSensor {
+    cache: ReadingCache,
+    inputs: Vec<String>,
+    compute: ComputeFn,
+    metadata: SensorMetadata,
+}
+
+impl VirtualSensor {
+    /// Crea un sensor virtual que aplica `compute` a las últimas lecturas de
+    /// `inputs` (ids de sensores), en ese orden.
+    pub fn new<F>(cache: ReadingCache, inputs: &[&str], compute: F) -> Self
+    where
+        F: FnMut(&[SensorReading]) -> Result<SensorOutput, SensorError> + Send + 'static,
+    {
+        Self {
+            cache,
+            inputs: inputs.iter().map(|id| id.to_string()).collect(),
+            compute: Box::new(compute),
+            metadata: SensorMetadata::new("virtual", SensorKind::Unknown),
+        }
+    }
+
+    /// Crea un sensor virtual del punto de rocío (°C) a partir de un sensor que
+    /// entrega un `SensorOutput::Map` con `"temp"` (°C) y `"humidity"` (%), como el
+    /// [`Dht22`](crate::devices::sensors::dht22::Dht22). Ver [`dew_point`].
+    pub fn dew_point(cache: ReadingCache, source: &str) -> Self {
+        Self::new(cache, &[source], |readings| {
+            let field = |name: &str| match &readings[0].value {
+                SensorOutput::Map(fields) => fields.get(name).copied().ok_or_else(|| {
+                    SensorError::ParseError(format!("la lectura de {} no tiene \"{}\"", readings[0].sensor_id, name))
+                }),
+                other => Err(SensorError::ParseError(format!("se esperaba un mapa, se recibió {:?}", other))),
+            };
+            Ok(SensorOutput::Float(dew_point(field("temp")?, field("humidity")?)))
+        })
+        .with_metadata(SensorMetadata::new("dew-point", SensorKind::Numeric).with_unit(Some(Unit::Celsius)))
+    }
+
+    /// Define los metadatos del valor calculado (nombre, unidad, rango); por
+    /// defecto `"virtual"`, de tipo desconocido y sin unidad.
+    pub fn with_metadata(mut self, metadata: SensorMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+}
+
+impl Sensor for VirtualSensor {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<Self::Output, SensorError> {
+        let mut readings = Vec::with_capacity(self.inputs.len());
+        for id in &self.inputs {
+            match self.cache.get(id) {
+                Some(reading) => readings.push(reading),
+                // La entrada aún no se ha leído (p. ej. en el primer ciclo).
+                None => return Err(SensorError::Warmup(Duration::ZERO)),
+            }
+        }
+        (self.compute)(&readings)
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        self.metadata.unit
+    }
+
+    fn metadata(&self) -> SensorMetadata {
+        self.metadata.clone()
+    }
+}
+
+/// Punto de rocío en °C según la fórmula de Magnus (coeficientes de Sonntag,
+/// b = 17.62 y c = 243.12 °C), válida entre -45 °C y 60 °C.
+///
+/// `humidity` es la humedad relativa en % y debe ser mayor que 0.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::devices::sensors::virtual_sensor::dew_point;
+///
+/// // Valores de referencia de la fórmula de Magnus.
+/// for (temp, humidity, expected) in [(20.0, 50.0, 9.26), (30.0, 80.0, 26.17), (25.0, 60.0, 16.69), (10.0, 90.0, 8.43)] {
+///     assert!((dew_point(temp, humidity) - expected).abs() < 0.01, "{temp} °C, {humidity} %");
+/// }
+/// // Con el aire saturado el punto de rocío es la propia temperatura.
+/// assert!((dew_point(15.0, 100.0) - 15.0).abs() < 1e-4);
+/// ```
+pub fn dew_point(temp_c: f32, humidity: f32) -> f32 {
+    const B: f32 = 17.62;
+    const C: f32 = 243.12;
+    let gamma = (humidity / 100.0).ln() + B * temp_c / (C + temp_c);
+    C * gamma / (B - gamma)
+}
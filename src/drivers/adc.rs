@@ -0,0 +1,16 @@
+// src/drivers/adc.rs
+use std::error::Error;
+
+/// Conversor analógico-digital con varios canales.
+///
+/// Los sensores analógicos (humedad de suelo, gas, corriente...) dependen de
+/// este trait en lugar de un chip concreto, de modo que el mismo sensor sirve
+/// con cualquier ADC o con una fuente simulada.
+pub trait Adc {
+    /// Valor máximo que puede devolver [`read_raw`](Adc::read_raw)
+    /// (`1023` para un ADC de 10 bits).
+    fn max_value(&self) -> u16;
+
+    /// Lee el valor crudo del canal `channel`, entre `0` y [`max_value`](Adc::max_value).
+    fn read_raw(&mut self, channel: u8) -> Result<u16, Box<dyn Error>>;
+}
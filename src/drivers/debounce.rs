@@ -0,0 +1,111 @@
+// src/drivers/debounce.rs
+use crate::drivers::gpio::GpioDriver;
+use std::thread;
+use std::time::Duration;
+
+/// Fuente de un nivel digital (true = HIGH, false = LOW).
+///
+/// Permite aplicar el antirrebote tanto a un pin real ([`GpioDriver`]) como a
+/// cualquier otra fuente inyectable, por ejemplo una secuencia simulada.
+pub trait LevelSource {
+    /// Devuelve el nivel instantáneo.
+    fn read_bool(&self) -> bool;
+}
+
+impl LevelSource for GpioDriver {
+    fn read_bool(&self) -> bool {
+        GpioDriver::read_bool(self)
+    }
+}
+
+/// Antirrebote por software para entradas digitales.
+///
+/// Muestrea la fuente hasta obtener `samples` lecturas iguales consecutivas,
+/// separadas por `spacing`, y solo entonces reporta ese nivel. Si la señal no se
+/// estabiliza tras `samples * MAX_ROUNDS` muestras, se mantiene el último nivel
+/// estable conocido (o el instantáneo si aún no hay ninguno).
+///
+/// # Ejemplo
+/// ```
+/// use std::cell::RefCell;
+/// use std::time::Duration;
+/// use iot_framework::drivers::debounce::{DebouncedInput, LevelSource};
+///
+/// // Secuencia con rebotes antes de estabilizarse en HIGH.
+/// struct Bouncing(RefCell<Vec<bool>>);
+/// impl LevelSource for Bouncing {
+///     fn read_bool(&self) -> bool {
+///         self.0.borrow_mut().pop().unwrap_or(true)
+///     }
+/// }
+///
+/// let bounces = vec![true, true, true, false, true, false];
+/// let mut input = DebouncedInput::new(Bouncing(RefCell::new(bounces)), 3, Duration::ZERO);
+/// assert!(input.read_bool());
+/// ```
+pub struct DebouncedInput<L> {
+    source: L,
+    samples: u32,
+    spacing: Duration,
+    stable: Option<bool>,
+}
+
+/// Número máximo de rondas de muestreo antes de rendirse.
+const MAX_ROUNDS: u32 = 10;
+
+impl<L: LevelSource> DebouncedInput<L> {
+    /// Crea un `DebouncedInput` sobre `source`.
+    ///
+    /// # Parámetros
+    /// - `samples`: lecturas iguales consecutivas requeridas (`1` desactiva el antirrebote).
+    /// - `spacing`: tiempo entre muestras.
+    pub fn new(source: L, samples: u32, spacing: Duration) -> Self {
+        Self {
+            source,
+            samples: samples.max(1),
+            spacing,
+            stable: None,
+        }
+    }
+
+    /// Entrada sin antirrebote: cada lectura devuelve el nivel instantáneo.
+    pub fn passthrough(source: L) -> Self {
+        Self::new(source, 1, Duration::ZERO)
+    }
+
+    /// Cambia la configuración del antirrebote conservando la fuente.
+    pub fn set_debounce(&mut self, samples: u32, spacing: Duration) {
+        self.samples = samples.max(1);
+        self.spacing = spacing;
+    }
+
+    /// Devuelve el nivel estable: true = HIGH, false = LOW
+    pub fn read_bool(&mut self) -> bool {
+        let mut candidate = self.source.read_bool();
+        let mut count = 1;
+        let mut taken = 1;
+        while count < self.samples {
+            if taken >= self.samples * MAX_ROUNDS {
+                return self.stable.unwrap_or(candidate);
+            }
+            if !self.spacing.is_zero() {
+                thread::sleep(self.spacing);
+            }
+            let level = self.source.read_bool();
+            taken += 1;
+            if level == candidate {
+                count += 1;
+            } else {
+                candidate = level;
+                count = 1;
+            }
+        }
+        self.stable = Some(candidate);
+        candidate
+    }
+
+    /// Devuelve una referencia a la fuente envuelta.
+    pub fn source(&self) -> &L {
+        &self.source
+    }
+}
@@ -1,8 +1,72 @@
 // src/drivers/gpio.rs
-use rppal::gpio::{Gpio, InputPin, Level};
+#[cfg(not(feature = "mock"))]
+use rppal::gpio::{Gpio, InputPin, OutputPin};
+use rppal::gpio::Level;
+pub use rppal::gpio::Trigger;
 use std::error::Error;
 
+#[cfg(feature = "mock")]
+mod mock;
+
+/// Flancos que [`GpioDriver::edge_events`] guarda mientras nadie los consume;
+/// con la cola llena se descartan los nuevos.
+pub const EDGE_EVENT_CAPACITY: usize = 64;
+#[cfg(feature = "mock")]
+use mock::{MockInput as InputPin, MockOutput as OutputPin};
+
+/// Fija el nivel del pin BCM `pin` simulado (true = HIGH), como si se
+/// conectara a VCC o a GND. Solo con la feature `mock`.
+///
+/// Con `mock`, [`GpioDriver`] y [`GpioOutput`] no tocan el hardware: cada pin
+/// es un nivel en memoria compartido por todos sus drivers. Las entradas leen
+/// HIGH (pull-up) hasta que se fija otro nivel, las salidas escriben en él y
+/// cada cambio dispara los callbacks de [`GpioDriver::on_edge`] cuyo flanco
+/// coincide. Así los sensores GPIO pueden probarse en un portátil o en CI.
+///
+/// # Ejemplo
+/// ```
+/// # #[cfg(feature = "mock")] {
+/// use iot_framework::core::traits::sensor::Sensor;
+/// use iot_framework::devices::sensors::rain::RainSensor;
+/// use iot_framework::drivers::gpio::set_mock_level;
+/// use iot_framework::SensorOutput;
+///
+/// // Módulo active low: DO = LOW con agua.
+/// let mut rain = RainSensor::new(17, true).unwrap();
+/// assert_eq!(rain.read().unwrap(), SensorOutput::Text("SECO".into()));
+/// set_mock_level(17, false);
+/// assert_eq!(rain.read().unwrap(), SensorOutput::Text("HÚMEDO".into()));
+/// # }
+/// ```
+#[cfg(feature = "mock")]
+pub fn set_mock_level(pin: u8, level: bool) {
+    mock::set_level(pin, level);
+}
+
+/// Nivel actual del pin BCM `pin` simulado (true = HIGH), p. ej. el que
+/// escribió un actuador. Solo con la feature `mock`.
+///
+/// # Ejemplo
+/// ```
+/// # #[cfg(feature = "mock")] {
+/// use iot_framework::devices::actuators::relay::RelayActuator;
+/// use iot_framework::drivers::gpio::mock_level;
+/// use iot_framework::{Actuator, SensorOutput, SensorReading};
+///
+/// let mut relay = RelayActuator::new(22, false).unwrap();
+/// assert!(!mock_level(22));
+/// relay.execute(SensorReading::new("temp", SensorOutput::Bool(true))).unwrap();
+/// assert!(mock_level(22));
+/// # }
+/// ```
+#[cfg(feature = "mock")]
+pub fn mock_level(pin: u8) -> bool {
+    mock::get_level(pin)
+}
+
 /// Driver mínimo y seguro para leer un pin digital en Raspberry Pi.
+///
+/// Con la feature `mock` lee un pin simulado (ver `set_mock_level`).
 pub struct GpioDriver {
     pin: InputPin,
     pub pin_number: u8,
@@ -12,12 +76,22 @@ impl GpioDriver {
     /// Crea un nuevo driver para el pin BCM indicado.
     /// Devuelve Err si rppal falla (pin inválido, permisos, etc.).
     pub fn new(pin_number: u8) -> Result<Self, Box<dyn Error>> {
-        let gpio = Gpio::new()?;
-        let pin = gpio.get(pin_number)?.into_input_pullup();
+        #[cfg(not(feature = "mock"))]
+        let pin = Gpio::new()?.get(pin_number)?.into_input_pullup();
+        #[cfg(feature = "mock")]
+        let pin = InputPin::new(pin_number);
 
         Ok(Self { pin, pin_number})
     }
 
+    /// Crea un driver de **salida** para el pin BCM indicado.
+    ///
+    /// Devuelve un [`GpioOutput`] en lugar de un `GpioDriver`, de modo que un pin
+    /// de salida nunca pueda leerse como entrada por error. El pin arranca en LOW.
+    pub fn new_output(pin_number: u8) -> Result<GpioOutput, Box<dyn Error>> {
+        GpioOutput::new(pin_number)
+    }
+
     /// Lee el nivel físico (High/Low).
     pub fn read_level(&self) -> Level {
         self.pin.read()
@@ -27,4 +101,140 @@ impl GpioDriver {
     pub fn read_bool(&self) -> bool {
         self.read_level() == Level::High
     }
+
+    /// Registra un callback que se ejecuta en un hilo de rppal con cada flanco
+    /// que coincida con `trigger`. Recibe el nivel tras el flanco (true = HIGH).
+    ///
+    /// Sustituye cualquier callback registrado antes; se elimina al descartar el driver.
+    pub fn on_edge<C>(&mut self, trigger: Trigger, mut callback: C) -> Result<(), Box<dyn Error>>
+    where
+        C: FnMut(bool) + Send + 'static,
+    {
+        self.pin
+            .set_async_interrupt(trigger, move |level| callback(level == Level::High))?;
+        Ok(())
+    }
+
+    /// Devuelve un stream asíncrono con el nivel tras cada flanco que coincida con `trigger`.
+    ///
+    /// La cola guarda como mucho [`EDGE_EVENT_CAPACITY`] flancos sin consumir:
+    /// si el pin rebota más rápido de lo que se leen, los flancos nuevos se
+    /// descartan en lugar de acumular memoria sin límite.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use iot_framework::drivers::gpio::{set_mock_level, GpioDriver, Trigger, EDGE_EVENT_CAPACITY};
+    ///
+    /// let mut gpio = GpioDriver::new(6).unwrap();
+    /// let mut events = gpio.edge_events(Trigger::FallingEdge).unwrap();
+    /// // Un pin que rebota mientras nadie consume los eventos.
+    /// for _ in 0..EDGE_EVENT_CAPACITY + 10 {
+    ///     set_mock_level(6, false);
+    ///     set_mock_level(6, true);
+    /// }
+    /// let mut received = 0;
+    /// while let Ok(level) = events.try_recv() {
+    ///     assert!(!level);
+    ///     received += 1;
+    /// }
+    /// assert_eq!(received, EDGE_EVENT_CAPACITY);
+    /// # }
+    /// ```
+    pub fn edge_events(
+        &mut self,
+        trigger: Trigger,
+    ) -> Result<tokio::sync::mpsc::Receiver<bool>, Box<dyn Error>> {
+        let (tx, rx) = tokio::sync::mpsc::channel(EDGE_EVENT_CAPACITY);
+        self.on_edge(trigger, move |level| {
+            // Con la cola llena o sin receptor simplemente se descarta el evento.
+            let _ = tx.try_send(level);
+        })?;
+        Ok(rx)
+    }
+}
+
+/// Fuente de flancos de una entrada digital.
+///
+/// Permite contar o reaccionar a flancos tanto de un pin real ([`GpioDriver`])
+/// como de una fuente simulada que invoque el callback directamente.
+pub trait EdgeSource {
+    /// Registra `callback`, que recibe el nivel tras cada flanco que coincida
+    /// con `trigger` (true = HIGH). Puede llamarse desde otro hilo.
+    fn on_edge<C>(&mut self, trigger: Trigger, callback: C) -> Result<(), Box<dyn Error>>
+    where
+        C: FnMut(bool) + Send + 'static;
+}
+
+impl EdgeSource for GpioDriver {
+    fn on_edge<C>(&mut self, trigger: Trigger, callback: C) -> Result<(), Box<dyn Error>>
+    where
+        C: FnMut(bool) + Send + 'static,
+    {
+        GpioDriver::on_edge(self, trigger, callback)
+    }
+}
+
+/// Destino de un nivel digital (true = HIGH, false = LOW).
+///
+/// Permite usar los actuadores digitales tanto con un pin real ([`GpioOutput`])
+/// como con cualquier salida simulada.
+pub trait LevelSink {
+    /// Escribe el nivel indicado.
+    fn write_bool(&mut self, value: bool);
+
+    /// Devuelve el último nivel escrito.
+    fn is_set_high(&self) -> bool;
+}
+
+impl LevelSink for GpioOutput {
+    fn write_bool(&mut self, value: bool) {
+        GpioOutput::write_bool(self, value)
+    }
+
+    fn is_set_high(&self) -> bool {
+        GpioOutput::is_set_high(self)
+    }
+}
+
+/// Driver mínimo para escribir un pin digital de salida en Raspberry Pi.
+///
+/// Es la base de actuadores como relés o LEDs. Con la feature `mock` escribe
+/// un pin simulado (ver `mock_level`).
+pub struct GpioOutput {
+    pin: OutputPin,
+    pub pin_number: u8,
+}
+
+impl GpioOutput {
+    /// Configura el pin BCM indicado como salida, inicialmente en LOW.
+    /// Devuelve Err si rppal falla (pin inválido, permisos, etc.).
+    pub fn new(pin_number: u8) -> Result<Self, Box<dyn Error>> {
+        #[cfg(not(feature = "mock"))]
+        let pin = Gpio::new()?.get(pin_number)?.into_output_low();
+        #[cfg(feature = "mock")]
+        let pin = OutputPin::new(pin_number);
+
+        Ok(Self { pin, pin_number })
+    }
+
+    /// Pone el pin en HIGH.
+    pub fn set_high(&mut self) {
+        self.pin.set_high();
+    }
+
+    /// Pone el pin en LOW.
+    pub fn set_low(&mut self) {
+        self.pin.set_low();
+    }
+
+    /// Escribe un booleano: true = HIGH, false = LOW
+    pub fn write_bool(&mut self, value: bool) {
+        if value { self.set_high() } else { self.set_low() }
+    }
+
+    /// Devuelve el último nivel escrito: true = HIGH, false = LOW
+    pub fn is_set_high(&self) -> bool {
+        self.pin.is_set_high()
+    }
 }
@@ -0,0 +1,133 @@
+//! Pines simulados en memoria para la feature `mock`.
+//!
+//! Todos los drivers de un mismo número de pin comparten su nivel: lo que
+//! escribe un [`GpioOutput`](super::GpioOutput) o se fija con
+//! [`set_mock_level`](super::set_mock_level) lo leen los
+//! [`GpioDriver`](super::GpioDriver) de ese pin, y cada cambio dispara sus
+//! callbacks de flanco como lo haría rppal.
+
+use rppal::gpio::{Level, Trigger};
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
+
+type Callback = Arc<Mutex<dyn FnMut(Level) + Send>>;
+
+/// Estado de un pin simulado.
+struct MockPin {
+    high: bool,
+    /// Callbacks de flanco por driver que los registró.
+    callbacks: HashMap<u64, (Trigger, Callback)>,
+}
+
+static PINS: LazyLock<Mutex<HashMap<u8, MockPin>>> = LazyLock::new(Mutex::default);
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+fn pins() -> MutexGuard<'static, HashMap<u8, MockPin>> {
+    PINS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn level(high: bool) -> Level {
+    if high { Level::High } else { Level::Low }
+}
+
+/// Registra el pin si aún no existe, con el nivel `high`.
+fn ensure(pin: u8, high: bool) {
+    pins().entry(pin).or_insert_with(|| MockPin { high, callbacks: HashMap::new() });
+}
+
+/// Fija el nivel de `pin` y dispara fuera del bloqueo los callbacks cuyo
+/// flanco coincide con el cambio.
+pub fn set_level(pin: u8, high: bool) {
+    let triggered: Vec<Callback> = {
+        let mut pins = pins();
+        let state = pins.entry(pin).or_insert_with(|| MockPin { high: !high, callbacks: HashMap::new() });
+        let changed = state.high != high;
+        state.high = high;
+        if !changed {
+            return;
+        }
+        state
+            .callbacks
+            .values()
+            .filter(|(trigger, _)| match trigger {
+                Trigger::Both => true,
+                Trigger::RisingEdge => high,
+                Trigger::FallingEdge => !high,
+                _ => false,
+            })
+            .map(|(_, callback)| Arc::clone(callback))
+            .collect()
+    };
+    for callback in triggered {
+        let mut callback = callback.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        (*callback)(level(high));
+    }
+}
+
+/// Nivel actual de `pin`; `true` (pull-up) si nadie lo fijó aún.
+pub fn get_level(pin: u8) -> bool {
+    pins().get(&pin).is_none_or(|state| state.high)
+}
+
+/// Entrada simulada, con pull-up: lee HIGH hasta que se fije otro nivel.
+pub struct MockInput {
+    pin: u8,
+    id: u64,
+}
+
+impl MockInput {
+    pub fn new(pin: u8) -> Self {
+        ensure(pin, true);
+        Self { pin, id: NEXT_ID.fetch_add(1, Ordering::Relaxed) }
+    }
+
+    pub fn read(&self) -> Level {
+        level(get_level(self.pin))
+    }
+
+    pub fn set_async_interrupt<C>(&mut self, trigger: Trigger, callback: C) -> Result<(), Box<dyn Error>>
+    where
+        C: FnMut(Level) + Send + 'static,
+    {
+        let callback: Callback = Arc::new(Mutex::new(callback));
+        if let Some(state) = pins().get_mut(&self.pin) {
+            state.callbacks.insert(self.id, (trigger, callback));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for MockInput {
+    /// Como rppal, elimina la interrupción al descartar el pin.
+    fn drop(&mut self) {
+        if let Some(state) = pins().get_mut(&self.pin) {
+            state.callbacks.remove(&self.id);
+        }
+    }
+}
+
+/// Salida simulada: arranca en LOW y escribe el nivel compartido del pin.
+pub struct MockOutput {
+    pin: u8,
+}
+
+impl MockOutput {
+    pub fn new(pin: u8) -> Self {
+        set_level(pin, false);
+        Self { pin }
+    }
+
+    pub fn set_high(&mut self) {
+        set_level(self.pin, true);
+    }
+
+    pub fn set_low(&mut self) {
+        set_level(self.pin, false);
+    }
+
+    pub fn is_set_high(&self) -> bool {
+        get_level(self.pin)
+    }
+}
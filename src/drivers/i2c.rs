@@ -0,0 +1,66 @@
+// src/drivers/i2c.rs
+use rppal::i2c::I2c;
+use std::error::Error;
+
+/// Escritura en un dispositivo I2C ya direccionado.
+///
+/// Abstrae el bus real para que los chips que solo reciben datos (como la
+/// pantalla SSD1306) puedan probarse con una escritura simulada.
+pub trait I2cWrite {
+    /// Escribe `bytes` tal cual en el dispositivo.
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>>;
+}
+
+/// Driver mínimo para un dispositivo I2C en Raspberry Pi.
+///
+/// Cada instancia queda asociada a una dirección de esclavo, de modo que los
+/// sensores solo se preocupan por registros y bytes.
+pub struct I2cDriver {
+    bus: I2c,
+    pub address: u16,
+}
+
+impl I2cDriver {
+    /// Abre el bus I2C principal (bus 1 en la mayoría de Raspberry Pi) para `address`.
+    /// Devuelve Err si rppal falla (bus deshabilitado, permisos, etc.).
+    pub fn new(address: u16) -> Result<Self, Box<dyn Error>> {
+        Self::with_bus(1, address)
+    }
+
+    /// Abre el bus I2C `bus` para el dispositivo en `address`.
+    pub fn with_bus(bus: u8, address: u16) -> Result<Self, Box<dyn Error>> {
+        let mut i2c = I2c::with_bus(bus)?;
+        i2c.set_slave_address(address)?;
+
+        Ok(Self { bus: i2c, address })
+    }
+
+    /// Escribe `bytes` tal cual en el dispositivo.
+    pub fn write(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+        self.bus.write(bytes)?;
+        Ok(())
+    }
+
+    /// Lee tantos bytes como quepan en `buffer`.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<(), Box<dyn Error>> {
+        self.bus.read(buffer)?;
+        Ok(())
+    }
+
+    /// Escribe un byte en el registro `register`.
+    pub fn write_register(&mut self, register: u8, value: u8) -> Result<(), Box<dyn Error>> {
+        self.write(&[register, value])
+    }
+
+    /// Lee `buffer.len()` bytes consecutivos a partir del registro `register`.
+    pub fn read_registers(&self, register: u8, buffer: &mut [u8]) -> Result<(), Box<dyn Error>> {
+        self.bus.write_read(&[register], buffer)?;
+        Ok(())
+    }
+}
+
+impl I2cWrite for I2cDriver {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+        I2cDriver::write(self, bytes)
+    }
+}
@@ -0,0 +1,105 @@
+// src/drivers/mcp3008.rs
+use crate::drivers::adc::Adc;
+use crate::drivers::spi::{Bus, SlaveSelect, SpiDriver, SpiTransfer};
+use crate::drivers::DriverError;
+use std::error::Error;
+
+/// Número de canales del MCP3008.
+pub const CHANNELS: u8 = 8;
+/// Valor máximo de una conversión de 10 bits.
+pub const MAX_VALUE: u16 = 1023;
+/// Reloj SPI por defecto (1.35 MHz es el máximo a 3.3 V).
+pub const DEFAULT_CLOCK_HZ: u32 = 1_350_000;
+
+/// Bytes a enviar para leer `channel` en modo single-ended:
+/// bit de inicio, `1 D2 D1 D0` en el nibble alto y un byte de relleno.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::mcp3008::command_bytes;
+///
+/// assert_eq!(command_bytes(0), [0x01, 0x80, 0x00]);
+/// assert_eq!(command_bytes(5), [0x01, 0xD0, 0x00]);
+/// ```
+pub fn command_bytes(channel: u8) -> [u8; 3] {
+    [0x01, (0x08 | (channel & 0x07)) << 4, 0x00]
+}
+
+/// Extrae la conversión de 10 bits de los tres bytes recibidos: los dos bits
+/// altos van al final del segundo byte y los ocho bajos en el tercero.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::mcp3008::parse_response;
+///
+/// assert_eq!(parse_response([0x00, 0x02, 0x9A]), 666);
+/// // Los bits que no pertenecen al resultado se ignoran.
+/// assert_eq!(parse_response([0xFF, 0xFF, 0xFF]), 1023);
+/// ```
+pub fn parse_response(bytes: [u8; 3]) -> u16 {
+    (((bytes[1] & 0x03) as u16) << 8) | bytes[2] as u16
+}
+
+/// ADC MCP3008 (8 canales, 10 bits) por SPI.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::mcp3008::Mcp3008;
+/// use iot_framework::drivers::spi::SpiTransfer;
+/// use iot_framework::drivers::DriverError;
+///
+/// /// Simula el chip: comprueba la orden y responde 512 en cualquier canal.
+/// struct FakeBus(Vec<[u8; 3]>);
+/// impl SpiTransfer for FakeBus {
+///     fn transfer(&mut self, write: &[u8], read: &mut [u8]) -> Result<(), DriverError> {
+///         self.0.push(write.try_into().unwrap());
+///         read.copy_from_slice(&[0x00, 0x02, 0x00]);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut adc = Mcp3008::from_transfer(FakeBus(Vec::new()));
+/// assert_eq!(adc.read_channel(3).unwrap(), 512);
+/// assert!(matches!(adc.read_channel(8), Err(DriverError::InvalidChannel(8))));
+/// ```
+pub struct Mcp3008<S: SpiTransfer = SpiDriver> {
+    spi: S,
+}
+
+impl Mcp3008 {
+    /// Abre el MCP3008 conectado a `bus` / `slave_select` con [`DEFAULT_CLOCK_HZ`].
+    pub fn new(bus: Bus, slave_select: SlaveSelect) -> Result<Self, DriverError> {
+        Ok(Self::from_transfer(SpiDriver::new(bus, slave_select, DEFAULT_CLOCK_HZ)?))
+    }
+}
+
+impl<S: SpiTransfer> Mcp3008<S> {
+    /// Crea un MCP3008 sobre cualquier transferencia SPI.
+    pub fn from_transfer(spi: S) -> Self {
+        Self { spi }
+    }
+
+    /// Lee el canal `channel` (0–7) y devuelve su valor de 10 bits.
+    ///
+    /// # Retorna
+    /// - `Err(DriverError::InvalidChannel)` si `channel >= 8`.
+    /// - `Err(DriverError::Bus)` si falla la transferencia.
+    pub fn read_channel(&mut self, channel: u8) -> Result<u16, DriverError> {
+        if channel >= CHANNELS {
+            return Err(DriverError::InvalidChannel(channel));
+        }
+        let mut response = [0u8; 3];
+        self.spi.transfer(&command_bytes(channel), &mut response)?;
+        Ok(parse_response(response))
+    }
+}
+
+impl<S: SpiTransfer> Adc for Mcp3008<S> {
+    fn max_value(&self) -> u16 {
+        MAX_VALUE
+    }
+
+    fn read_raw(&mut self, channel: u8) -> Result<u16, Box<dyn Error>> {
+        Ok(self.read_channel(channel)?)
+    }
+}
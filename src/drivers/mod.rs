@@ -1 +1,30 @@
-pub mod gpio;
\ No newline at end of file
+pub mod gpio;
+pub mod debounce;
+pub mod i2c;
+pub mod pwm;
+pub mod adc;
+pub mod spi;
+pub mod mcp3008;
+#[cfg(feature = "display")]
+pub mod ssd1306;
+pub mod sx127x;
+
+use thiserror::Error;
+
+/// Errores de los drivers de buses (SPI, etc.).
+#[derive(Debug, Error)]
+pub enum DriverError {
+    /// El bus no pudo abrirse o la transferencia falló.
+    #[error("error de bus: {0}")]
+    Bus(String),
+    /// El canal solicitado no existe en el dispositivo.
+    #[error("canal no válido: {0}")]
+    InvalidChannel(u8),
+    /// Los datos superan el tamaño que admite el dispositivo.
+    #[error("datos demasiado grandes: {0} bytes")]
+    PayloadTooLarge(usize),
+    /// El dispositivo no completó la operación a tiempo.
+    #[error("tiempo de espera agotado")]
+    Timeout,
+}
+pub mod nmea;
@@ -0,0 +1,36 @@
+// src/drivers/nmea.rs
+
+/// XOR de todos los bytes de `payload`: el checksum de una sentencia NMEA 0183,
+/// calculado sobre lo que hay entre `$` y `*`.
+pub fn checksum(payload: &str) -> u8 {
+    payload.bytes().fold(0u8, |acc, b| acc ^ b)
+}
+
+/// Comprueba el checksum `*XX` con que termina una sentencia NMEA.
+///
+/// Acepta la sentencia con o sin el `$` inicial y con el `\r\n` final. Devuelve
+/// `false` si falta el delimitador `*`, si `XX` no son dos dígitos
+/// hexadecimales o si no coincide con el XOR de la carga útil.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::nmea::validate_checksum;
+///
+/// assert!(validate_checksum("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n"));
+/// assert!(validate_checksum("GPRMC,,V,,,,,,,,,,N*53"));
+/// // Un dígito alterado por ruido en la línea serie.
+/// assert!(!validate_checksum("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.9,M,46.9,M,,*47"));
+/// // Sin el delimitador del checksum.
+/// assert!(!validate_checksum("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,47"));
+/// assert!(!validate_checksum("$GPGSV,3,1,11*7"));
+/// ```
+pub fn validate_checksum(sentence: &str) -> bool {
+    let sentence = sentence.trim_end();
+    let body = sentence.strip_prefix('$').unwrap_or(sentence);
+    let Some((payload, expected)) = body.rsplit_once('*') else {
+        return false;
+    };
+    expected.len() == 2
+        && expected.bytes().all(|b| b.is_ascii_hexdigit())
+        && u8::from_str_radix(expected, 16).ok() == Some(checksum(payload))
+}
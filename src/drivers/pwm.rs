@@ -0,0 +1,35 @@
+// src/drivers/pwm.rs
+use rppal::pwm::{Polarity, Pwm};
+use std::error::Error;
+
+pub use rppal::pwm::Channel;
+
+/// Driver mínimo para el PWM por hardware de Raspberry Pi.
+///
+/// Los canales `Pwm0`/`Pwm1` deben habilitarse en `/boot/config.txt`
+/// (`dtoverlay=pwm` o `dtoverlay=pwm-2chan`).
+pub struct PwmDriver {
+    pwm: Pwm,
+    pub frequency: f64,
+}
+
+impl PwmDriver {
+    /// Habilita el canal indicado a `frequency` Hz con ciclo de trabajo 0.
+    /// Devuelve Err si rppal falla (canal no habilitado, permisos, etc.).
+    pub fn new(channel: Channel, frequency: f64) -> Result<Self, Box<dyn Error>> {
+        let pwm = Pwm::with_frequency(channel, frequency, 0.0, Polarity::Normal, true)?;
+
+        Ok(Self { pwm, frequency })
+    }
+
+    /// Fija el ciclo de trabajo (0.0 = siempre LOW, 1.0 = siempre HIGH).
+    pub fn set_duty_cycle(&mut self, duty_cycle: f64) -> Result<(), Box<dyn Error>> {
+        self.pwm.set_duty_cycle(duty_cycle)?;
+        Ok(())
+    }
+
+    /// Devuelve el ciclo de trabajo actual.
+    pub fn duty_cycle(&self) -> Result<f64, Box<dyn Error>> {
+        Ok(self.pwm.duty_cycle()?)
+    }
+}
@@ -0,0 +1,39 @@
+// src/drivers/spi.rs
+use crate::drivers::DriverError;
+use rppal::spi::{Mode, Spi};
+pub use rppal::spi::{Bus, SlaveSelect};
+
+/// Transferencia full-duplex sobre un bus SPI.
+///
+/// Abstrae el bus real para que los chips SPI (como el MCP3008) puedan
+/// probarse con una transferencia simulada.
+pub trait SpiTransfer {
+    /// Envía `write` y guarda en `read` los bytes recibidos a la vez
+    /// (ambos del mismo tamaño).
+    fn transfer(&mut self, write: &[u8], read: &mut [u8]) -> Result<(), DriverError>;
+}
+
+/// Driver mínimo para un dispositivo SPI en Raspberry Pi (modo 0).
+pub struct SpiDriver {
+    spi: Spi,
+    pub clock_hz: u32,
+}
+
+impl SpiDriver {
+    /// Abre `bus` con la línea de selección `slave_select` a `clock_hz`.
+    /// Devuelve Err si rppal falla (SPI deshabilitado, permisos, etc.).
+    pub fn new(bus: Bus, slave_select: SlaveSelect, clock_hz: u32) -> Result<Self, DriverError> {
+        let spi = Spi::new(bus, slave_select, clock_hz, Mode::Mode0)
+            .map_err(|e| DriverError::Bus(format!("spi init: {}", e)))?;
+        Ok(Self { spi, clock_hz })
+    }
+}
+
+impl SpiTransfer for SpiDriver {
+    fn transfer(&mut self, write: &[u8], read: &mut [u8]) -> Result<(), DriverError> {
+        self.spi
+            .transfer(read, write)
+            .map_err(|e| DriverError::Bus(format!("spi: {}", e)))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+// src/drivers/ssd1306.rs
+use crate::drivers::i2c::{I2cDriver, I2cWrite};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, Pixel, Size};
+use std::convert::Infallible;
+use std::error::Error;
+
+/// Dirección I2C habitual de los módulos SSD1306 (0x3D con SA0 a VCC).
+pub const DEFAULT_ADDRESS: u16 = 0x3C;
+/// Ancho de la pantalla en píxeles.
+pub const WIDTH: u32 = 128;
+/// Alto de la pantalla en píxeles.
+pub const HEIGHT: u32 = 64;
+/// Tamaño del framebuffer: una página de 8 filas por byte y columna.
+pub const BUFFER_SIZE: usize = (WIDTH * HEIGHT / 8) as usize;
+
+/// Byte de control que precede a una secuencia de comandos.
+const CONTROL_COMMAND: u8 = 0x00;
+/// Byte de control que precede a datos de pantalla.
+const CONTROL_DATA: u8 = 0x40;
+
+/// Secuencia de arranque para 128x64 con la bomba de carga interna y
+/// direccionamiento horizontal.
+const INIT_SEQUENCE: [u8; 25] = [
+    0xAE, // pantalla apagada
+    0xD5, 0x80, // divisor del reloj
+    0xA8, 0x3F, // multiplexado: 64 filas
+    0xD3, 0x00, // sin desplazamiento vertical
+    0x40, // línea de inicio 0
+    0x8D, 0x14, // bomba de carga activada
+    0x20, 0x00, // direccionamiento horizontal
+    0xA1, // columnas invertidas (conector arriba)
+    0xC8, // filas invertidas
+    0xDA, 0x12, // pines COM alternativos
+    0x81, 0xCF, // contraste
+    0xD9, 0xF1, // precarga
+    0xDB, 0x40, // nivel VCOMH
+    0xA4, // mostrar el contenido de la RAM
+    0xA6, // modo normal (no invertido)
+    0xAF, // pantalla encendida
+];
+
+/// Pantalla OLED SSD1306 de 128x64 por I2C.
+///
+/// Se dibuja en un framebuffer en memoria con `embedded-graphics` (implementa
+/// [`DrawTarget`]) y [`flush`](Self::flush) lo envía completo a la pantalla.
+///
+/// # Ejemplo
+/// ```
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use embedded_graphics::prelude::*;
+/// use iot_framework::drivers::i2c::I2cWrite;
+/// use iot_framework::drivers::ssd1306::{Ssd1306, BUFFER_SIZE};
+///
+/// /// Guarda cada escritura en el bus.
+/// #[derive(Default)]
+/// struct FakeBus(Vec<Vec<u8>>);
+/// impl I2cWrite for FakeBus {
+///     fn write(&mut self, bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+///         self.0.push(bytes.to_vec());
+///         Ok(())
+///     }
+/// }
+///
+/// let mut oled = Ssd1306::from_bus(FakeBus::default()).unwrap();
+/// Pixel(Point::new(3, 9), BinaryColor::On).draw(&mut oled).unwrap();
+/// // Fila 9: página 1, bit 1.
+/// assert_eq!(oled.buffer()[128 + 3], 0b10);
+///
+/// oled.flush().unwrap();
+/// let data: Vec<u8> = oled.bus().0.iter().filter(|w| w[0] == 0x40).flat_map(|w| w[1..].to_vec()).collect();
+/// assert_eq!(data.len(), BUFFER_SIZE);
+/// assert_eq!(data[128 + 3], 0b10);
+/// ```
+pub struct Ssd1306<I: I2cWrite = I2cDriver> {
+    bus: I,
+    buffer: [u8; BUFFER_SIZE],
+}
+
+impl Ssd1306 {
+    /// Abre la pantalla en `address` del bus I2C principal y la inicializa.
+    pub fn new(address: u16) -> Result<Self, Box<dyn Error>> {
+        Self::from_bus(I2cDriver::new(address)?)
+    }
+}
+
+impl<I: I2cWrite> Ssd1306<I> {
+    /// Inicializa la pantalla sobre cualquier escritura I2C, con el
+    /// framebuffer vacío.
+    pub fn from_bus(bus: I) -> Result<Self, Box<dyn Error>> {
+        let mut display = Self { bus, buffer: [0; BUFFER_SIZE] };
+        display.commands(&INIT_SEQUENCE)?;
+        Ok(display)
+    }
+
+    /// Framebuffer actual: byte `x + (y / 8) * 128`, bit `y % 8`.
+    pub fn buffer(&self) -> &[u8; BUFFER_SIZE] {
+        &self.buffer
+    }
+
+    /// Bus I2C subyacente.
+    pub fn bus(&self) -> &I {
+        &self.bus
+    }
+
+    /// Envía el framebuffer completo a la pantalla, una página por escritura.
+    pub fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        // Ventana completa: columnas 0–127, páginas 0–7.
+        self.commands(&[0x21, 0x00, (WIDTH - 1) as u8, 0x22, 0x00, (HEIGHT / 8 - 1) as u8])?;
+        let mut chunk = [0u8; WIDTH as usize + 1];
+        chunk[0] = CONTROL_DATA;
+        for page in self.buffer.chunks(WIDTH as usize) {
+            chunk[1..].copy_from_slice(page);
+            self.bus.write(&chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Apaga la pantalla (el contenido de la RAM se conserva).
+    pub fn power_off(&mut self) -> Result<(), Box<dyn Error>> {
+        self.commands(&[0xAE])
+    }
+
+    fn commands(&mut self, commands: &[u8]) -> Result<(), Box<dyn Error>> {
+        let mut bytes = Vec::with_capacity(commands.len() + 1);
+        bytes.push(CONTROL_COMMAND);
+        bytes.extend_from_slice(commands);
+        self.bus.write(&bytes)
+    }
+}
+
+impl<I: I2cWrite> OriginDimensions for Ssd1306<I> {
+    fn size(&self) -> Size {
+        Size::new(WIDTH, HEIGHT)
+    }
+}
+
+impl<I: I2cWrite> DrawTarget for Ssd1306<I> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    /// Dibuja en el framebuffer; los píxeles fuera de la pantalla se ignoran.
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
+    where
+        P: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let (Ok(x), Ok(y)) = (u32::try_from(point.x), u32::try_from(point.y)) else {
+                continue;
+            };
+            if x >= WIDTH || y >= HEIGHT {
+                continue;
+            }
+            let index = (x + (y / 8) * WIDTH) as usize;
+            let bit = 1 << (y % 8);
+            match color {
+                BinaryColor::On => self.buffer[index] |= bit,
+                BinaryColor::Off => self.buffer[index] &= !bit,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+// src/drivers/sx127x.rs
+use crate::drivers::spi::{Bus, SlaveSelect, SpiDriver, SpiTransfer};
+use crate::drivers::DriverError;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Reloj SPI por defecto (el SX127x admite hasta 10 MHz).
+pub const DEFAULT_CLOCK_HZ: u32 = 8_000_000;
+/// Frecuencia por defecto: canal 868.1 MHz de la banda europea.
+pub const DEFAULT_FREQUENCY_HZ: u32 = 868_100_000;
+/// Tamaño máximo de la carga útil de un paquete LoRa (FIFO de 256 bytes).
+pub const MAX_PAYLOAD: usize = 255;
+/// Versión de silicio que devuelve `RegVersion` en los SX1276/77/78/79.
+pub const CHIP_VERSION: u8 = 0x12;
+/// Frecuencia del oscilador de cristal del módulo.
+const FXOSC_HZ: u64 = 32_000_000;
+
+/// Direcciones de los registros usados (modo LoRa).
+pub mod reg {
+    pub const FIFO: u8 = 0x00;
+    pub const OP_MODE: u8 = 0x01;
+    pub const FRF_MSB: u8 = 0x06;
+    pub const FRF_MID: u8 = 0x07;
+    pub const FRF_LSB: u8 = 0x08;
+    pub const PA_CONFIG: u8 = 0x09;
+    pub const FIFO_ADDR_PTR: u8 = 0x0D;
+    pub const FIFO_TX_BASE_ADDR: u8 = 0x0E;
+    pub const IRQ_FLAGS: u8 = 0x12;
+    pub const MODEM_CONFIG_1: u8 = 0x1D;
+    pub const MODEM_CONFIG_2: u8 = 0x1E;
+    pub const PAYLOAD_LENGTH: u8 = 0x22;
+    pub const VERSION: u8 = 0x42;
+}
+
+/// Bit de `RegOpMode` que selecciona el modo LoRa.
+pub const MODE_LONG_RANGE: u8 = 0x80;
+/// Modos de funcionamiento en los tres bits bajos de `RegOpMode`.
+pub const MODE_SLEEP: u8 = 0x00;
+pub const MODE_STANDBY: u8 = 0x01;
+pub const MODE_TX: u8 = 0x03;
+/// Bit de `RegIrqFlags` que indica fin de transmisión.
+pub const IRQ_TX_DONE: u8 = 0x08;
+/// Bit de dirección que marca una escritura de registro.
+const WRITE: u8 = 0x80;
+
+/// Valor de los registros `RegFrf` para `frequency_hz`:
+/// `frequency * 2^19 / 32 MHz`.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::sx127x::frf;
+///
+/// assert_eq!(frf(868_100_000), 0xD9_06_66);
+/// assert_eq!(frf(915_000_000), 0xE4_C0_00);
+/// ```
+pub fn frf(frequency_hz: u32) -> u32 {
+    (((frequency_hz as u64) << 19) / FXOSC_HZ) as u32
+}
+
+/// Radio LoRa Semtech SX127x (SX1276/77/78/79) por SPI.
+///
+/// Solo transmite, con la configuración habitual de los módulos RFM95:
+/// ancho de banda de 125 kHz, factor de dispersión 7, codificación 4/5,
+/// cabecera explícita, CRC y salida PA_BOOST a 17 dBm.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::drivers::spi::SpiTransfer;
+/// use iot_framework::drivers::sx127x::{reg, Sx127x, CHIP_VERSION, IRQ_TX_DONE, MODE_TX};
+/// use iot_framework::drivers::DriverError;
+/// use std::time::Duration;
+///
+/// /// Simula los registros del chip y guarda lo escrito en la FIFO.
+/// struct FakeRadio { regs: [u8; 128], fifo: Vec<u8> }
+/// impl SpiTransfer for FakeRadio {
+///     fn transfer(&mut self, write: &[u8], read: &mut [u8]) -> Result<(), DriverError> {
+///         let (addr, data) = ((write[0] & 0x7F) as usize, &write[1..]);
+///         if write[0] & 0x80 == 0 {
+///             read[1] = self.regs[addr];
+///         } else if addr == reg::FIFO as usize {
+///             self.fifo.extend_from_slice(data);
+///         } else if addr == reg::IRQ_FLAGS as usize {
+///             self.regs[addr] &= !data[0];
+///         } else {
+///             self.regs[addr] = data[0];
+///             if addr == reg::OP_MODE as usize && data[0] & 0x07 == MODE_TX {
+///                 self.regs[reg::IRQ_FLAGS as usize] |= IRQ_TX_DONE;
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut regs = [0u8; 128];
+/// regs[reg::VERSION as usize] = CHIP_VERSION;
+/// let mut radio = Sx127x::from_transfer(FakeRadio { regs, fifo: Vec::new() }, 868_100_000).unwrap();
+/// radio.transmit(b"hola", Duration::from_millis(100)).unwrap();
+/// assert_eq!(radio.read_register(reg::PAYLOAD_LENGTH).unwrap(), 4);
+///
+/// assert!(matches!(radio.transmit(&[0; 256], Duration::ZERO), Err(DriverError::PayloadTooLarge(256))));
+///
+/// // Sin chip el bus devuelve ceros y la versión no coincide.
+/// assert!(Sx127x::from_transfer(FakeRadio { regs: [0; 128], fifo: Vec::new() }, 868_100_000).is_err());
+/// ```
+pub struct Sx127x<S: SpiTransfer = SpiDriver> {
+    spi: S,
+}
+
+impl Sx127x {
+    /// Abre el SX127x conectado a `bus` / `slave_select` a [`DEFAULT_CLOCK_HZ`]
+    /// y lo sintoniza en `frequency_hz`.
+    pub fn new(bus: Bus, slave_select: SlaveSelect, frequency_hz: u32) -> Result<Self, DriverError> {
+        Self::from_transfer(SpiDriver::new(bus, slave_select, DEFAULT_CLOCK_HZ)?, frequency_hz)
+    }
+}
+
+impl<S: SpiTransfer> Sx127x<S> {
+    /// Inicializa el SX127x sobre cualquier transferencia SPI: comprueba la
+    /// versión del chip, pasa a modo LoRa, fija la frecuencia y la
+    /// configuración del módem y lo deja en standby.
+    ///
+    /// # Retorna
+    /// - `Err(DriverError::Bus)` si falla la transferencia o el chip no responde
+    ///   con [`CHIP_VERSION`].
+    pub fn from_transfer(spi: S, frequency_hz: u32) -> Result<Self, DriverError> {
+        let mut radio = Self { spi };
+        let version = radio.read_register(reg::VERSION)?;
+        if version != CHIP_VERSION {
+            return Err(DriverError::Bus(format!("sx127x no detectado (versión 0x{:02x})", version)));
+        }
+        // El bit LoRa solo puede cambiarse en modo sleep.
+        radio.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_SLEEP)?;
+        radio.set_frequency(frequency_hz)?;
+        radio.write_register(reg::FIFO_TX_BASE_ADDR, 0x00)?;
+        radio.write_register(reg::PA_CONFIG, 0x8F)?;
+        radio.write_register(reg::MODEM_CONFIG_1, 0x72)?;
+        radio.write_register(reg::MODEM_CONFIG_2, 0x74)?;
+        radio.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_STANDBY)?;
+        Ok(radio)
+    }
+
+    /// Lee el registro `addr`.
+    pub fn read_register(&mut self, addr: u8) -> Result<u8, DriverError> {
+        let mut response = [0u8; 2];
+        self.spi.transfer(&[addr & !WRITE, 0x00], &mut response)?;
+        Ok(response[1])
+    }
+
+    /// Escribe `value` en el registro `addr`.
+    pub fn write_register(&mut self, addr: u8, value: u8) -> Result<(), DriverError> {
+        self.spi.transfer(&[addr | WRITE, value], &mut [0u8; 2])
+    }
+
+    /// Sintoniza la portadora en `frequency_hz`.
+    pub fn set_frequency(&mut self, frequency_hz: u32) -> Result<(), DriverError> {
+        let [_, msb, mid, lsb] = frf(frequency_hz).to_be_bytes();
+        self.write_register(reg::FRF_MSB, msb)?;
+        self.write_register(reg::FRF_MID, mid)?;
+        self.write_register(reg::FRF_LSB, lsb)
+    }
+
+    /// Transmite `payload` como un paquete y espera a que termine, hasta `timeout`.
+    ///
+    /// # Retorna
+    /// - `Err(DriverError::PayloadTooLarge)` si `payload` supera [`MAX_PAYLOAD`].
+    /// - `Err(DriverError::Timeout)` si el chip no indica fin de transmisión a tiempo.
+    /// - `Err(DriverError::Bus)` si falla la transferencia.
+    pub fn transmit(&mut self, payload: &[u8], timeout: Duration) -> Result<(), DriverError> {
+        if payload.len() > MAX_PAYLOAD {
+            return Err(DriverError::PayloadTooLarge(payload.len()));
+        }
+        self.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_STANDBY)?;
+        self.write_register(reg::FIFO_ADDR_PTR, 0x00)?;
+        let mut burst = Vec::with_capacity(payload.len() + 1);
+        burst.push(reg::FIFO | WRITE);
+        burst.extend_from_slice(payload);
+        self.spi.transfer(&burst, &mut vec![0u8; burst.len()])?;
+        self.write_register(reg::PAYLOAD_LENGTH, payload.len() as u8)?;
+        self.write_register(reg::IRQ_FLAGS, 0xFF)?;
+        self.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_TX)?;
+
+        let deadline = Instant::now() + timeout;
+        while self.read_register(reg::IRQ_FLAGS)? & IRQ_TX_DONE == 0 {
+            if Instant::now() >= deadline {
+                self.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_STANDBY)?;
+                return Err(DriverError::Timeout);
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        self.write_register(reg::IRQ_FLAGS, IRQ_TX_DONE)
+    }
+
+    /// Pasa el chip a modo sleep (consumo mínimo).
+    pub fn sleep(&mut self) -> Result<(), DriverError> {
+        self.write_register(reg::OP_MODE, MODE_LONG_RANGE | MODE_SLEEP)
+    }
+}
@@ -27,14 +27,7 @@ pub mod config;
 pub mod core;
 
 // Drivers para sensores y actuadores (GPIO, I2C, SPI, etc.)
-pub mod devices{
-    pub mod sensors{
-        pub mod simulated_sensor;
-    }
-    pub mod actuators{
-        pub mod dummy;
-    }
-}
+pub mod devices;
 
 // Comunicación de red (MQTT, AMQP, HTTP, WebSockets, etc.)
 pub mod network;
@@ -44,16 +37,21 @@ pub mod platform;
 
 pub mod drivers;
 
+// Persistencia local de lecturas (SQLite, etc.)
+pub mod storage;
+
 // Reexportar interfaces clave si se desea una API unificada
 pub use core::traits::{
-    actuator::Actuator,
+    actuator::{Actuator, AsyncActuator, BlockingActuator},
     communicator::Communicator,
-    sensor::Sensor,
+    sensor::{AsyncSensor, BlockingSensor, Sensor},
     storage::Storage,
 };
 pub use config::config::Config;
+pub use core::types::{Quality, SensorKind, SensorMetadata, SensorOutput, SensorReading, Unit};
 pub use devices::sensors::simulated_sensor::SimulatedSensor;
 pub use network::console::ConsoleCommunicator;
+#[cfg(feature = "serde")]
 pub use network::mqtt::MqttCommunicator;
 
 
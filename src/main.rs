@@ -96,40 +96,37 @@
 
 
 // src/main.rs
-mod drivers;
-mod devices;
-mod core;
-mod network;
-
-use crate::core::SensorOutput;
-use crate::core::traits::sensor::Sensor;
-use crate::core::traits::communicator::Communicator;
-use crate::core::traits::actuator::Actuator;
-
-use crate::devices::sensors::temperature::Temperature;
-use crate::devices::sensors::rain::RainSensor; // si lo usas
-use crate::devices::actuators::dummy::DummyActuator;
-
-use crate::network::console::ConsoleCommunicator;
-use crate::core::runtime::RuntimeController;
+use iot_framework::config::loader::load_config;
+use iot_framework::core::factory::build_runtime;
+use tokio::sync::watch;
+use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
 async fn main() {
-    // Sensores
-    let temp = Temperature::new("28-00000b0e60f1").unwrap();
-    let rain = RainSensor::new(17, true).unwrap();
-
-    let sensors: Vec<Box<dyn Sensor<Output = SensorOutput> + Send>> = vec![
-        Box::new(temp),
-        Box::new(rain),
-    ];
-
-    // Comunicador
-    let communicator: Box<dyn Communicator<Command = SensorOutput, Response = ()> + Send> =
-        Box::new(ConsoleCommunicator::new());
-
-    let mut runtime = RuntimeController::new(sensors, None, communicator, 5);
-
-    println!("Runtime configurado. Iniciando ciclo de ejecución...");
-    runtime.run().await;
+    // Verbosidad controlada con RUST_LOG (por defecto `info`)
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init();
+
+    // Sensores, actuadores y comunicador se definen en config.toml
+    let config = load_config("config.toml").expect("no se pudo cargar config.toml");
+    let mut runtime = build_runtime(&config).expect("configuración del runtime inválida");
+
+    // Con `hot-reload`, los cambios en config.toml se aplican sin reiniciar
+    #[cfg(feature = "hot-reload")]
+    let _watcher = iot_framework::config::watcher::ConfigWatcher::new("config.toml", runtime.update_sender())
+        .map_err(|e| tracing::warn!("Recarga en caliente desactivada: {}", e))
+        .ok();
+
+    // Señal de apagado: Ctrl+C detiene el ciclo de forma ordenada
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = shutdown_tx.send(true);
+        }
+    });
+
+    tracing::info!("Runtime configurado para {} ({}). Iniciando ciclo de ejecución...",
+        config.device.name, config.device.location);
+    runtime.run(shutdown_rx).await;
 }
@@ -0,0 +1,141 @@
+use std::time::Duration;
+use tokio::time::Instant;
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::alert::Alert;
+use crate::core::ActuatorCommand;
+
+/// `BatchingCommunicator` acumula lecturas y las envía en bloque.
+///
+/// Envuelve otro [`Communicator`] y, en lugar de un mensaje por lectura, llama a
+/// [`Communicator::send_batch`] del comunicador interno cuando ocurre lo primero de:
+/// - Se acumulan `max_batch` lecturas.
+/// - Han pasado `max_age` desde la primera lectura del bloque.
+///
+/// Con `MqttCommunicator` o `HttpCommunicator` cada bloque viaja como un único
+/// arreglo JSON, lo que reduce el tráfico en enlaces celulares.
+///
+/// La antigüedad se comprueba en cada `send()`, no con un temporizador: un bloque
+/// incompleto se envía con la siguiente lectura que llegue tras `max_age`, o al
+/// llamar a [`Communicator::flush`] (el runtime lo hace al apagarse). El reloj
+/// es el de tokio (`tokio::time::Instant`), así que con el tiempo pausado de
+/// las pruebas la antigüedad avanza solo con `tokio::time::advance`.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::network::batching::BatchingCommunicator;
+/// use iot_framework::{Communicator, ConsoleCommunicator, SensorOutput, SensorReading};
+///
+/// let mut batching = BatchingCommunicator::new(ConsoleCommunicator::new(), 3, Duration::from_secs(60));
+/// batching.send(SensorReading::new("temp", SensorOutput::Float(21.0))).unwrap();
+/// batching.send(SensorReading::new("temp", SensorOutput::Float(21.1))).unwrap();
+/// assert_eq!(batching.pending(), 2);
+/// batching.send(SensorReading::new("temp", SensorOutput::Float(21.2))).unwrap();
+/// assert_eq!(batching.pending(), 0); // se alcanzó max_batch
+/// ```
+///
+/// Un bloque incompleto sale con la primera lectura que llega tras `max_age`:
+/// ```
+/// use std::time::Duration;
+/// use iot_framework::network::batching::BatchingCommunicator;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)] async fn main() {
+/// let sent = RecordingCommunicator::new();
+/// let mut batching = BatchingCommunicator::new(sent.clone(), 100, Duration::from_secs(60));
+/// batching.send(SensorReading::new("temp", SensorOutput::Int(1))).unwrap();
+///
+/// tokio::time::advance(Duration::from_secs(59)).await;
+/// batching.send(SensorReading::new("temp", SensorOutput::Int(2))).unwrap();
+/// assert_eq!(batching.pending(), 2);
+/// assert!(sent.readings().is_empty());
+///
+/// tokio::time::advance(Duration::from_secs(1)).await;
+/// batching.send(SensorReading::new("temp", SensorOutput::Int(3))).unwrap();
+/// assert_eq!(batching.pending(), 0);
+/// assert_eq!(sent.values("temp"), [1, 2, 3].map(SensorOutput::Int));
+///
+/// // El bloque siguiente cuenta su antigüedad desde su primera lectura.
+/// batching.send(SensorReading::new("temp", SensorOutput::Int(4))).unwrap();
+/// tokio::time::advance(Duration::from_secs(30)).await;
+/// batching.send(SensorReading::new("temp", SensorOutput::Int(5))).unwrap();
+/// assert_eq!(batching.pending(), 2);
+/// # }
+/// ```
+pub struct BatchingCommunicator<C: Communicator> {
+    inner: C,
+    max_batch: usize,
+    max_age: Duration,
+    buffer: Vec<C::Command>,
+    /// Instante en que entró la primera lectura del bloque actual.
+    started: Option<Instant>,
+}
+
+impl<C: Communicator> BatchingCommunicator<C> {
+    /// Crea un `BatchingCommunicator`.
+    ///
+    /// # Parámetros
+    /// - `inner`: comunicador que recibirá los bloques.
+    /// - `max_batch`: lecturas por bloque (mínimo 1).
+    /// - `max_age`: antigüedad máxima de un bloque antes de enviarse.
+    pub fn new(inner: C, max_batch: usize, max_age: Duration) -> Self {
+        let max_batch = max_batch.max(1);
+        Self {
+            inner,
+            max_batch,
+            max_age,
+            buffer: Vec::with_capacity(max_batch),
+            started: None,
+        }
+    }
+
+    /// Lecturas pendientes de envío.
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Envía el bloque actual al comunicador interno.
+    ///
+    /// Si el envío falla, las lecturas se descartan para no crecer sin límite.
+    fn send_buffer(&mut self) -> Result<(), CommunicatorError> {
+        self.started = None;
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let batch = std::mem::replace(&mut self.buffer, Vec::with_capacity(self.max_batch));
+        self.inner.send_batch(batch)
+    }
+}
+
+impl<C: Communicator> Communicator for BatchingCommunicator<C> {
+    type Command = C::Command;
+    type Response = ();
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        self.buffer.push(command);
+        if self.buffer.len() >= self.max_batch || started.elapsed() >= self.max_age {
+            self.send_buffer()?;
+        }
+        Ok(())
+    }
+
+    /// Reenvía la alerta de inmediato, sin esperar a completar el bloque.
+    fn send_alert(&mut self, alert: Alert) -> Result<(), CommunicatorError>
+    where
+        Self::Command: From<Alert>,
+    {
+        self.inner.send_alert(alert)
+    }
+
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        self.inner.receive()
+    }
+
+    /// Envía el bloque pendiente (aunque esté incompleto) y vacía el comunicador interno.
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.send_buffer()?;
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+use coap::request::RequestBuilder;
+use coap::UdpCoAPClient;
+use coap_lite::RequestType;
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::SensorReading;
+use super::worker::Worker;
+
+/// Puerto UDP estándar de CoAP.
+pub const DEFAULT_PORT: u16 = 5683;
+
+/// Espera por defecto del ACK de cada intento (`ACK_TIMEOUT` de la RFC 7252).
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Intentos por defecto de un mensaje confirmable (envío inicial más
+/// `MAX_RETRANSMIT = 4` retransmisiones).
+pub const DEFAULT_ATTEMPTS: usize = 5;
+
+/// Método de las peticiones que envía [`CoapCommunicator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoapMethod {
+    /// Crea un recurso nuevo con cada lectura.
+    Post,
+    /// Reemplaza el estado del recurso con cada lectura.
+    Put,
+}
+
+/// `CoapCommunicator` envía cada lectura como JSON a un recurso CoAP.
+///
+/// Pensado para redes restringidas (6LoWPAN, NB-IoT) donde una conexión TCP
+/// persistente como la de MQTT es demasiado costosa. Las peticiones son
+/// *confirmables*: si el servidor no responde con un ACK dentro de
+/// `ack_timeout` se retransmiten hasta agotar los intentos, y entonces `send()`
+/// devuelve [`CommunicatorError::Timeout`].
+///
+/// El resto de errores se clasifican según el código de respuesta CoAP:
+/// 4.xx → [`CommunicatorError::Client`], 5.xx → [`CommunicatorError::Server`];
+/// cualquier otra clase es una respuesta inesperada ([`CommunicatorError::Send`]).
+/// Igual que en [`HttpCommunicator`](crate::network::http::HttpCommunicator), el
+/// cliente (asíncrono) vive en un hilo propio y `send()` espera su resultado.
+///
+/// # Ejemplo
+/// ```
+/// use std::net::SocketAddr;
+/// use coap::server::UdpCoapListener;
+/// use coap::Server;
+/// use coap_lite::{CoapRequest, RequestType};
+/// use iot_framework::network::coap::{CoapCommunicator, CoapMethod};
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// // Servidor CoAP local que reenvía método, ruta y payload de cada petición.
+/// let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+/// let port = socket.local_addr().unwrap().port();
+/// let (tx, rx) = std::sync::mpsc::channel();
+/// std::thread::spawn(move || {
+///     tokio::runtime::Runtime::new().unwrap().block_on(async move {
+///         socket.set_nonblocking(true).unwrap();
+///         let listener = UdpCoapListener::from_socket(tokio::net::UdpSocket::from_std(socket).unwrap());
+///         let server = Server::from_listeners(vec![Box::new(listener)]);
+///         server
+///             .run(move |request: Box<CoapRequest<SocketAddr>>| {
+///                 let tx = tx.clone();
+///                 async move {
+///                     let method = *request.get_method();
+///                     let _ = tx.send((method, request.get_path(), request.message.payload.clone()));
+///                     request
+///                 }
+///             })
+///             .await
+///             .unwrap();
+///     });
+/// });
+///
+/// let url = format!("coap://127.0.0.1:{port}/lecturas");
+/// let mut coap = CoapCommunicator::new(&url).unwrap().with_method(CoapMethod::Put);
+/// coap.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+///
+/// let (method, path, payload) = rx.recv().unwrap();
+/// assert_eq!(method, RequestType::Put);
+/// assert_eq!(path, "lecturas");
+/// let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+/// assert_eq!(json["sensor_id"], "temp");
+/// assert_eq!(json["value"]["Float"], 21.5);
+/// ```
+pub struct CoapCommunicator {
+    host: String,
+    port: u16,
+    path: String,
+    method: CoapMethod,
+    ack_timeout: Duration,
+    attempts: usize,
+    /// Hilo de trabajo con el cliente UDP; se lanza con el primer envío.
+    worker: Option<Worker<Vec<u8>>>,
+}
+
+impl CoapCommunicator {
+    /// Crea un `CoapCommunicator` que envía `POST` al recurso `url`
+    /// (`coap://host[:puerto]/ruta`, puerto [`DEFAULT_PORT`] si falta).
+    ///
+    /// El socket se abre con el primer envío.
+    pub fn new(url: &str) -> Result<Self, CommunicatorError> {
+        let (host, port, path) = parse_coap_url(url)?;
+        Ok(Self {
+            host,
+            port,
+            path,
+            method: CoapMethod::Post,
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            attempts: DEFAULT_ATTEMPTS,
+            worker: None,
+        })
+    }
+
+    /// Cambia el método de las peticiones (por defecto `POST`).
+    pub fn with_method(mut self, method: CoapMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Cambia la espera del ACK de cada intento y el número de intentos (mínimo 1).
+    pub fn with_ack_timeout(mut self, ack_timeout: Duration, attempts: usize) -> Self {
+        self.ack_timeout = ack_timeout;
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Lanza el hilo de trabajo con un runtime propio y el cliente UDP.
+    fn spawn(&self) -> Result<Worker<Vec<u8>>, CommunicatorError> {
+        let (host, port, path) = (self.host.clone(), self.port, self.path.clone());
+        let (ack_timeout, attempts) = (self.ack_timeout, self.attempts);
+        let method = match self.method {
+            CoapMethod::Post => RequestType::Post,
+            CoapMethod::Put => RequestType::Put,
+        };
+        Worker::spawn(
+            "coap-communicator",
+            "CoAP",
+            move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| CommunicatorError::Execute(e.to_string()))?;
+                let mut client = runtime
+                    .block_on(UdpCoAPClient::new_udp((host.as_str(), port)))
+                    .map_err(|e| CommunicatorError::Connection(e.to_string()))?;
+                client.set_receive_timeout(ack_timeout);
+                client.set_transport_retries(attempts);
+                Ok((runtime, client, host))
+            },
+            // El hilo espera cada petición bloqueado, pero este runtime no tiene otras tareas.
+            move |(runtime, client, host), body| {
+                let request = RequestBuilder::new(&path, method)
+                    .domain(host.clone())
+                    .confirmable(true)
+                    .data(Some(body))
+                    .build();
+                classify(runtime.block_on(client.send(request)))
+            },
+        )
+    }
+
+    /// Encola un cuerpo JSON en el hilo de trabajo (lanzándolo si hace falta)
+    /// y espera el resultado.
+    fn submit(&mut self, body: Vec<u8>) -> Result<(), CommunicatorError> {
+        let worker = match &self.worker {
+            Some(worker) => worker,
+            None => self.worker.insert(self.spawn()?),
+        };
+        let result = worker.submit(body);
+        // Solo el propio hilo devuelve `Execute`: si terminó, se relanza en el próximo envío.
+        if let Err(CommunicatorError::Execute(_)) = result {
+            self.worker = None;
+        }
+        result
+    }
+}
+
+/// Clasifica la respuesta (o el fallo) de una petición.
+fn classify(result: std::io::Result<coap_lite::CoapResponse>) -> Result<(), CommunicatorError> {
+    let response = result.map_err(|e| match e.kind() {
+        std::io::ErrorKind::TimedOut => CommunicatorError::Timeout,
+        _ => CommunicatorError::Send(e.to_string()),
+    })?;
+    let header = &response.message.header;
+    // El código es `clase.detalle`, con la clase en los tres bits altos.
+    let code = u8::from(header.code);
+    match code >> 5 {
+        2 => Ok(()),
+        4 => Err(CommunicatorError::Client(code as u16, header.get_code())),
+        5 => Err(CommunicatorError::Server(code as u16, header.get_code())),
+        _ => Err(CommunicatorError::Send(format!("respuesta CoAP inesperada: {}", header.get_code()))),
+    }
+}
+
+/// Separa `coap://host[:puerto]/ruta` en sus partes.
+fn parse_coap_url(url: &str) -> Result<(String, u16, String), CommunicatorError> {
+    let invalid = || CommunicatorError::Connection(format!("url CoAP no válida: {}", url));
+    let rest = url.strip_prefix("coap://").ok_or_else(invalid)?;
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+        None => (authority, DEFAULT_PORT),
+    };
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    Ok((host.to_string(), port, path.to_string()))
+}
+
+impl Communicator for CoapCommunicator {
+    /// Tipo de datos a enviar: una lectura completa del runtime.
+    type Command = SensorReading;
+    /// Tipo de respuesta: `()`; el payload de la respuesta se descarta.
+    type Response = ();
+
+    /// Serializa la lectura a JSON y la envía al recurso.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let body = serde_json::to_vec(&command)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        self.submit(body)
+    }
+
+    /// Envía todas las lecturas en una única petición con un arreglo JSON
+    /// (por transferencia por bloques si no cabe en un datagrama).
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let body = serde_json::to_vec(&batch)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        self.submit(body)
+    }
+}
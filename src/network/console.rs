@@ -1,48 +1,172 @@
 use crate::core::traits::communicator::{Communicator, CommunicatorError};
-use crate::core::SensorOutput;
-use crate::Sensor;
+use crate::core::{SensorOutput, SensorReading};
+
+/// Formato con el que [`ConsoleCommunicator`] imprime cada lectura.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConsoleFormat {
+    /// Formato de depuración con prefijo: `[CONSOLE] [<ms>] [<id>] Float(21.5) °C`;
+    /// las lecturas numeradas por el runtime muestran su secuencia (`[<id> #3]`).
+    #[default]
+    Plain,
+    /// La lectura serializada con serde, un objeto JSON por línea (apto para `jq`).
+    #[cfg(feature = "serde")]
+    Json,
+    /// Una línea corta con el valor sin envoltorio: `<ms> <id>=21.5 °C`
+    /// (ver [`ConsoleCommunicator::with_precision`]).
+    Compact,
+}
 
 /// `ConsoleCommunicator` es un comunicador simple que envía datos a la salida estándar (consola).
 ///
 /// Este componente implementa el trait [`Communicator`] y se utiliza principalmente
 /// para depuración o ejecución local, permitiendo visualizar los datos que serían enviados
-/// a un sistema de comunicación real.
+/// a un sistema de comunicación real. El formato de cada línea se elige con
+/// [`ConsoleCommunicator::with_format`] (por defecto [`ConsoleFormat::Plain`]).
+///
+/// # Ejemplo
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::network::console::{ConsoleCommunicator, ConsoleFormat};
+/// use iot_framework::{SensorOutput, SensorReading, Unit};
+///
+/// let mut reading = SensorReading::new("temp", SensorOutput::Float(21.5)).with_unit(Some(Unit::Celsius));
+/// reading.timestamp = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+///
+/// let plain = ConsoleCommunicator::new();
+/// assert_eq!(plain.format_reading(&reading).unwrap(), "[CONSOLE] [1700000000123] [temp] Float(21.5) °C");
+/// assert_eq!(
+///     plain.format_reading(&reading.clone().with_seq(3)).unwrap(),
+///     "[CONSOLE] [1700000000123] [temp #3] Float(21.5) °C"
+/// );
+///
+/// let compact = ConsoleCommunicator::with_format(ConsoleFormat::Compact);
+/// assert_eq!(compact.format_reading(&reading).unwrap(), "1700000000123 temp=21.5 °C");
 ///
-pub struct ConsoleCommunicator;
+/// let dht = SensorOutput::Map([("humidity".into(), 48.0), ("temp".into(), 21.25)].into());
+/// let mut dht = SensorReading::new("dht", dht);
+/// dht.timestamp = reading.timestamp;
+/// assert_eq!(compact.format_reading(&dht).unwrap(), "1700000000123 dht=humidity=48,temp=21.25");
+///
+/// // Con una precisión fija todos los números se muestran con los mismos decimales.
+/// let fixed = ConsoleCommunicator::with_format(ConsoleFormat::Compact).with_precision(1);
+/// assert_eq!(fixed.format_reading(&dht).unwrap(), "1700000000123 dht=humidity=48.0,temp=21.2");
+///
+/// # #[cfg(feature = "serde")] {
+/// let json = ConsoleCommunicator::with_format(ConsoleFormat::Json);
+/// assert_eq!(
+///     json.format_reading(&reading).unwrap(),
+///     r#"{"sensor_id":"temp","timestamp":1700000000123,"value":{"Float":21.5},"unit":"Celsius"}"#
+/// );
+///
+/// // Los valores `Json` se imprimen tal cual y pueden volver a leerse.
+/// let fix = serde_json::json!({"pos": {"lat": 40.4168, "lon": -3.7038}, "sats": [3, 7, 12]});
+/// let mut gps = SensorReading::new("gps", SensorOutput::Json(fix.clone()));
+/// gps.timestamp = reading.timestamp;
+/// let line = json.format_reading(&gps).unwrap();
+/// assert_eq!(serde_json::from_str::<SensorReading>(&line).unwrap(), gps);
+/// let line = compact.format_reading(&gps).unwrap();
+/// let value = line.strip_prefix("1700000000123 gps=").unwrap();
+/// assert_eq!(serde_json::from_str::<serde_json::Value>(value).unwrap(), fix);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ConsoleCommunicator {
+    format: ConsoleFormat,
+    precision: Option<usize>,
+}
 
 impl Communicator for ConsoleCommunicator {
     /// El tipo de datos que se enviará al comunicador.
-    type Command = SensorOutput;
+    type Command = SensorReading;
     /// El tipo de datos que se recibirá como respuesta.
     type Response = ();
 
-    /// Envía un comando a la consola imprimiéndolo con un prefijo identificador.
+    /// Envía una lectura a la consola, una línea por lectura en el formato
+    /// configurado (ver [`format_reading`](ConsoleCommunicator::format_reading)).
     ///
     /// # Parámetros
-    /// - `command`: Cadena de texto a enviar/imprimir.
+    /// - `command`: Lectura a imprimir (id del sensor, marca de tiempo y valor).
     ///
     /// # Retorna
     /// - `Ok(())` si el mensaje fue impreso correctamente.
-    /// - [`CommunicatorError`] en caso de error (no se esperan errores en implementación local).
+    /// - `CommunicatorError::Serialization` si el formato es JSON y la lectura no
+    ///   puede serializarse.
     ///
     /// # Ejemplo
     /// ```
-    /// let mut console_comm = ConsoleCommunicator;
-    /// console_comm.send("Temperatura: 25°C".to_string()).unwrap();
+    /// use iot_framework::{Communicator, ConsoleCommunicator};
+    /// use iot_framework::core::{SensorOutput, SensorReading};
+    ///
+    /// let mut console_comm = ConsoleCommunicator::new();
+    /// let reading = SensorReading::new("lab_temp", SensorOutput::Float(25.0));
+    /// console_comm.send(reading).unwrap();
     /// ```
-    /// Envía datos del sensor a la consola con formato legible.
     fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
-        println!("[CONSOLE] {:?}", command);
+        println!("{}", self.format_reading(&command)?);
         Ok(())
     }
-
-    fn receive(&mut self) -> Result<Self::Response, CommunicatorError> {
-        unimplemented!()
-    }
 }
 
 impl ConsoleCommunicator {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Crea un `ConsoleCommunicator` que imprime en el formato indicado.
+    pub fn with_format(format: ConsoleFormat) -> Self {
+        Self { format, precision: None }
     }
-}
\ No newline at end of file
+
+    /// Muestra los números de punto flotante con `precision` decimales en el
+    /// formato [`ConsoleFormat::Compact`] (ver [`SensorOutput::format`]). Sin
+    /// precisión se usa la representación más corta de cada valor.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Devuelve la línea que [`send`](Communicator::send) imprimiría para `reading`.
+    pub fn format_reading(&self, reading: &SensorReading) -> Result<String, CommunicatorError> {
+        let unit = reading.unit.map(|u| format!(" {}", u)).unwrap_or_default();
+        match self.format {
+            ConsoleFormat::Plain => Ok(format!(
+                "[CONSOLE] [{}] [{}{}] {:?}{}",
+                reading.timestamp_millis(),
+                reading.sensor_id,
+                // Como en JSON, la secuencia 0 (sin asignar) se omite.
+                if reading.seq > 0 { format!(" #{}", reading.seq) } else { String::new() },
+                reading.value,
+                unit
+            )),
+            #[cfg(feature = "serde")]
+            ConsoleFormat::Json => serde_json::to_string(reading)
+                .map_err(|e| CommunicatorError::Serialization(e.to_string())),
+            ConsoleFormat::Compact => Ok(format!(
+                "{} {}={}{}",
+                reading.timestamp_millis(),
+                reading.sensor_id,
+                match self.precision {
+                    Some(precision) => reading.value.format(precision),
+                    None => compact_value(&reading.value),
+                },
+                unit
+            )),
+        }
+    }
+}
+
+/// Valor sin el nombre de la variante: `21.5`, `true`, `HÚMEDO`, `humidity=48,temp=21.3`;
+/// como [`SensorOutput::format`], pero con la representación más corta de cada
+/// número de punto flotante.
+fn compact_value(value: &SensorOutput) -> String {
+    match value {
+        SensorOutput::Float(v) => v.to_string(),
+        SensorOutput::Map(fields) => fields
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(","),
+        // La precisión solo afecta a `Float` y `Map`.
+        other => other.format(0),
+    }
+}
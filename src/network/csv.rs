@@ -0,0 +1,232 @@
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{SensorOutput, SensorReading};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Cabecera escrita al comienzo de cada archivo nuevo.
+const HEADER: &str = "timestamp,sensor_id,seq,value";
+
+/// `CsvCommunicator` registra cada lectura como una fila de un archivo CSV.
+///
+/// Pensado para despliegues sin conectividad: las filas `timestamp,sensor_id,seq,value`
+/// se añaden al final del archivo (la marca de tiempo en milisegundos desde el
+/// UNIX epoch). `seq` es el número de secuencia que asigna el runtime a cada
+/// sensor (`0` si la lectura no lo tiene) y permite descartar filas duplicadas.
+/// Si el archivo es nuevo o está vacío se escribe la cabecera.
+///
+/// Formato de `value`:
+/// - `Bool`, `Int`, `Text`: su representación textual (entrecomillada
+///   según RFC 4180 si contiene comas, comillas o saltos de línea).
+/// - `Float`: siempre con punto decimal (`22.0`), para distinguirlo de un
+///   `Int` al leer el archivo.
+/// - `Bytes`: texto base64.
+/// - `Map`: pares `clave=valor` separados por `;`.
+/// - `Timestamp`: milisegundos desde el UNIX epoch.
+/// - `Json`: el JSON compacto, entrecomillado como cualquier otro texto.
+///
+/// Con un límite de tamaño (`max_bytes`), cuando el archivo lo supera se renombra
+/// a `<nombre>.1.<ext>` (o el primer número libre) y se empieza uno nuevo.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::csv::CsvCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let dir = std::env::temp_dir().join(format!("csv-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("lecturas.csv");
+///
+/// let mut csv = CsvCommunicator::new(&path, None).unwrap();
+/// for (id, value) in [
+///     ("temp", SensorOutput::Float(21.5)),
+///     ("puerta", SensorOutput::Bool(true)),
+///     ("raw", SensorOutput::Bytes(vec![1, 2, 3])),
+///     ("nota", SensorOutput::Text("hola, mundo".into())),
+/// ] {
+///     let mut reading = SensorReading::new(id, value);
+///     reading.timestamp = UNIX_EPOCH + Duration::from_millis(1000);
+///     csv.send(reading).unwrap();
+/// }
+/// let mut numbered = SensorReading::new("temp", SensorOutput::Float(21.7)).with_seq(2);
+/// numbered.timestamp = UNIX_EPOCH + Duration::from_millis(2000);
+/// csv.send(numbered).unwrap();
+/// csv.flush().unwrap();
+///
+/// assert_eq!(
+///     std::fs::read_to_string(&path).unwrap(),
+///     "timestamp,sensor_id,seq,value\n\
+///      1000,temp,0,21.5\n\
+///      1000,puerta,0,true\n\
+///      1000,raw,0,AQID\n\
+///      1000,nota,0,\"hola, mundo\"\n\
+///      2000,temp,2,21.7\n"
+/// );
+///
+/// # #[cfg(feature = "serde")] {
+/// // Un valor `Json` se guarda compacto y entrecomillado; se recupera deshaciendo
+/// // el entrecomillado RFC 4180.
+/// let fix = serde_json::json!({"pos": {"lat": 40.4168, "lon": -3.7038}, "sats": [3, 7, 12]});
+/// csv.send(SensorReading::new("gps", SensorOutput::Json(fix.clone()))).unwrap();
+/// csv.flush().unwrap();
+/// let contents = std::fs::read_to_string(&path).unwrap();
+/// let row = contents.lines().last().unwrap();
+/// let field = row.splitn(4, ',').nth(3).unwrap();
+/// let field = field.strip_prefix('"').unwrap().strip_suffix('"').unwrap().replace("\"\"", "\"");
+/// assert_eq!(serde_json::from_str::<serde_json::Value>(&field).unwrap(), fix);
+/// # }
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+///
+/// Rotación: la cabecera ocupa 30 bytes y cada fila 14, así que con un límite
+/// de 40 cada fila después de la primera empieza archivo nuevo.
+/// ```
+/// use iot_framework::network::csv::CsvCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let dir = std::env::temp_dir().join(format!("csv-rotate-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("lecturas.csv");
+///
+/// let mut csv = CsvCommunicator::new(&path, Some(40)).unwrap();
+/// for i in 1..=3 {
+///     let mut reading = SensorReading::new("temp", SensorOutput::Int(i));
+///     reading.timestamp = UNIX_EPOCH + Duration::from_millis(1000);
+///     csv.send(reading).unwrap();
+/// }
+/// csv.flush().unwrap();
+///
+/// let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+/// let header = "timestamp,sensor_id,seq,value\n";
+/// assert_eq!(read("lecturas.1.csv"), format!("{header}1000,temp,0,1\n"));
+/// assert_eq!(read("lecturas.2.csv"), format!("{header}1000,temp,0,2\n"));
+/// // El archivo activo sigue en la ruta original, con su propia cabecera.
+/// assert_eq!(csv.path(), path);
+/// assert_eq!(read("lecturas.csv"), format!("{header}1000,temp,0,3\n"));
+/// assert!(!dir.join("lecturas.3.csv").exists());
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub struct CsvCommunicator {
+    path: PathBuf,
+    max_bytes: Option<u64>,
+    writer: BufWriter<File>,
+    /// Tamaño actual del archivo, incluidas las filas aún en el buffer.
+    size: u64,
+}
+
+impl CsvCommunicator {
+    /// Abre (o crea) el archivo CSV en `path`.
+    ///
+    /// # Parámetros
+    /// - `path`: archivo de destino; los directorios deben existir.
+    /// - `max_bytes`: tamaño a partir del cual se rota el archivo (`None` = sin rotación).
+    pub fn new(path: impl AsRef<Path>, max_bytes: Option<u64>) -> Result<Self, CommunicatorError> {
+        let path = path.as_ref().to_path_buf();
+        let (writer, size) = open(&path)?;
+        Ok(Self { path, max_bytes, writer, size })
+    }
+
+    /// Ruta del archivo activo.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Renombra el archivo activo al primer `<nombre>.N.<ext>` libre y abre uno nuevo.
+    fn rotate(&mut self) -> Result<(), CommunicatorError> {
+        self.writer.flush().map_err(io_error)?;
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        let ext = self.path.extension().map(|e| e.to_string_lossy().into_owned());
+        let rotated = (1..)
+            .map(|n| {
+                let name = match &ext {
+                    Some(ext) => format!("{stem}.{n}.{ext}"),
+                    None => format!("{stem}.{n}"),
+                };
+                self.path.with_file_name(name)
+            })
+            .find(|candidate| !candidate.exists())
+            .expect("siempre hay un nombre libre");
+        fs::rename(&self.path, rotated).map_err(io_error)?;
+        let (writer, size) = open(&self.path)?;
+        self.writer = writer;
+        self.size = size;
+        Ok(())
+    }
+}
+
+impl Communicator for CsvCommunicator {
+    type Command = SensorReading;
+    type Response = ();
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        if self.max_bytes.is_some_and(|max| self.size >= max) {
+            self.rotate()?;
+        }
+        let row = format!(
+            "{},{},{},{}\n",
+            command.timestamp_millis(),
+            escape(&command.sensor_id),
+            command.seq,
+            escape(&format_value(&command.value))
+        );
+        self.writer.write_all(row.as_bytes()).map_err(io_error)?;
+        self.size += row.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+/// Abre `path` en modo *append*, escribiendo la cabecera si está vacío.
+fn open(path: &Path) -> Result<(BufWriter<File>, u64), CommunicatorError> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(io_error)?;
+    let mut size = file.metadata().map_err(io_error)?.len();
+    let mut writer = BufWriter::new(file);
+    if size == 0 {
+        writeln!(writer, "{}", HEADER).map_err(io_error)?;
+        size = HEADER.len() as u64 + 1;
+    }
+    Ok((writer, size))
+}
+
+fn format_value(value: &SensorOutput) -> String {
+    match value {
+        SensorOutput::Bool(b) => b.to_string(),
+        SensorOutput::Int(v) => v.to_string(),
+        SensorOutput::Float(v) => format!("{:?}", v),
+        SensorOutput::Text(t) => t.clone(),
+        SensorOutput::Bytes(bytes) => STANDARD.encode(bytes),
+        SensorOutput::Timestamp(at) => at.duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0).to_string(),
+        SensorOutput::Map(fields) => fields
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(";"),
+        #[cfg(feature = "serde")]
+        SensorOutput::Json(v) => v.to_string(),
+    }
+}
+
+/// Entrecomilla un campo si contiene caracteres especiales de CSV.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn io_error(e: std::io::Error) -> CommunicatorError {
+    CommunicatorError::Execute(format!("csv: {}", e))
+}
@@ -0,0 +1,109 @@
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::alert::Alert;
+use crate::core::ActuatorCommand;
+
+/// `DownsamplingCommunicator` reenvía solo una de cada `n` lecturas.
+///
+/// Envuelve otro [`Communicator`] para enlaces con poco ancho de banda: los
+/// sensores se leen con frecuencia (los actuadores y el almacenamiento local
+/// reciben todas las lecturas) pero hacia fuera solo sale la primera de cada
+/// grupo de `n`; el resto se descarta sin error. Se compone con
+/// [`BatchingCommunicator`](crate::network::batching::BatchingCommunicator)
+/// en cualquier orden.
+///
+/// La cuenta es común a todos los envíos, no por sensor: con varios sensores
+/// intercalados conviene un runtime (o un comunicador) por grupo de sensores.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::downsampling::DownsamplingCommunicator;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// let sent = RecordingCommunicator::new();
+/// let mut link = DownsamplingCommunicator::new(sent.clone(), 3);
+/// for i in 0..9 {
+///     link.send(SensorReading::new("temp", SensorOutput::Int(i))).unwrap();
+/// }
+/// assert_eq!(sent.values("temp"), [SensorOutput::Int(0), SensorOutput::Int(3), SensorOutput::Int(6)]);
+/// assert_eq!(link.dropped(), 6);
+/// ```
+pub struct DownsamplingCommunicator<C> {
+    inner: C,
+    every: u64,
+    /// Envíos que faltan descartar antes del próximo reenvío.
+    skip: u64,
+    dropped: u64,
+}
+
+impl<C: Communicator> DownsamplingCommunicator<C> {
+    /// Crea un `DownsamplingCommunicator` que reenvía una de cada `every`
+    /// lecturas (mínimo 1, que las reenvía todas).
+    pub fn new(inner: C, every: u64) -> Self {
+        Self {
+            inner,
+            every: every.max(1),
+            skip: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Comunicador envuelto.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Lecturas descartadas hasta ahora.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Cuenta un envío e indica si debe reenviarse.
+    fn keep(&mut self) -> bool {
+        if self.skip == 0 {
+            self.skip = self.every - 1;
+            true
+        } else {
+            self.skip -= 1;
+            self.dropped += 1;
+            false
+        }
+    }
+}
+
+impl<C: Communicator> Communicator for DownsamplingCommunicator<C> {
+    type Command = C::Command;
+    type Response = ();
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        if self.keep() {
+            self.inner.send(command)?;
+        }
+        Ok(())
+    }
+
+    /// Aplica el mismo filtro a cada lectura y reenvía las restantes en un solo bloque.
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let kept: Vec<_> = batch.into_iter().filter(|_| self.keep()).collect();
+        if kept.is_empty() {
+            return Ok(());
+        }
+        self.inner.send_batch(kept)
+    }
+
+    /// Reenvía siempre la alerta: no cuenta para el submuestreo.
+    fn send_alert(&mut self, alert: Alert) -> Result<(), CommunicatorError>
+    where
+        Self::Command: From<Alert>,
+    {
+        self.inner.send_alert(alert)
+    }
+
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        self.inner.receive()
+    }
+
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.inner.flush()
+    }
+}
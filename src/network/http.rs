@@ -0,0 +1,97 @@
+use std::time::Duration;
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::SensorReading;
+use super::worker::{self, Worker};
+
+/// Tiempo máximo de cada petición HTTP.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `HttpCommunicator` envía cada lectura como JSON mediante `POST` a un backend REST.
+///
+/// Implementa el trait [`Communicator`] usando el cliente bloqueante de [`reqwest`].
+/// Como ese cliente no puede usarse dentro del runtime de `tokio`, las peticiones se
+/// ejecutan en un hilo dedicado y `send()` espera su resultado.
+///
+/// Los errores se distinguen según su causa:
+/// - [`CommunicatorError::Timeout`] si el servidor no responde a tiempo.
+/// - [`CommunicatorError::Client`] ante respuestas 4xx.
+/// - [`CommunicatorError::Server`] ante respuestas 5xx.
+/// - [`CommunicatorError::Send`] ante fallos de red o conexión.
+pub struct HttpCommunicator {
+    /// Hilo de trabajo que ejecuta los `POST` con el cuerpo JSON ya serializado.
+    worker: Worker<Vec<u8>>,
+}
+
+impl HttpCommunicator {
+    /// Crea un `HttpCommunicator` que publica en `url`.
+    ///
+    /// # Parámetros
+    /// - `url`: endpoint que recibirá los `POST` (ej. `"https://api.example.com/readings"`).
+    /// - `auth`: valor opcional de la cabecera `Authorization` (ej. `"Bearer <token>"`).
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::network::http::HttpCommunicator;
+    ///
+    /// let http = HttpCommunicator::new("http://localhost:8080/readings", Some("Bearer abc")).unwrap();
+    /// ```
+    pub fn new(url: &str, auth: Option<&str>) -> Result<Self, CommunicatorError> {
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| CommunicatorError::Connection(format!("url no válida: {}", e)))?;
+        let auth = auth.map(str::to_string);
+
+        let worker = Worker::spawn(
+            "http-communicator",
+            "HTTP",
+            || {
+                Client::builder()
+                    .timeout(REQUEST_TIMEOUT)
+                    .build()
+                    .map_err(|e| CommunicatorError::Connection(e.to_string()))
+            },
+            move |client: &mut Client, body| post(client, &url, auth.as_deref(), body),
+        )?;
+        Ok(Self { worker })
+    }
+}
+
+/// Ejecuta un `POST` y clasifica el resultado con [`worker::execute`].
+fn post(
+    client: &Client,
+    url: &reqwest::Url,
+    auth: Option<&str>,
+    body: Vec<u8>,
+) -> Result<(), CommunicatorError> {
+    let mut request = client
+        .post(url.clone())
+        .header(CONTENT_TYPE, "application/json")
+        .body(body);
+    if let Some(auth) = auth {
+        request = request.header(AUTHORIZATION, auth);
+    }
+
+    worker::execute(request)
+}
+
+impl Communicator for HttpCommunicator {
+    /// Tipo de datos a enviar: una lectura completa del runtime.
+    type Command = SensorReading;
+    /// Tipo de respuesta: `()`; el cuerpo de la respuesta HTTP se descarta.
+    type Response = ();
+
+    /// Serializa la lectura a JSON y la envía con `POST`.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let body = serde_json::to_vec(&command)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        self.worker.submit(body)
+    }
+
+    /// Envía todas las lecturas en un único `POST` con un arreglo JSON.
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let body = serde_json::to_vec(&batch)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        self.worker.submit(body)
+    }
+}
@@ -0,0 +1,277 @@
+use std::time::{Duration, UNIX_EPOCH};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{SensorOutput, SensorReading};
+use super::worker::{self, Worker};
+
+/// Tiempo máximo de cada escritura.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Nombre de la medición cuando no se indica otro.
+pub const DEFAULT_MEASUREMENT: &str = "readings";
+
+/// Convierte una lectura en una línea del *line protocol* de InfluxDB:
+/// `medición,sensor=<id>[,unit=<símbolo>] <campos> <timestamp en ns>`.
+///
+/// Campos según el valor:
+/// - `Float` → `value=21.5`; `Int` → `value=42i`; `Bool` → `value=true`.
+/// - `Text` → `value="..."`; `Bytes` → `value="<base64>"`; `Json` → `value="<json>"`.
+/// - `Timestamp` → milisegundos desde el UNIX epoch como entero (`value=1700000000000i`).
+/// - `Map` → un campo por clave (`humidity=48,temp=21.3`).
+///
+/// Si la lectura tiene número de secuencia se añade el campo entero `seq`
+/// (`value=21.5,seq=7i`); es un campo y no una etiqueta para no crear una
+/// serie por lectura. Un `Map` con una clave `seq` chocaría con ese campo, así
+/// que en ese caso la lectura se rechaza.
+///
+/// Las comas, espacios y `=` de la medición, las etiquetas y las claves se
+/// escapan con `\`, igual que las comillas y barras de los textos.
+///
+/// # Errores
+/// - `CommunicatorError::Serialization` si la lectura no produce ningún campo
+///   (`Map` vacío), el valor no es finito o un `Map` con clave `seq` lleva
+///   número de secuencia.
+///
+/// # Ejemplo
+/// ```
+/// use std::collections::BTreeMap;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use iot_framework::network::influx::to_line_protocol;
+/// use iot_framework::{SensorOutput, SensorReading, Unit};
+///
+/// let line = |id: &str, value| {
+///     let mut reading = SensorReading::new(id, value);
+///     reading.timestamp = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+///     to_line_protocol(&reading, "ambiente").unwrap()
+/// };
+///
+/// assert_eq!(line("t1", SensorOutput::Float(21.5)), "ambiente,sensor=t1 value=21.5 1700000000123000000");
+/// assert_eq!(line("pulsos", SensorOutput::Int(42)), "ambiente,sensor=pulsos value=42i 1700000000123000000");
+/// assert_eq!(line("puerta", SensorOutput::Bool(true)), "ambiente,sensor=puerta value=true 1700000000123000000");
+/// assert_eq!(
+///     line("reloj", SensorOutput::Timestamp(UNIX_EPOCH + Duration::from_secs(1_700_000_000))),
+///     "ambiente,sensor=reloj value=1700000000000i 1700000000123000000"
+/// );
+///
+/// let dht = SensorOutput::Map(BTreeMap::from([("humidity".into(), 48.0), ("temp".into(), 21.25)]));
+/// assert_eq!(line("dht", dht), "ambiente,sensor=dht humidity=48,temp=21.25 1700000000123000000");
+///
+/// // Espacios, comas y `=` en las etiquetas se escapan.
+/// assert_eq!(
+///     line("sala 2,norte=a", SensorOutput::Text("dice \"hola\"".into())),
+///     r#"ambiente,sensor=sala\ 2\,norte\=a value="dice \"hola\"" 1700000000123000000"#
+/// );
+///
+/// let mut temp = SensorReading::new("t1", SensorOutput::Float(20.0)).with_unit(Some(Unit::Celsius));
+/// temp.timestamp = UNIX_EPOCH;
+/// assert_eq!(to_line_protocol(&temp, "ambiente").unwrap(), "ambiente,sensor=t1,unit=°C value=20 0");
+///
+/// let temp = temp.with_seq(7);
+/// assert_eq!(to_line_protocol(&temp, "ambiente").unwrap(), "ambiente,sensor=t1,unit=°C value=20,seq=7i 0");
+///
+/// // La clave `seq` de un Map no puede convivir con el número de secuencia.
+/// let pump = SensorOutput::Map(BTreeMap::from([("seq".into(), 3.0), ("flow".into(), 1.5)]));
+/// let pump = SensorReading::new("bomba", pump);
+/// assert!(to_line_protocol(&pump, "ambiente").is_ok());
+/// assert!(to_line_protocol(&pump.with_seq(7), "ambiente").is_err());
+/// ```
+pub fn to_line_protocol(reading: &SensorReading, measurement: &str) -> Result<String, CommunicatorError> {
+    let mut line = escape_key(measurement, false);
+    line.push_str(",sensor=");
+    line.push_str(&escape_key(&reading.sensor_id, true));
+    if let Some(unit) = reading.unit {
+        line.push_str(",unit=");
+        line.push_str(&escape_key(unit.symbol(), true));
+    }
+    line.push(' ');
+
+    let fields = match &reading.value {
+        SensorOutput::Float(v) => format!("value={}", float_field(*v)?),
+        SensorOutput::Int(v) => format!("value={}i", v),
+        SensorOutput::Bool(v) => format!("value={}", v),
+        SensorOutput::Text(t) => format!("value={}", string_field(t)),
+        SensorOutput::Bytes(bytes) => format!("value={}", string_field(&STANDARD.encode(bytes))),
+        SensorOutput::Timestamp(at) => format!("value={}i", at.duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0)),
+        #[cfg(feature = "serde")]
+        SensorOutput::Json(v) => format!("value={}", string_field(&v.to_string())),
+        SensorOutput::Map(map) if map.is_empty() => {
+            return Err(CommunicatorError::Serialization(format!(
+                "{}: lectura sin campos",
+                reading.sensor_id
+            )))
+        }
+        SensorOutput::Map(map) if reading.seq != 0 && map.contains_key("seq") => {
+            return Err(CommunicatorError::Serialization(format!(
+                "{}: la clave \"seq\" choca con el número de secuencia",
+                reading.sensor_id
+            )))
+        }
+        SensorOutput::Map(map) => map
+            .iter()
+            .map(|(k, v)| Ok(format!("{}={}", escape_key(k, true), float_field(*v)?)))
+            .collect::<Result<Vec<_>, CommunicatorError>>()?
+            .join(","),
+    };
+    line.push_str(&fields);
+    if reading.seq != 0 {
+        line.push_str(&format!(",seq={}i", reading.seq));
+    }
+
+    let nanos = reading
+        .timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    line.push(' ');
+    line.push_str(&nanos.to_string());
+    Ok(line)
+}
+
+/// Escapa comas y espacios (y `=` si `equals` es true, para etiquetas y claves).
+fn escape_key(s: &str, equals: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == ',' || c == ' ' || (equals && c == '=') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Valor de campo de texto: entre comillas, escapando `"` y `\`.
+fn string_field(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Valor de campo decimal; InfluxDB no admite NaN ni infinitos.
+fn float_field(v: f32) -> Result<String, CommunicatorError> {
+    if v.is_finite() {
+        Ok(v.to_string())
+    } else {
+        Err(CommunicatorError::Serialization(format!("valor no finito: {}", v)))
+    }
+}
+
+/// `InfluxCommunicator` escribe las lecturas directamente en InfluxDB.
+///
+/// Cada lectura se convierte con [`to_line_protocol`] y se envía con `POST`
+/// al endpoint de escritura; los lotes van en una sola petición, una línea por
+/// lectura. Igual que [`HttpCommunicator`](crate::network::http::HttpCommunicator),
+/// las peticiones se ejecutan en un hilo dedicado con el cliente bloqueante de
+/// [`reqwest`], y los errores se clasifican en `Timeout`, `Client` (4xx),
+/// `Server` (5xx) o `Send`.
+pub struct InfluxCommunicator {
+    worker: Worker<String>,
+    measurement: String,
+}
+
+impl InfluxCommunicator {
+    /// Escribe en InfluxDB 2.x (`/api/v2/write`) en `bucket` de `org`,
+    /// autenticando con `token`.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::network::influx::InfluxCommunicator;
+    ///
+    /// let influx = InfluxCommunicator::v2("http://localhost:8086", "smartcampus", "sensores", "mi-token")
+    ///     .unwrap()
+    ///     .with_measurement("ambiente");
+    /// ```
+    pub fn v2(base_url: &str, org: &str, bucket: &str, token: &str) -> Result<Self, CommunicatorError> {
+        let mut url = endpoint(base_url, "api/v2/write")?;
+        url.query_pairs_mut()
+            .append_pair("org", org)
+            .append_pair("bucket", bucket)
+            .append_pair("precision", "ns");
+        Self::spawn(url, Some(format!("Token {}", token)))
+    }
+
+    /// Escribe en InfluxDB 1.x (`/write`) en la base de datos `database`.
+    pub fn v1(base_url: &str, database: &str) -> Result<Self, CommunicatorError> {
+        let mut url = endpoint(base_url, "write")?;
+        url.query_pairs_mut()
+            .append_pair("db", database)
+            .append_pair("precision", "ns");
+        Self::spawn(url, None)
+    }
+
+    /// Cambia el nombre de la medición (por defecto [`DEFAULT_MEASUREMENT`]).
+    pub fn with_measurement(mut self, measurement: impl Into<String>) -> Self {
+        self.measurement = measurement.into();
+        self
+    }
+
+    /// Lanza el hilo de trabajo que realiza los `POST` a `url`.
+    fn spawn(url: reqwest::Url, auth: Option<String>) -> Result<Self, CommunicatorError> {
+        let worker = Worker::spawn(
+            "influx-communicator",
+            "InfluxDB",
+            || {
+                Client::builder()
+                    .timeout(REQUEST_TIMEOUT)
+                    .build()
+                    .map_err(|e| CommunicatorError::Connection(e.to_string()))
+            },
+            move |client: &mut Client, body| write(client, &url, auth.as_deref(), body),
+        )?;
+        Ok(Self {
+            worker,
+            measurement: DEFAULT_MEASUREMENT.to_string(),
+        })
+    }
+}
+
+/// Une `base_url` con la ruta del endpoint de escritura.
+fn endpoint(base_url: &str, path: &str) -> Result<reqwest::Url, CommunicatorError> {
+    let base = format!("{}/", base_url.trim_end_matches('/'));
+    reqwest::Url::parse(&base)
+        .and_then(|url| url.join(path))
+        .map_err(|e| CommunicatorError::Connection(format!("url no válida: {}", e)))
+}
+
+/// Ejecuta el `POST` de escritura y clasifica el resultado con [`worker::execute`].
+fn write(
+    client: &Client,
+    url: &reqwest::Url,
+    auth: Option<&str>,
+    body: String,
+) -> Result<(), CommunicatorError> {
+    let mut request = client
+        .post(url.clone())
+        .header(CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(body);
+    if let Some(auth) = auth {
+        request = request.header(AUTHORIZATION, auth);
+    }
+
+    worker::execute(request)
+}
+
+impl Communicator for InfluxCommunicator {
+    /// Tipo de datos a enviar: una lectura completa del runtime.
+    type Command = SensorReading;
+    /// Tipo de respuesta: `()`; InfluxDB responde `204 No Content`.
+    type Response = ();
+
+    /// Escribe la lectura como una línea.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let line = to_line_protocol(&command, &self.measurement)?;
+        self.worker.submit(line)
+    }
+
+    /// Escribe todas las lecturas en un único `POST`, una línea por lectura.
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let lines = batch
+            .iter()
+            .map(|reading| to_line_protocol(reading, &self.measurement))
+            .collect::<Result<Vec<_>, _>>()?;
+        if lines.is_empty() {
+            return Ok(());
+        }
+        self.worker.submit(lines.join("\n"))
+    }
+}
@@ -0,0 +1,167 @@
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::codec;
+use crate::core::SensorReading;
+use crate::drivers::spi::{Bus, SlaveSelect, SpiDriver, SpiTransfer};
+use crate::drivers::sx127x::{Sx127x, MAX_PAYLOAD};
+use crate::drivers::DriverError;
+use std::time::Duration;
+
+/// Tiempo máximo por defecto para transmitir un paquete (a SF7 / 125 kHz un
+/// paquete de 255 bytes tarda unos 400 ms en el aire).
+pub const DEFAULT_TX_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Codifica una lectura como carga útil de un paquete LoRa, en el formato
+/// binario de [`codec::encode_reading`]: id del sensor, marca de tiempo en
+/// milisegundos, número de secuencia y el valor con su etiqueta de variante.
+///
+/// # Retorna
+/// - `CommunicatorError::Serialization` si la lectura no cabe en los
+///   [`MAX_PAYLOAD`] bytes de un paquete.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::lora::{decode_frame, encode_frame};
+/// use iot_framework::{SensorOutput, SensorReading};
+///
+/// let reading = SensorReading::new("t1", SensorOutput::Float(21.5)).with_seq(42);
+/// let frame = encode_frame(&reading).unwrap();
+/// assert_eq!(frame.len(), 3 + 8 + 1 + 5);
+/// let decoded = decode_frame(&frame).unwrap();
+/// assert_eq!((decoded.seq, decoded.value), (42, reading.value));
+///
+/// let big = SensorReading::new("t1", SensorOutput::Bytes(vec![0; 250]));
+/// assert!(encode_frame(&big).is_err());
+/// ```
+pub fn encode_frame(reading: &SensorReading) -> Result<Vec<u8>, CommunicatorError> {
+    let frame = codec::encode_reading(reading);
+    if frame.len() > MAX_PAYLOAD {
+        return Err(CommunicatorError::Serialization(format!(
+            "trama de {} bytes (máximo {})",
+            frame.len(),
+            MAX_PAYLOAD
+        )));
+    }
+    Ok(frame)
+}
+
+/// Decodifica una trama de [`encode_frame`] como lectura sin unidad y de
+/// calidad buena.
+///
+/// # Retorna
+/// - `CommunicatorError::Serialization` si la trama no es válida.
+pub fn decode_frame(frame: &[u8]) -> Result<SensorReading, CommunicatorError> {
+    codec::decode_reading(frame)
+        .map_err(|e| CommunicatorError::Serialization(format!("trama LoRa no válida: {}", e)))
+}
+
+/// `LoraCommunicator` envía lecturas por radio LoRa con un módulo SX127x
+/// (RFM95 y similares) conectado por SPI.
+///
+/// Cada `send()` transmite un paquete con la lectura en binario
+/// ([`encode_frame`]): una lectura numérica ocupa unos 20 bytes frente a los
+/// ~70 de su JSON, lo que importa con el ciclo de trabajo limitado de las
+/// bandas ISM. Es LoRa punto a punto: el receptor (otra pasarela con el mismo
+/// módulo) decodifica las tramas con [`decode_frame`]; no implementa el
+/// protocolo LoRaWAN.
+///
+/// Si el chip no termina de transmitir antes del tiempo de espera el envío
+/// falla con [`CommunicatorError::Timeout`].
+///
+/// # Ejemplo
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// use iot_framework::drivers::spi::SpiTransfer;
+/// use iot_framework::drivers::sx127x::{reg, Sx127x, CHIP_VERSION, IRQ_TX_DONE, MODE_TX};
+/// use iot_framework::drivers::DriverError;
+/// use iot_framework::network::lora::{decode_frame, LoraCommunicator};
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// /// Simula la radio y guarda cada paquete transmitido.
+/// struct FakeRadio { regs: [u8; 128], fifo: Vec<u8>, sent: Arc<Mutex<Vec<Vec<u8>>>> }
+/// impl SpiTransfer for FakeRadio {
+///     fn transfer(&mut self, write: &[u8], read: &mut [u8]) -> Result<(), DriverError> {
+///         let (addr, data) = ((write[0] & 0x7F) as usize, &write[1..]);
+///         if write[0] & 0x80 == 0 {
+///             read[1] = self.regs[addr];
+///         } else if addr == reg::FIFO as usize {
+///             self.fifo = data.to_vec();
+///         } else if addr == reg::IRQ_FLAGS as usize {
+///             self.regs[addr] &= !data[0];
+///         } else {
+///             self.regs[addr] = data[0];
+///             if addr == reg::OP_MODE as usize && data[0] & 0x07 == MODE_TX {
+///                 self.sent.lock().unwrap().push(self.fifo.clone());
+///                 self.regs[reg::IRQ_FLAGS as usize] |= IRQ_TX_DONE;
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let sent = Arc::new(Mutex::new(Vec::new()));
+/// let mut regs = [0u8; 128];
+/// regs[reg::VERSION as usize] = CHIP_VERSION;
+/// let radio = Sx127x::from_transfer(FakeRadio { regs, fifo: Vec::new(), sent: sent.clone() }, 868_100_000).unwrap();
+/// let mut lora = LoraCommunicator::from_radio(radio).with_tx_timeout(Duration::from_millis(100));
+///
+/// lora.send(SensorReading::new("lluvia", SensorOutput::Float(1.5))).unwrap();
+/// let packets = sent.lock().unwrap();
+/// let received = decode_frame(&packets[0]).unwrap();
+/// assert_eq!((received.sensor_id.as_str(), received.value), ("lluvia", SensorOutput::Float(1.5)));
+/// ```
+pub struct LoraCommunicator<S: SpiTransfer = SpiDriver> {
+    radio: Sx127x<S>,
+    tx_timeout: Duration,
+}
+
+impl LoraCommunicator {
+    /// Abre el módulo conectado a `bus` / `slave_select` y lo sintoniza en
+    /// `frequency_hz` (p. ej. [`DEFAULT_FREQUENCY_HZ`](crate::drivers::sx127x::DEFAULT_FREQUENCY_HZ)).
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::drivers::spi::{Bus, SlaveSelect};
+    /// use iot_framework::drivers::sx127x::DEFAULT_FREQUENCY_HZ;
+    /// use iot_framework::network::lora::LoraCommunicator;
+    ///
+    /// let lora = LoraCommunicator::new(Bus::Spi0, SlaveSelect::Ss0, DEFAULT_FREQUENCY_HZ).unwrap();
+    /// ```
+    pub fn new(bus: Bus, slave_select: SlaveSelect, frequency_hz: u32) -> Result<Self, CommunicatorError> {
+        let radio = Sx127x::new(bus, slave_select, frequency_hz)
+            .map_err(|e| CommunicatorError::Connection(format!("sx127x: {}", e)))?;
+        Ok(Self::from_radio(radio))
+    }
+}
+
+impl<S: SpiTransfer> LoraCommunicator<S> {
+    /// Crea el comunicador sobre una radio ya inicializada.
+    pub fn from_radio(radio: Sx127x<S>) -> Self {
+        Self { radio, tx_timeout: DEFAULT_TX_TIMEOUT }
+    }
+
+    /// Cambia el tiempo máximo para transmitir cada paquete.
+    pub fn with_tx_timeout(mut self, timeout: Duration) -> Self {
+        self.tx_timeout = timeout;
+        self
+    }
+}
+
+impl<S: SpiTransfer> Communicator for LoraCommunicator<S> {
+    type Command = SensorReading;
+    type Response = ();
+
+    /// Transmite la trama de `command` y espera a que salga al aire.
+    ///
+    /// # Retorna
+    /// - [`CommunicatorError::Serialization`] si la lectura no cabe en un paquete.
+    /// - [`CommunicatorError::Timeout`] si la transmisión no terminó a tiempo.
+    /// - [`CommunicatorError::Send`] si falló el bus SPI.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let frame = encode_frame(&command)?;
+        self.radio.transmit(&frame, self.tx_timeout).map_err(|e| match e {
+            DriverError::Timeout => CommunicatorError::Timeout,
+            other => CommunicatorError::Send(other.to_string()),
+        })
+    }
+}
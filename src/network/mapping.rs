@@ -0,0 +1,137 @@
+use crate::core::alert::Alert;
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{ActuatorCommand, SensorOutput, SensorReading};
+use std::collections::HashMap;
+
+/// Transformación del valor de una lectura antes de reenviarla.
+pub type TransformFn = Box<dyn FnMut(SensorOutput) -> SensorOutput + Send>;
+
+/// `MappingCommunicator` adapta las lecturas a lo que espera un backend concreto
+/// antes de reenviarlas a otro [`Communicator`].
+///
+/// Renombra el id de cada lectura según un mapa `id → clave` (los ids sin
+/// entrada se reenvían tal cual) y, opcionalmente, transforma su valor con una
+/// función por sensor (ver [`with_transform`](Self::with_transform)). Así los
+/// sensores conservan sus ids y cada backend recibe sus propias claves sin
+/// duplicar la configuración.
+///
+/// Los cambios solo afectan a lo que sale por el comunicador: los actuadores,
+/// el almacenamiento y la caché del runtime siguen viendo los ids originales.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::mapping::MappingCommunicator;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// let recorder = RecordingCommunicator::new();
+/// let mut link = MappingCommunicator::new(recorder.clone(), [("temp", "temperature_c"), ("hum", "rh")])
+///     .with_transform("puerta", |value| match value {
+///         SensorOutput::Bool(open) => SensorOutput::Int(open as i64),
+///         other => other,
+///     });
+/// link.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+/// link.send_batch(vec![
+///     SensorReading::new("puerta", SensorOutput::Bool(true)),
+///     SensorReading::new("otro", SensorOutput::Int(7)),
+/// ])
+/// .unwrap();
+///
+/// let sent: Vec<_> = recorder.readings().into_iter().map(|r| (r.sensor_id, r.value)).collect();
+/// assert_eq!(
+///     sent,
+///     [
+///         ("temperature_c".to_string(), SensorOutput::Float(21.5)),
+///         ("puerta".to_string(), SensorOutput::Int(1)),
+///         ("otro".to_string(), SensorOutput::Int(7)),
+///     ]
+/// );
+/// ```
+pub struct MappingCommunicator<C> {
+    inner: C,
+    renames: HashMap<String, String>,
+    /// Transformaciones indexadas por el id original del sensor.
+    transforms: HashMap<String, TransformFn>,
+}
+
+impl<C> MappingCommunicator<C>
+where
+    C: Communicator<Command = SensorReading>,
+{
+    /// Crea un `MappingCommunicator` con los pares `(id del sensor, clave del backend)`.
+    pub fn new<I, K, V>(inner: C, renames: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            inner,
+            renames: renames.into_iter().map(|(k, v)| (k.into(), v.into())).collect(),
+            transforms: HashMap::new(),
+        }
+    }
+
+    /// Aplica `transform` al valor de las lecturas del sensor `id` (el id
+    /// original, antes de renombrarlo). La unidad no se modifica.
+    pub fn with_transform<F>(mut self, id: impl Into<String>, transform: F) -> Self
+    where
+        F: FnMut(SensorOutput) -> SensorOutput + Send + 'static,
+    {
+        self.transforms.insert(id.into(), Box::new(transform));
+        self
+    }
+
+    /// Comunicador envuelto.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Aplica la transformación y el renombrado de `reading`.
+    fn map(&mut self, mut reading: SensorReading) -> SensorReading {
+        if let Some(transform) = self.transforms.get_mut(&reading.sensor_id) {
+            reading.value = transform(reading.value);
+        }
+        if let Some(key) = self.renames.get(&reading.sensor_id) {
+            reading.sensor_id = key.clone();
+        }
+        reading
+    }
+}
+
+impl<C> Communicator for MappingCommunicator<C>
+where
+    C: Communicator<Command = SensorReading>,
+{
+    type Command = SensorReading;
+    type Response = C::Response;
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let reading = self.map(command);
+        self.inner.send(reading)
+    }
+
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let batch = batch.into_iter().map(|reading| self.map(reading)).collect();
+        self.inner.send_batch(batch)
+    }
+
+    /// Reenvía la alerta con el id del sensor renombrado; el valor no se transforma.
+    fn send_alert(&mut self, mut alert: Alert) -> Result<(), CommunicatorError>
+    where
+        Self::Command: From<Alert>,
+    {
+        if let Some(key) = self.renames.get(&alert.sensor_id) {
+            alert.sensor_id = key.clone();
+        }
+        self.inner.send_alert(alert)
+    }
+
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        self.inner.receive()
+    }
+
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.inner.flush()
+    }
+}
@@ -1,2 +1,25 @@
+#[cfg(feature = "amqp")]
+pub mod amqp;
+pub mod batching;
+#[cfg(feature = "coap")]
+pub mod coap;
 pub mod console;
-pub mod mqtt;
\ No newline at end of file
+pub mod downsampling;
+pub mod mapping;
+pub mod multi;
+pub mod null;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "serde")]
+pub mod mqtt;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "influx")]
+pub mod influx;
+pub mod lora;
+#[cfg(feature = "serde")]
+pub mod serial;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+#[cfg(any(feature = "http", feature = "influx", feature = "coap", feature = "amqp"))]
+mod worker;
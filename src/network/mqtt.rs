@@ -1,81 +1,612 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, TryRecvError, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Duration;
-use rumqttc::{Client, MqttOptions, QoS};
+use rumqttc::{Client, ClientError, Connection, Event, Key, MqttOptions, Packet, QoS, Request, Transport};
+use crate::core::alert::Alert;
 use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{ActuatorCommand, SensorReading};
+
+/// Tiempo máximo de espera para el `CONNACK` del broker al construir el comunicador.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Espera inicial antes de reintentar la conexión con el broker.
+pub const RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Espera máxima entre reintentos; el *backoff* se duplica hasta este valor.
+pub const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Publicaciones que se guardan por defecto mientras no hay conexión.
+pub const DEFAULT_OFFLINE_BUFFER: usize = 100;
+
+/// Mensajes recibidos que esperan a [`Communicator::receive`]. Si el runtime no
+/// los consume a tiempo los siguientes se descartan con un aviso, para que un
+/// broker que inunda el tópico de órdenes no agote la memoria del dispositivo.
+pub const INCOMING_CAPACITY: usize = 100;
+
+/// Sufijo del tópico en que se publican las alertas (`<topic>/alerts`).
+pub const ALERT_TOPIC_SUFFIX: &str = "/alerts";
+
+/// Identificador de cliente por defecto.
+pub const DEFAULT_CLIENT_ID: &str = "iot_framework";
+
+/// Parámetros de conexión con el broker (ver [`MqttCommunicator::connect`]).
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::mqtt::{MqttConnectOptions, MqttTls};
+///
+/// let options = MqttConnectOptions::new("mqtts://broker.example.com", 8883)
+///     .unwrap()
+///     .with_credentials("sensor-01", "secreto")
+///     .with_tls(MqttTls::new("/etc/iot/ca.pem").with_client_auth("/etc/iot/client.pem", "/etc/iot/client.key"));
+/// assert_eq!(options.host, "broker.example.com");
+/// assert_eq!(options.credentials, Some(("sensor-01".into(), "secreto".into())));
+/// assert!(options.tls.unwrap().client_auth.is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttConnectOptions {
+    /// Dirección del broker, sin esquema.
+    pub host: String,
+    pub port: u16,
+    /// Identificador de cliente (por defecto [`DEFAULT_CLIENT_ID`]).
+    pub client_id: String,
+    /// Usuario y contraseña, si el broker los exige.
+    pub credentials: Option<(String, String)>,
+    /// Cifrado TLS; `None` conecta por TCP sin cifrar.
+    pub tls: Option<MqttTls>,
+}
+
+/// Certificados para conectar por TLS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttTls {
+    /// Certificado de la CA que firmó el del broker (PEM).
+    pub ca_cert: PathBuf,
+    /// Certificado y clave privada del cliente (PEM) para TLS mutuo. La clave
+    /// puede ser RSA (`BEGIN RSA PRIVATE KEY`) o PKCS#8 (`BEGIN PRIVATE KEY`).
+    pub client_auth: Option<(PathBuf, PathBuf)>,
+}
+
+impl MqttTls {
+    /// TLS validando el broker con la CA de `ca_cert`.
+    pub fn new(ca_cert: impl Into<PathBuf>) -> Self {
+        Self { ca_cert: ca_cert.into(), client_auth: None }
+    }
+
+    /// Añade el certificado y la clave del cliente (mTLS).
+    pub fn with_client_auth(mut self, cert: impl Into<PathBuf>, key: impl Into<PathBuf>) -> Self {
+        self.client_auth = Some((cert.into(), key.into()));
+        self
+    }
+}
+
+impl MqttConnectOptions {
+    /// Opciones para `broker_url` (con o sin esquema `mqtt://`, `mqtts://` o
+    /// `tcp://`) y `port`, sin credenciales ni TLS.
+    ///
+    /// # Errores
+    /// [`CommunicatorError::Connection`] si la dirección está vacía.
+    pub fn new(broker_url: &str, port: u16) -> Result<Self, CommunicatorError> {
+        let host = broker_url
+            .trim_start_matches("mqtt://")
+            .trim_start_matches("mqtts://")
+            .trim_start_matches("tcp://")
+            .trim_end_matches('/');
+        if host.is_empty() {
+            return Err(CommunicatorError::Connection("broker_url vacío".to_string()));
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            client_id: DEFAULT_CLIENT_ID.to_string(),
+            credentials: None,
+            tls: None,
+        })
+    }
+
+    /// Cambia el identificador de cliente; debe ser único por broker.
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = client_id.into();
+        self
+    }
+
+    /// Autentica con usuario y contraseña.
+    pub fn with_credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Conecta por TLS con los certificados indicados.
+    pub fn with_tls(mut self, tls: MqttTls) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Construye las `MqttOptions` de `rumqttc`, leyendo los certificados.
+    ///
+    /// # Errores
+    /// [`CommunicatorError::Connection`] si algún certificado no puede leerse o
+    /// la clave del cliente no tiene un formato soportado.
+    pub fn to_mqtt_options(&self) -> Result<MqttOptions, CommunicatorError> {
+        let mut options = MqttOptions::new(self.client_id.as_str(), self.host.as_str(), self.port);
+        options.set_keep_alive(Duration::from_secs(20));
+        if let Some((username, password)) = &self.credentials {
+            options.set_credentials(username.as_str(), password.as_str());
+        }
+        if let Some(tls) = &self.tls {
+            let ca = read_pem(&tls.ca_cert)?;
+            let client_auth = match &tls.client_auth {
+                Some((cert, key)) => Some((read_pem(cert)?, client_key(key)?)),
+                None => None,
+            };
+            options.set_transport(Transport::tls(ca, client_auth, None));
+        }
+        Ok(options)
+    }
+}
+
+/// Lee un archivo PEM.
+fn read_pem(path: &Path) -> Result<Vec<u8>, CommunicatorError> {
+    std::fs::read(path)
+        .map_err(|e| CommunicatorError::Connection(format!("no se pudo leer {}: {}", path.display(), e)))
+}
+
+/// Lee la clave privada del cliente y la clasifica según su cabecera PEM.
+fn client_key(path: &Path) -> Result<Key, CommunicatorError> {
+    let pem = read_pem(path)?;
+    let text = String::from_utf8_lossy(&pem);
+    if text.contains("BEGIN RSA PRIVATE KEY") {
+        Ok(Key::RSA(pem))
+    } else if text.contains("BEGIN PRIVATE KEY") {
+        Ok(Key::ECC(pem))
+    } else {
+        Err(CommunicatorError::Connection(format!(
+            "clave de cliente no soportada en {} (se espera RSA o PKCS#8)",
+            path.display()
+        )))
+    }
+}
+
+/// Publicación guardada mientras no hay conexión.
+struct Pending {
+    topic: String,
+    payload: Vec<u8>,
+    retain: bool,
+}
+
+/// Estado de la conexión compartido entre el comunicador y el hilo del event loop.
+struct Link {
+    connected: bool,
+    /// Publicaciones pendientes mientras no hay conexión, de la más antigua a
+    /// la más nueva.
+    offline: VecDeque<Pending>,
+    capacity: usize,
+    /// QoS de todas las publicaciones, también las guardadas.
+    qos: QoS,
+}
+
+impl Link {
+    /// Guarda `payload` para publicarlo en `topic` al reconectar; si el buffer
+    /// se llena descarta el más antiguo y lo informa como error de conexión.
+    fn buffer(&mut self, topic: &str, payload: Vec<u8>, retain: bool) -> Result<(), CommunicatorError> {
+        self.offline.push_back(Pending { topic: topic.to_string(), payload, retain });
+        if self.offline.len() <= self.capacity {
+            return Ok(());
+        }
+        self.offline.pop_front();
+        Err(CommunicatorError::Connection(format!(
+            "broker MQTT sin conexión: buffer de {} lecturas lleno, se descartó la más antigua",
+            self.capacity
+        )))
+    }
+}
 
 /// `MqttCommunicator` es un comunicador que envía datos a un broker MQTT.
 ///
-/// Implementa el trait [`Communicator`], permitiendo la publicación de mensajes
-/// en tópicos MQTT usando la librería [`rumqttc`].
+/// Implementa el trait [`Communicator`], permitiendo la publicación de lecturas
+/// en un tópico MQTT usando la librería [`rumqttc`].
 ///
 /// Está diseñado para aplicaciones IoT donde los datos deben enviarse a un servidor/broker
 /// que pueda distribuirlos a múltiples suscriptores.
-
+///
+/// La conexión se mantiene en un hilo dedicado que recorre el event loop de `rumqttc`;
+/// sin ese hilo las publicaciones se acumularían en la cola interna sin llegar al broker.
+///
+/// Para control remoto, [`subscribe_commands`](Self::subscribe_commands) suscribe un
+/// tópico de órdenes: cada mensaje JSON recibido (`{"actuator_id": ..., "value": ...}`)
+/// se entrega como [`ActuatorCommand`] en [`Communicator::receive`].
+///
+/// Si el broker se cae, el hilo reintenta la conexión con espera exponencial
+/// ([`RECONNECT_INITIAL_BACKOFF`] duplicándose hasta [`RECONNECT_MAX_BACKOFF`]).
+/// Mientras tanto `send()` guarda las publicaciones en un buffer acotado
+/// ([`DEFAULT_OFFLINE_BUFFER`], ver [`with_offline_buffer`](Self::with_offline_buffer))
+/// y las publica en orden al reconectar. Solo cuando el buffer se llena se
+/// descarta la más antigua y `send()` devuelve [`CommunicatorError::Connection`].
+///
+/// Las alertas del runtime ([`Communicator::send_alert`]) se publican como JSON
+/// en un tópico aparte, `<topic>/alerts` ([`ALERT_TOPIC_SUFFIX`]), para que los
+/// servicios de notificación no tengan que filtrar las lecturas.
+///
+/// Todo se publica con [`QoS::AtLeastOnce`] y sin *retain* salvo que se indique
+/// otra cosa con [`with_qos`](Self::with_qos) y [`with_retain`](Self::with_retain).
+///
+/// # Ejemplo
+/// ```no_run
+/// use iot_framework::{Communicator, MqttCommunicator, SensorOutput, SensorReading};
+///
+/// let mut mqtt = MqttCommunicator::new("localhost", 1883, "invernadero/lecturas")
+///     .unwrap()
+///     .with_offline_buffer(500);
+/// // Si el broker no está disponible, la lectura espera en el buffer.
+/// mqtt.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+/// println!("pendientes: {}", mqtt.buffered());
+/// ```
 pub struct MqttCommunicator {
     client: Client,
-    topic_prefix: String,
+    topic: String,
+    /// Conexión y buffer de publicaciones pendientes, compartidos con el event loop.
+    link: Arc<Mutex<Link>>,
+    /// Payloads recibidos en los tópicos suscritos, reenviados por el event loop
+    /// (como mucho [`INCOMING_CAPACITY`] pendientes).
+    incoming: mpsc::Receiver<Vec<u8>>,
+    /// Tópicos suscritos; se vuelven a suscribir tras cada reconexión.
+    subscriptions: Arc<Mutex<Vec<String>>>,
+    /// Si las lecturas se publican con el flag *retain*.
+    retain: bool,
 }
 
 impl MqttCommunicator {
-    /// Crea una nueva instancia de `MqttCommunicator` configurada para conectarse a un broker MQTT.
+    /// Crea una nueva instancia de `MqttCommunicator` conectada a un broker MQTT.
     ///
     /// # Parámetros
-    /// - `broker_url`: Dirección del broker MQTT (por ejemplo, `"mqtt.example.com"`).
-    /// - `topic_prefix`: Prefijo que se antepondrá a todos los tópicos publicados.
+    /// - `broker_url`: Dirección del broker (por ejemplo, `"mqtt://localhost"` o `"test.mosquitto.org"`).
+    ///   El esquema `mqtt://`/`tcp://` es opcional.
+    /// - `port`: Puerto del broker (normalmente `1883`).
+    /// - `topic`: Tópico donde se publicarán todas las lecturas.
+    ///
+    /// # Errores
+    /// Devuelve [`CommunicatorError::Connection`] si la dirección está vacía o si el broker
+    /// no responde con un `CONNACK` dentro de [`CONNECT_TIMEOUT`].
     ///
     /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::MqttCommunicator;
+    ///
+    /// let mut mqtt_comm = MqttCommunicator::new("mqtt://localhost", 1883, "smartcampus/ambiente").unwrap();
     /// ```
-    /// let mut mqtt_comm = MqttCommunicator::new("test.mosquitto.org", "sensores/");
-    /// ```
-    /// 
-    /// 
+    pub fn new(broker_url: &str, port: u16, topic: &str) -> Result<Self, CommunicatorError> {
+        Self::connect(&MqttConnectOptions::new(broker_url, port)?, topic)
+    }
 
-    
-    pub fn new(broker_url: &str, topic_prefix: &str) -> Self {
-        // Configuración básica de conexión MQTT
-        let mut mqtt_options = MqttOptions::new(broker_url, "iot_framework", 1883);
-        mqtt_options.set_keep_alive(Duration::from_secs(20));
+    /// Como [`new`](Self::new), pero con credenciales, TLS o un identificador de
+    /// cliente propio (ver [`MqttConnectOptions`]).
+    ///
+    /// # Errores
+    /// Además de los de [`new`](Self::new), [`CommunicatorError::Connection`] si
+    /// los certificados no pueden leerse o el broker rechaza la conexión (p. ej.
+    /// por credenciales incorrectas o un fallo en el *handshake* TLS).
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::network::mqtt::{MqttCommunicator, MqttConnectOptions, MqttTls};
+    ///
+    /// let options = MqttConnectOptions::new("broker.example.com", 8883)?
+    ///     .with_credentials("sensor-01", "secreto")
+    ///     .with_tls(MqttTls::new("/etc/iot/ca.pem"));
+    /// let mqtt = MqttCommunicator::connect(&options, "planta/lecturas")?;
+    /// # Ok::<(), iot_framework::core::traits::communicator::CommunicatorError>(())
+    /// ```
+    pub fn connect(options: &MqttConnectOptions, topic: &str) -> Result<Self, CommunicatorError> {
+        let (host, port) = (options.host.as_str(), options.port);
+        let mqtt_options = options.to_mqtt_options()?;
 
         // Crea el cliente MQTT
-        let (client, _connection) = Client::new(mqtt_options, 10);
+        let (client, connection) = Client::new(mqtt_options, 10);
+
+        // El event loop corre en su propio hilo: el `Connection` síncrono crea su propio
+        // runtime de tokio y no puede bloquearse dentro del runtime de la aplicación.
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let (incoming_tx, incoming) = mpsc::sync_channel(INCOMING_CAPACITY);
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        let link = Arc::new(Mutex::new(Link {
+            connected: false,
+            offline: VecDeque::new(),
+            capacity: DEFAULT_OFFLINE_BUFFER,
+            qos: QoS::AtLeastOnce,
+        }));
+        let shared = Shared {
+            client: client.clone(),
+            incoming: incoming_tx,
+            subscriptions: Arc::clone(&subscriptions),
+            link: Arc::clone(&link),
+        };
+        thread::Builder::new()
+            .name("mqtt-eventloop".to_string())
+            .spawn(move || drive_connection(connection, ready_tx, shared))
+            .map_err(|e| CommunicatorError::Execute(e.to_string()))?;
+
+        match ready_rx.recv_timeout(CONNECT_TIMEOUT) {
+            Ok(Ok(())) => Ok(MqttCommunicator {
+                client,
+                topic: topic.to_string(),
+                link,
+                incoming,
+                subscriptions,
+                retain: false,
+            }),
+            Ok(Err(e)) => Err(CommunicatorError::Connection(e)),
+            Err(_) => Err(CommunicatorError::Connection(format!(
+                "sin respuesta del broker {}:{}",
+                host, port
+            ))),
+        }
+    }
+
+    /// Suscribe `topic` para recibir órdenes dirigidas a los actuadores.
+    ///
+    /// # Errores
+    /// - [`CommunicatorError::Send`] si la suscripción no pudo encolarse.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::{Communicator, MqttCommunicator};
+    ///
+    /// let mut mqtt = MqttCommunicator::new("localhost", 1883, "invernadero/lecturas").unwrap();
+    /// mqtt.subscribe_commands("invernadero/ordenes").unwrap();
+    /// if let Some(command) = mqtt.receive().unwrap() {
+    ///     println!("orden para {}", command.actuator_id);
+    /// }
+    /// ```
+    pub fn subscribe_commands(&mut self, topic: &str) -> Result<(), CommunicatorError> {
+        self.client
+            .subscribe(topic, QoS::AtLeastOnce)
+            .map_err(|e| CommunicatorError::Send(e.to_string()))?;
+        if let Ok(mut subscriptions) = self.subscriptions.lock() {
+            subscriptions.push(topic.to_string());
+        }
+        Ok(())
+    }
+
+    /// Cambia cuántas publicaciones se guardan mientras no hay conexión
+    /// (por defecto [`DEFAULT_OFFLINE_BUFFER`]; `0` las descarta todas).
+    pub fn with_offline_buffer(self, capacity: usize) -> Self {
+        if let Ok(mut link) = self.link.lock() {
+            link.capacity = capacity;
+            while link.offline.len() > capacity {
+                link.offline.pop_front();
+            }
+        }
+        self
+    }
+
+    /// Cambia la QoS de las publicaciones (por defecto [`QoS::AtLeastOnce`]),
+    /// incluidas las que ya esperan en el buffer sin conexión.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::MqttCommunicator;
+    /// use rumqttc::QoS;
+    ///
+    /// // Estado de una válvula: entrega exactamente una vez y el último valor
+    /// // queda retenido para los nuevos suscriptores.
+    /// let mqtt = MqttCommunicator::new("localhost", 1883, "invernadero/valvula")
+    ///     .unwrap()
+    ///     .with_qos(QoS::ExactlyOnce)
+    ///     .with_retain(true);
+    /// ```
+    pub fn with_qos(self, qos: QoS) -> Self {
+        if let Ok(mut link) = self.link.lock() {
+            link.qos = qos;
+        }
+        self
+    }
+
+    /// Publica las lecturas con el flag *retain* (por defecto desactivado), para
+    /// que el broker entregue el último estado conocido a cada nuevo suscriptor.
+    /// Las alertas nunca se retienen: un suscriptor nuevo recibiría una alerta
+    /// ya pasada.
+    pub fn with_retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    /// QoS con que se publica.
+    pub fn qos(&self) -> QoS {
+        self.link.lock().map(|link| link.qos).unwrap_or(QoS::AtLeastOnce)
+    }
+
+    /// Indica si las lecturas se publican con *retain*.
+    pub fn retain(&self) -> bool {
+        self.retain
+    }
+
+    /// Indica si la conexión con el broker está establecida.
+    pub fn is_connected(&self) -> bool {
+        self.link.lock().map(|link| link.connected).unwrap_or(false)
+    }
+
+    /// Publicaciones guardadas a la espera de reconectar.
+    pub fn buffered(&self) -> usize {
+        self.link.lock().map(|link| link.offline.len()).unwrap_or(0)
+    }
+
+    /// Publica `payload` en `topic` o, sin conexión, lo guarda en el buffer.
+    ///
+    /// Mientras quedan publicaciones guardadas las nuevas se encolan detrás,
+    /// para conservar el orden. Nunca bloquea: si la cola de `rumqttc` está
+    /// llena, la publicación también se guarda.
+    fn publish(&mut self, topic: &str, payload: Vec<u8>, retain: bool) -> Result<(), CommunicatorError> {
+        let mut link = self
+            .link
+            .lock()
+            .map_err(|_| CommunicatorError::Execute("estado MQTT no disponible".to_string()))?;
+        if !link.connected || !link.offline.is_empty() {
+            return link.buffer(topic, payload, retain);
+        }
+        match self.client.try_publish(topic, link.qos, retain, payload) {
+            Ok(()) => Ok(()),
+            Err(ClientError::TryRequest(Request::Publish(publish))) => {
+                link.buffer(topic, publish.payload.to_vec(), retain)
+            }
+            Err(e) => Err(CommunicatorError::Send(e.to_string())),
+        }
+    }
+}
+
+/// Lo que comparte el hilo del event loop con el comunicador.
+struct Shared {
+    client: Client,
+    incoming: mpsc::SyncSender<Vec<u8>>,
+    subscriptions: Arc<Mutex<Vec<String>>>,
+    link: Arc<Mutex<Link>>,
+}
 
-        MqttCommunicator {
-            client,
-            topic_prefix: topic_prefix.to_string(),
+impl Shared {
+    /// Marca la conexión como establecida o caída.
+    fn set_connected(&self, connected: bool) {
+        if let Ok(mut link) = self.link.lock() {
+            link.connected = connected;
+        }
+    }
+
+    /// Publica en orden las lecturas guardadas hasta vaciar el buffer o llenar la
+    /// cola de `rumqttc`; lo que quede se reintenta con el siguiente evento.
+    fn flush_offline(&mut self) {
+        let Ok(mut link) = self.link.lock() else { return };
+        if !link.connected {
+            return;
+        }
+        let qos = link.qos;
+        while let Some(Pending { topic, payload, retain }) = link.offline.pop_front() {
+            match self.client.try_publish(topic.as_str(), qos, retain, payload) {
+                Ok(()) => {}
+                Err(ClientError::TryRequest(Request::Publish(publish))) => {
+                    link.offline.push_front(Pending { topic, payload: publish.payload.to_vec(), retain });
+                    break;
+                }
+                Err(e) => {
+                    tracing::warn!("No se pudo publicar una lectura guardada: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Recorre el event loop de `rumqttc` indefinidamente.
+///
+/// Notifica por `ready` el resultado del primer intento de conexión; a partir de ahí
+/// los errores solo se registran y `rumqttc` reintenta la conexión en la siguiente
+/// iteración, tras una espera que se duplica con cada fallo consecutivo.
+/// Los mensajes publicados en los tópicos suscritos se reenvían por `incoming`
+/// (descartándolos si ya hay [`INCOMING_CAPACITY`] pendientes);
+/// tras cada reconexión se renuevan las suscripciones (la sesión es limpia) y se
+/// publican las lecturas guardadas sin conexión.
+fn drive_connection(
+    mut connection: Connection,
+    ready: mpsc::Sender<Result<(), String>>,
+    mut shared: Shared,
+) {
+    let mut ready = Some(ready);
+    let mut backoff = RECONNECT_INITIAL_BACKOFF;
+    for event in connection.iter() {
+        match event {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                shared.set_connected(true);
+                backoff = RECONNECT_INITIAL_BACKOFF;
+                if let Some(tx) = ready.take() {
+                    let _ = tx.send(Ok(()));
+                } else if let Ok(topics) = shared.subscriptions.lock() {
+                    tracing::info!("Conexión MQTT restablecida");
+                    // `try_subscribe`: este hilo es quien vacía la cola de peticiones.
+                    for topic in topics.iter() {
+                        if let Err(e) = shared.client.try_subscribe(topic.as_str(), QoS::AtLeastOnce) {
+                            tracing::warn!("No se pudo renovar la suscripción a {}: {}", topic, e);
+                        }
+                    }
+                }
+                shared.flush_offline();
+            }
+            Ok(Event::Incoming(Packet::Publish(publish))) => {
+                if let Err(TrySendError::Full(_)) = shared.incoming.try_send(publish.payload.to_vec()) {
+                    tracing::warn!(topic = %publish.topic, "Cola de mensajes MQTT llena; mensaje descartado");
+                }
+            }
+            Ok(_) => shared.flush_offline(),
+            Err(e) => {
+                if let Some(tx) = ready.take() {
+                    let _ = tx.send(Err(e.to_string()));
+                    return;
+                }
+                shared.set_connected(false);
+                tracing::warn!(retry_ms = backoff.as_millis() as u64, "Error en conexión MQTT: {}", e);
+                thread::sleep(backoff);
+                backoff = (backoff * 2).min(RECONNECT_MAX_BACKOFF);
+            }
         }
     }
 }
 
 impl Communicator for MqttCommunicator {
-    /// Tipo de datos a enviar: `(topic, payload)`.
-    type Command = (String, String);
+    /// Tipo de datos a enviar: una lectura completa del runtime.
+    type Command = SensorReading;
     /// Tipo de respuesta: `()`, ya que no se espera un retorno inmediato.
     type Response = ();
 
-    /// Publica un mensaje en un tópico MQTT.
+    /// Publica una lectura en el tópico configurado.
     ///
     /// # Detalles
-    /// - Concatena `topic_prefix` con el tópico recibido para formar el tópico final.
-    /// - Publica el mensaje con [`QoS::AtLeastOnce`], garantizando entrega mínima una vez.
-    ///
-    /// # Parámetros
-    /// - `command`: Una tupla `(topic, payload)` donde:
-    ///   - `topic`: Tópico relativo al `topic_prefix`.
-    ///   - `payload`: Contenido del mensaje.
+    /// - Serializa la lectura a JSON (`{"sensor_id": ..., "timestamp": ..., "value": {...}}`).
+    /// - Publica el mensaje con la QoS configurada (por defecto [`QoS::AtLeastOnce`],
+    ///   entrega mínima una vez) y, con [`with_retain`](MqttCommunicator::with_retain),
+    ///   como mensaje retenido.
     ///
     /// # Retorna
-    /// - `Ok(())` si la publicación fue exitosa.
-    /// - [`CommunicatorError::SendError`] si hubo un fallo en la publicación.
+    /// - `Ok(())` si la publicación fue encolada o guardada para reconectar.
+    /// - [`CommunicatorError::Serialization`] si la lectura no pudo serializarse.
+    /// - [`CommunicatorError::Connection`] si, sin conexión, el buffer se llenó.
+    /// - [`CommunicatorError::Send`] si hubo un fallo en la publicación.
     fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
-        let (topic, payload) = command;
-        let full_topic = format!("{}{}", self.topic_prefix, topic);
+        let payload = serde_json::to_vec(&command)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        let topic = self.topic.clone();
+        self.publish(&topic, payload, self.retain)
+    }
 
-        self.client
-            .publish(full_topic, QoS::AtLeastOnce, false, payload.as_bytes())
-            .map_err(|e| CommunicatorError::SendError(e.to_string()))
-            .map(|_| ())
+    /// Publica todas las lecturas en un único mensaje con un arreglo JSON.
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        let payload = serde_json::to_vec(&batch)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        let topic = self.topic.clone();
+        self.publish(&topic, payload, self.retain)
     }
 
-    fn receive(&mut self) -> Result<Self::Response, CommunicatorError> {
-        unimplemented!()
+    /// Publica la alerta como JSON en `<topic>/alerts`, con el mismo buffer
+    /// sin conexión que las lecturas.
+    fn send_alert(&mut self, alert: Alert) -> Result<(), CommunicatorError> {
+        let payload = serde_json::to_vec(&alert)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        let topic = format!("{}{}", self.topic, ALERT_TOPIC_SUFFIX);
+        self.publish(&topic, payload, false)
     }
-}
\ No newline at end of file
+
+    /// Devuelve la siguiente orden recibida en los tópicos suscritos, si la hay.
+    ///
+    /// # Retorna
+    /// - `Ok(None)` si no hay mensajes pendientes.
+    /// - [`CommunicatorError::Serialization`] si el payload no es un `ActuatorCommand` JSON.
+    /// - [`CommunicatorError::Connection`] si el hilo del event loop terminó.
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        match self.incoming.try_recv() {
+            Ok(payload) => serde_json::from_slice(&payload)
+                .map(Some)
+                .map_err(|e| CommunicatorError::Serialization(e.to_string())),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(CommunicatorError::Connection(
+                "event loop MQTT terminado".to_string(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::core::alert::Alert;
+use crate::core::runtime::BoxedCommunicator;
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{ActuatorCommand, SensorReading};
+
+/// `MultiCommunicator` reenvía cada lectura a varios comunicadores a la vez.
+///
+/// Permite, por ejemplo, publicar por MQTT y guardar a la vez un registro CSV
+/// local. Cada destino se identifica con un nombre. Un destino que falla no
+/// impide que los demás reciban el mensaje: se intentan todos y, si alguno
+/// falló, se devuelve `CommunicatorError::Multiple` con el nombre y el error
+/// de cada uno.
+///
+/// `receive` consulta los destinos en orden y devuelve la primera orden
+/// disponible (los destinos que fallan no impiden consultar los siguientes);
+/// `flush` vacía todos.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::traits::communicator::CommunicatorError;
+/// use iot_framework::network::multi::MultiCommunicator;
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// struct Offline;
+/// impl Communicator for Offline {
+///     type Command = SensorReading;
+///     type Response = ();
+///     fn send(&mut self, _: SensorReading) -> Result<(), CommunicatorError> {
+///         Err(CommunicatorError::Connection("broker caído".into()))
+///     }
+/// }
+///
+/// let csv = RecordingCommunicator::new();
+/// let mut multi = MultiCommunicator::new()
+///     .with("mqtt", Box::new(Offline))
+///     .with("csv", Box::new(csv.clone()));
+///
+/// let err = multi.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap_err();
+/// // El CSV recibió la lectura aunque MQTT falló.
+/// assert_eq!(csv.values("temp"), [SensorOutput::Float(21.5)]);
+/// match err {
+///     CommunicatorError::Multiple(failures) => {
+///         assert_eq!(failures.len(), 1);
+///         assert_eq!(failures[0].0, "mqtt");
+///     }
+///     other => panic!("error inesperado: {other}"),
+/// }
+/// ```
+#[derive(Default)]
+pub struct MultiCommunicator {
+    targets: Vec<(String, BoxedCommunicator)>,
+}
+
+impl MultiCommunicator {
+    /// Crea un `MultiCommunicator` sin destinos.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Añade el destino `communicator` identificado como `name`.
+    pub fn with(mut self, name: impl Into<String>, communicator: BoxedCommunicator) -> Self {
+        self.targets.push((name.into(), communicator));
+        self
+    }
+
+    /// Número de destinos registrados.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Indica si no hay destinos registrados.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Ejecuta `op` en cada destino y agrupa los errores.
+    fn for_each<F>(&mut self, mut op: F) -> Result<(), CommunicatorError>
+    where
+        F: FnMut(&mut BoxedCommunicator) -> Result<(), CommunicatorError>,
+    {
+        let failures: Vec<_> = self
+            .targets
+            .iter_mut()
+            .filter_map(|(name, target)| op(target).err().map(|e| (name.clone(), e)))
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(CommunicatorError::Multiple(failures))
+        }
+    }
+}
+
+impl Communicator for MultiCommunicator {
+    type Command = SensorReading;
+    type Response = ();
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        self.for_each(|target| target.send(command.clone()))
+    }
+
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        self.for_each(|target| target.send_batch(batch.clone()))
+    }
+
+    fn send_alert(&mut self, alert: Alert) -> Result<(), CommunicatorError>
+    where
+        Self::Command: From<Alert>,
+    {
+        self.for_each(|target| target.send_alert(alert.clone()))
+    }
+
+    fn receive(&mut self) -> Result<Option<ActuatorCommand>, CommunicatorError> {
+        let mut failures = Vec::new();
+        for (name, target) in &mut self.targets {
+            match target.receive() {
+                Ok(Some(command)) => return Ok(Some(command)),
+                Ok(None) => {}
+                Err(e) => failures.push((name.clone(), e)),
+            }
+        }
+        if failures.is_empty() {
+            Ok(None)
+        } else {
+            Err(CommunicatorError::Multiple(failures))
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.for_each(|target| target.flush())
+    }
+}
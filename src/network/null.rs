@@ -0,0 +1,134 @@
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::{SensorOutput, SensorReading};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// `NullCommunicator` descarta todas las lecturas sin enviarlas a ningún sitio.
+///
+/// Sirve de referencia para medir el rendimiento del runtime sin el coste de
+/// la red y para ejecuciones de prueba (*dry runs*) de una configuración.
+/// Cada envío siempre tiene éxito y solo incrementa un contador; clonar un
+/// `NullCommunicator` comparte el contador, de modo que puede consultarse
+/// después de entregar el comunicador al runtime.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::null::NullCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// let null = NullCommunicator::new();
+/// let mut boxed: Box<dyn Communicator<Command = SensorReading, Response = ()>> = Box::new(null.clone());
+///
+/// for n in 0..100 {
+///     assert!(boxed.send(SensorReading::new("contador", SensorOutput::Int(n))).is_ok());
+/// }
+/// let batch = vec![SensorReading::new("temp", SensorOutput::Float(21.5)); 5];
+/// assert!(boxed.send_batch(batch).is_ok());
+/// assert!(boxed.flush().is_ok());
+/// assert_eq!(null.sent(), 105);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NullCommunicator {
+    sent: Arc<AtomicU64>,
+}
+
+impl NullCommunicator {
+    /// Crea un `NullCommunicator` con el contador a cero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Número de lecturas descartadas hasta ahora (por este comunicador y sus clones).
+    pub fn sent(&self) -> u64 {
+        self.sent.load(Ordering::Relaxed)
+    }
+}
+
+impl Communicator for NullCommunicator {
+    type Command = SensorReading;
+    type Response = ();
+
+    fn send(&mut self, _command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        self.sent.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn send_batch(&mut self, batch: Vec<Self::Command>) -> Result<(), CommunicatorError> {
+        self.sent.fetch_add(batch.len() as u64, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// `RecordingCommunicator` guarda en memoria cada lectura enviada.
+///
+/// Pensado para pruebas y ejemplos: se entrega un clon al runtime (o a otro
+/// comunicador) y después se consultan las lecturas con
+/// [`readings`](Self::readings). Los clones comparten la misma lista. Las
+/// alertas se guardan como cualquier otra lectura (id `alert/<sensor_id>`,
+/// ver [`Communicator::send_alert`]).
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::null::RecordingCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// let sent = RecordingCommunicator::new();
+/// let mut boxed: Box<dyn Communicator<Command = SensorReading, Response = ()>> = Box::new(sent.clone());
+/// boxed.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+/// boxed.send(SensorReading::new("hum", SensorOutput::Int(48))).unwrap();
+/// boxed.flush().unwrap();
+///
+/// assert_eq!(sent.values("temp"), [SensorOutput::Float(21.5)]);
+/// assert_eq!(sent.readings().len(), 2);
+/// assert_eq!(sent.flushes(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordingCommunicator {
+    readings: Arc<Mutex<Vec<SensorReading>>>,
+    flushes: Arc<AtomicU64>,
+}
+
+impl RecordingCommunicator {
+    /// Crea un `RecordingCommunicator` sin lecturas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copia de las lecturas recibidas, en orden de envío.
+    pub fn readings(&self) -> Vec<SensorReading> {
+        self.lock().clone()
+    }
+
+    /// Valores de las lecturas de `sensor_id`, en orden de envío.
+    pub fn values(&self, sensor_id: &str) -> Vec<SensorOutput> {
+        self.lock()
+            .iter()
+            .filter(|reading| reading.sensor_id == sensor_id)
+            .map(|reading| reading.value.clone())
+            .collect()
+    }
+
+    /// Veces que se vació el comunicador (ver [`Communicator::flush`]).
+    pub fn flushes(&self) -> u64 {
+        self.flushes.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<SensorReading>> {
+        self.readings.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Communicator for RecordingCommunicator {
+    type Command = SensorReading;
+    type Response = ();
+
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        self.lock().push(command);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.flushes.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::SensorReading;
+use rppal::uart::{Parity, Queue, Uart};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Tiempo máximo por defecto para escribir una trama completa.
+pub const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Codifica una lectura como trama JSON terminada en `\n`.
+///
+/// Es el mismo JSON que publica `MqttCommunicator`, una lectura por línea, de
+/// modo que el microcontrolador puede leer hasta el salto de línea y decodificar.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::network::serial::encode_frame;
+/// use iot_framework::{SensorOutput, SensorReading};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let frame = |value| {
+///     let mut reading = SensorReading::new("s1", value);
+///     reading.timestamp = UNIX_EPOCH + Duration::from_millis(1000);
+///     String::from_utf8(encode_frame(&reading).unwrap()).unwrap()
+/// };
+///
+/// let prefix = r#"{"sensor_id":"s1","timestamp":1000,"value":"#;
+/// assert_eq!(frame(SensorOutput::Bool(true)), format!("{prefix}{{\"Bool\":true}}}}\n"));
+/// assert_eq!(frame(SensorOutput::Int(-3)), format!("{prefix}{{\"Int\":-3}}}}\n"));
+/// assert_eq!(frame(SensorOutput::Float(1.5)), format!("{prefix}{{\"Float\":1.5}}}}\n"));
+/// assert_eq!(frame(SensorOutput::Text("SECO".into())), format!("{prefix}{{\"Text\":\"SECO\"}}}}\n"));
+/// assert_eq!(frame(SensorOutput::Bytes(vec![1, 2, 3])), format!("{prefix}{{\"Bytes\":\"AQID\"}}}}\n"));
+/// assert_eq!(
+///     frame(SensorOutput::Map([("temp".to_string(), 21.0)].into())),
+///     format!("{prefix}{{\"Map\":{{\"temp\":21.0}}}}}}\n")
+/// );
+/// assert_eq!(
+///     frame(SensorOutput::Timestamp(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123))),
+///     format!("{prefix}{{\"Timestamp\":1700000000123}}}}\n")
+/// );
+/// assert_eq!(
+///     frame(SensorOutput::Json(serde_json::json!({"sats": [3, 7]}))),
+///     format!("{prefix}{{\"Json\":{{\"sats\":[3,7]}}}}}}\n")
+/// );
+///
+/// // El JSON de la trama nunca lleva saltos de línea propios: un texto con
+/// // `\n` se escapa y la trama sigue siendo una sola línea.
+/// let text = frame(SensorOutput::Text("a\nb".into()));
+/// assert_eq!(text.matches('\n').count(), 1);
+/// ```
+pub fn encode_frame(reading: &SensorReading) -> Result<Vec<u8>, CommunicatorError> {
+    let mut frame = serde_json::to_vec(reading)
+        .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+    frame.push(b'\n');
+    Ok(frame)
+}
+
+/// `SerialCommunicator` envía lecturas por UART como JSON delimitado por líneas.
+///
+/// Pensado para pasarelas que alimentan a un microcontrolador: cada `send()`
+/// escribe una trama ([`encode_frame`]) en el buffer del puerto sin esperar a
+/// que salga por la línea; [`Communicator::flush`] (el runtime lo llama al
+/// apagarse) espera a que se transmita todo. El puerto se abre en modo 8N1.
+///
+/// Si la trama no termina de escribirse antes del tiempo de espera (el
+/// receptor detuvo el flujo, buffer lleno) el envío falla con
+/// [`CommunicatorError::Timeout`] y la trama parcial se descarta.
+pub struct SerialCommunicator {
+    uart: Uart,
+    write_timeout: Duration,
+}
+
+impl SerialCommunicator {
+    /// Abre el puerto `path` (p. ej. `"/dev/serial0"`) a `baud_rate` baudios.
+    ///
+    /// # Ejemplo
+    /// ```no_run
+    /// use iot_framework::network::serial::SerialCommunicator;
+    ///
+    /// let serial = SerialCommunicator::new("/dev/serial0", 115_200).unwrap();
+    /// ```
+    pub fn new(path: &str, baud_rate: u32) -> Result<Self, CommunicatorError> {
+        let uart = Uart::with_path(path, baud_rate, Parity::None, 8, 1)
+            .map_err(|e| CommunicatorError::Connection(format!("{}: {}", path, e)))?;
+        Ok(Self { uart, write_timeout: DEFAULT_WRITE_TIMEOUT })
+    }
+
+    /// Cambia el tiempo máximo para escribir cada trama.
+    pub fn with_write_timeout(mut self, timeout: Duration) -> Self {
+        self.write_timeout = timeout;
+        self
+    }
+
+    /// Escribe `frame` completo con escrituras no bloqueantes hasta agotar el plazo.
+    fn write_frame(&mut self, frame: &[u8]) -> Result<(), CommunicatorError> {
+        let deadline = Instant::now() + self.write_timeout;
+        let mut written = 0;
+        while written < frame.len() {
+            written += self
+                .uart
+                .write(&frame[written..])
+                .map_err(|e| CommunicatorError::Send(e.to_string()))?;
+            if written < frame.len() {
+                if Instant::now() >= deadline {
+                    let _ = self.uart.flush(Queue::Output);
+                    return Err(CommunicatorError::Timeout);
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Communicator for SerialCommunicator {
+    type Command = SensorReading;
+    type Response = ();
+
+    /// Escribe la trama de `command` en el buffer del puerto.
+    ///
+    /// # Retorna
+    /// - [`CommunicatorError::Serialization`] si la lectura no pudo codificarse.
+    /// - [`CommunicatorError::Timeout`] si la escritura no terminó a tiempo.
+    /// - [`CommunicatorError::Send`] si el puerto devolvió un error.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let frame = encode_frame(&command)?;
+        self.write_frame(&frame)
+    }
+
+    /// Espera a que el puerto transmita todo lo escrito.
+    fn flush(&mut self) -> Result<(), CommunicatorError> {
+        self.uart
+            .drain()
+            .map_err(|e| CommunicatorError::Send(e.to_string()))
+    }
+}
@@ -0,0 +1,351 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc as std_mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::time::sleep;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+use crate::core::traits::communicator::{Communicator, CommunicatorError};
+use crate::core::SensorReading;
+
+/// Espera inicial antes de reintentar una conexión caída.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Espera máxima entre reintentos; el *backoff* se duplica hasta este valor.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Tramas que puede acumular un cliente lento del servidor antes de perder las
+/// más antiguas.
+const BROADCAST_CAPACITY: usize = 256;
+
+/// Tramas que el modo cliente encola sin escribir; si el servidor no las
+/// consume a tiempo, `send()` falla en lugar de acumularlas sin límite.
+pub const CLIENT_QUEUE_CAPACITY: usize = 256;
+
+/// Destino de las tramas según el modo del comunicador.
+enum Mode {
+    /// Conexión saliente: las tramas van al hilo de trabajo, que las escribe
+    /// mientras `connected` sea true.
+    Client {
+        frames: mpsc::Sender<String>,
+        connected: Arc<AtomicBool>,
+    },
+    /// Servidor: cada trama se difunde a todos los clientes conectados. La
+    /// señal `_stop` se cierra al descartar el comunicador y detiene el hilo.
+    Server {
+        frames: broadcast::Sender<String>,
+        local_addr: SocketAddr,
+        _stop: oneshot::Sender<()>,
+    },
+}
+
+/// `WebSocketCommunicator` transmite cada lectura como una trama de texto JSON
+/// por WebSocket, pensado para paneles que muestran los datos en vivo.
+///
+/// Dos modos:
+/// - **Cliente** ([`connect`](Self::connect)): se conecta a `ws://...` y, si la
+///   conexión cae, la reintenta en segundo plano con espera exponencial
+///   ([`DEFAULT_INITIAL_BACKOFF`] duplicándose hasta [`DEFAULT_MAX_BACKOFF`]).
+///   Mientras está desconectado, o si ya hay [`CLIENT_QUEUE_CAPACITY`] tramas
+///   sin escribir, `send()` devuelve [`CommunicatorError::Connection`] sin
+///   encolar la lectura.
+/// - **Servidor** ([`listen`](Self::listen)): acepta navegadores en la dirección
+///   indicada y difunde cada lectura a todos los clientes conectados. Sin
+///   clientes, las lecturas se descartan sin error.
+///
+/// Igual que en [`CoapCommunicator`](crate::network::coap::CoapCommunicator), la
+/// E/S asíncrona vive en un hilo propio con su runtime, y `send()` solo encola.
+///
+/// # Ejemplo
+/// ```
+/// use std::time::Duration;
+/// use futures_util::StreamExt;
+/// use iot_framework::network::websocket::WebSocketCommunicator;
+/// use iot_framework::{Communicator, SensorOutput, SensorReading};
+///
+/// let mut ws = WebSocketCommunicator::listen("127.0.0.1:0").unwrap();
+/// let url = format!("ws://{}", ws.local_addr().unwrap());
+///
+/// // Un panel se conecta como cliente.
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let (mut panel, _) = runtime.block_on(tokio_tungstenite::connect_async(url)).unwrap();
+/// while !ws.is_connected() {
+///     std::thread::sleep(Duration::from_millis(10));
+/// }
+///
+/// ws.send(SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+///
+/// let frame = runtime.block_on(panel.next()).unwrap().unwrap();
+/// let json: serde_json::Value = serde_json::from_str(frame.to_text().unwrap()).unwrap();
+/// assert_eq!(json["sensor_id"], "temp");
+/// assert_eq!(json["value"]["Float"], 21.5);
+/// ```
+pub struct WebSocketCommunicator {
+    mode: Mode,
+}
+
+impl WebSocketCommunicator {
+    /// Se conecta como cliente a `url` (`ws://host[:puerto]/ruta`) con la
+    /// espera de reconexión por defecto.
+    ///
+    /// La conexión se establece en segundo plano; el primer intento empieza
+    /// de inmediato.
+    pub fn connect(url: &str) -> Result<Self, CommunicatorError> {
+        Self::connect_with_backoff(url, DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF)
+    }
+
+    /// Igual que [`connect`](Self::connect), con esperas de reconexión propias.
+    pub fn connect_with_backoff(
+        url: &str,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Result<Self, CommunicatorError> {
+        url.into_client_request()
+            .map_err(|e| CommunicatorError::Connection(format!("url WebSocket no válida: {}", e)))?;
+        let url = url.to_string();
+        let (frames, rx) = mpsc::channel(CLIENT_QUEUE_CAPACITY);
+        let connected = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&connected);
+        let backoff = (initial_backoff, max_backoff.max(initial_backoff));
+        spawn_worker("websocket-client", move || run_client(url, rx, flag, backoff))?;
+        Ok(Self {
+            mode: Mode::Client { frames, connected },
+        })
+    }
+
+    /// Escucha conexiones de clientes en `addr` (`"0.0.0.0:8080"`; puerto `0`
+    /// para uno libre, ver [`local_addr`](Self::local_addr)).
+    pub fn listen(addr: &str) -> Result<Self, CommunicatorError> {
+        let listener = std::net::TcpListener::bind(addr)
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+            .map_err(|e| CommunicatorError::Connection(format!("{}: {}", addr, e)))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|e| CommunicatorError::Connection(e.to_string()))?;
+        let (frames, _) = broadcast::channel(BROADCAST_CAPACITY);
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let tx = frames.clone();
+        spawn_worker("websocket-server", move || serve(listener, tx, stop_rx))?;
+        info!(addr = %local_addr, "servidor WebSocket escuchando");
+        Ok(Self {
+            mode: Mode::Server {
+                frames,
+                local_addr,
+                _stop: stop_tx,
+            },
+        })
+    }
+
+    /// Dirección en la que escucha el servidor (`None` en modo cliente).
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        match &self.mode {
+            Mode::Server { local_addr, .. } => Some(*local_addr),
+            Mode::Client { .. } => None,
+        }
+    }
+
+    /// En modo cliente, si la conexión está establecida; en modo servidor, si
+    /// hay al menos un cliente conectado.
+    pub fn is_connected(&self) -> bool {
+        match &self.mode {
+            Mode::Client { connected, .. } => connected.load(Ordering::Relaxed),
+            Mode::Server { frames, .. } => frames.receiver_count() > 0,
+        }
+    }
+}
+
+/// Lanza un hilo con un runtime propio que ejecuta la tarea de `task`.
+fn spawn_worker<F, Fut>(name: &str, task: F) -> Result<(), CommunicatorError>
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()>,
+{
+    let (ready_tx, ready_rx) = std_mpsc::channel();
+    thread::Builder::new()
+        .name(name.to_string())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => {
+                    let _ = ready_tx.send(Ok(()));
+                    runtime
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(CommunicatorError::Execute(e.to_string())));
+                    return;
+                }
+            };
+            runtime.block_on(task());
+        })
+        .map_err(|e| CommunicatorError::Execute(e.to_string()))?;
+
+    ready_rx
+        .recv()
+        .map_err(|_| CommunicatorError::Execute("hilo WebSocket terminó".to_string()))?
+}
+
+/// Mantiene la conexión del cliente: escribe las tramas recibidas por `frames`
+/// y reconecta con espera exponencial cuando se cae. Termina cuando el
+/// comunicador se descarta.
+async fn run_client(
+    url: String,
+    mut frames: mpsc::Receiver<String>,
+    connected: Arc<AtomicBool>,
+    (initial_backoff, max_backoff): (Duration, Duration),
+) {
+    let mut backoff = initial_backoff;
+    loop {
+        match tokio_tungstenite::connect_async(url.as_str()).await {
+            Ok((mut ws, _)) => {
+                info!(url = %url, "WebSocket conectado");
+                connected.store(true, Ordering::Relaxed);
+                backoff = initial_backoff;
+                loop {
+                    tokio::select! {
+                        frame = frames.recv() => match frame {
+                            Some(text) => {
+                                if let Err(e) = ws.send(Message::text(text)).await {
+                                    warn!(url = %url, "Error escribiendo en el WebSocket: {}", e);
+                                    break;
+                                }
+                            }
+                            None => {
+                                let _ = ws.close(None).await;
+                                return;
+                            }
+                        },
+                        // Se leen los mensajes entrantes para responder a los
+                        // pings y detectar el cierre; su contenido se ignora.
+                        message = ws.next() => match message {
+                            Some(Ok(Message::Close(_))) | None => break,
+                            Some(Err(e)) => {
+                                warn!(url = %url, "Error leyendo del WebSocket: {}", e);
+                                break;
+                            }
+                            Some(Ok(_)) => {}
+                        },
+                    }
+                }
+                connected.store(false, Ordering::Relaxed);
+                warn!(url = %url, "WebSocket desconectado");
+            }
+            Err(e) => warn!(
+                url = %url,
+                retry_ms = backoff.as_millis() as u64,
+                "No se pudo conectar al WebSocket: {}", e
+            ),
+        }
+
+        // Durante la espera se descartan las tramas encoladas antes de la caída.
+        let pause = sleep(backoff);
+        tokio::pin!(pause);
+        loop {
+            tokio::select! {
+                _ = &mut pause => break,
+                frame = frames.recv() => {
+                    if frame.is_none() {
+                        return;
+                    }
+                }
+            }
+        }
+        backoff = (backoff * 2).min(max_backoff);
+    }
+}
+
+/// Acepta clientes hasta que se cierra `stop` y atiende a cada uno en su tarea.
+async fn serve(
+    listener: std::net::TcpListener,
+    frames: broadcast::Sender<String>,
+    mut stop: oneshot::Receiver<()>,
+) {
+    let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(e) => {
+            warn!("No se pudo iniciar el servidor WebSocket: {}", e);
+            return;
+        }
+    };
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, peer)) => {
+                    tokio::spawn(serve_client(stream, peer, frames.subscribe()));
+                }
+                Err(e) => warn!("Error aceptando cliente WebSocket: {}", e),
+            },
+            // Al descartar el comunicador termina el runtime y con él las conexiones.
+            _ = &mut stop => return,
+        }
+    }
+}
+
+/// Completa el *handshake* con un cliente y le reenvía cada trama difundida.
+async fn serve_client(stream: TcpStream, peer: SocketAddr, mut frames: broadcast::Receiver<String>) {
+    let mut ws = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            debug!(peer = %peer, "Handshake WebSocket fallido: {}", e);
+            return;
+        }
+    };
+    info!(peer = %peer, "cliente WebSocket conectado");
+    loop {
+        tokio::select! {
+            frame = frames.recv() => match frame {
+                Ok(text) => {
+                    if ws.send(Message::text(text)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(peer = %peer, skipped, "Cliente WebSocket lento, tramas descartadas");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            message = ws.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    info!(peer = %peer, "cliente WebSocket desconectado");
+}
+
+impl Communicator for WebSocketCommunicator {
+    /// Tipo de datos a enviar: una lectura completa del runtime.
+    type Command = SensorReading;
+    /// Tipo de respuesta: `()`; las tramas no se confirman.
+    type Response = ();
+
+    /// Serializa la lectura a JSON y la envía como trama de texto.
+    fn send(&mut self, command: Self::Command) -> Result<Self::Response, CommunicatorError> {
+        let text = serde_json::to_string(&command)
+            .map_err(|e| CommunicatorError::Serialization(e.to_string()))?;
+        match &self.mode {
+            Mode::Client { frames, connected } => {
+                if !connected.load(Ordering::Relaxed) {
+                    return Err(CommunicatorError::Connection("WebSocket desconectado".to_string()));
+                }
+                frames.try_send(text).map_err(|e| match e {
+                    TrySendError::Full(_) => CommunicatorError::Connection(format!(
+                        "cola WebSocket llena ({} tramas sin escribir)",
+                        CLIENT_QUEUE_CAPACITY
+                    )),
+                    TrySendError::Closed(_) => CommunicatorError::Execute("hilo WebSocket terminó".to_string()),
+                })
+            }
+            Mode::Server { frames, .. } => {
+                // Sin clientes conectados no hay a quién entregarla.
+                let _ = frames.send(text);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Hilo de trabajo compartido por los comunicadores que hablan con un cliente
+//! bloqueante o con un runtime de tokio propio (HTTP, InfluxDB, CoAP, AMQP).
+//!
+//! `send()` es síncrono y el runtime lo llama desde una tarea bloqueante; cada
+//! comunicador delega la E/S en un hilo propio que atiende las peticiones de
+//! una en una y devuelve el resultado por un canal de respuesta.
+
+use crate::core::traits::communicator::CommunicatorError;
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+use tokio::sync::mpsc;
+
+/// Canal por el que el hilo devuelve el resultado de una petición.
+type Reply = std_mpsc::Sender<Result<(), CommunicatorError>>;
+
+/// Petición para el hilo de trabajo y canal donde devolver su resultado.
+type Job<T> = (T, Reply);
+
+/// Hilo de trabajo que atiende peticiones `T` de una en una.
+///
+/// El hilo termina cuando se descarta el `Worker`.
+pub(crate) struct Worker<T> {
+    jobs: mpsc::UnboundedSender<Job<T>>,
+    /// Nombre del protocolo en los mensajes de error (`"HTTP"`, `"CoAP"`...).
+    label: &'static str,
+}
+
+impl<T: Send + 'static> Worker<T> {
+    /// Lanza el hilo `name`: prepara el estado con `init` (un cliente, una
+    /// conexión) y atiende cada petición con `handle`.
+    ///
+    /// # Errores
+    /// - El error de `init`, si no pudo preparar el estado.
+    /// - `CommunicatorError::Execute` si no se pudo lanzar el hilo.
+    #[cfg(any(feature = "http", feature = "influx", feature = "coap"))]
+    pub(crate) fn spawn<S, I, H>(name: &str, label: &'static str, init: I, mut handle: H) -> Result<Self, CommunicatorError>
+    where
+        I: FnOnce() -> Result<S, CommunicatorError> + Send + 'static,
+        H: FnMut(&mut S, T) -> Result<(), CommunicatorError> + Send + 'static,
+    {
+        Self::start(name, label, move |mut jobs, ready| {
+            let mut state = match init() {
+                Ok(state) => {
+                    let _ = ready.send(Ok(()));
+                    state
+                }
+                Err(e) => {
+                    let _ = ready.send(Err(e));
+                    return;
+                }
+            };
+            while let Some((job, reply)) = jobs.blocking_recv() {
+                let _ = reply.send(handle(&mut state, job));
+            }
+        })
+    }
+
+    /// Como [`spawn`](Self::spawn), pero con un runtime de tokio propio en el
+    /// hilo: entre petición y petición el runtime sigue atendiendo las tareas
+    /// del cliente (E/S, *heartbeats*).
+    #[cfg(feature = "amqp")]
+    pub(crate) fn spawn_async<S, I, IF, H, HF>(
+        name: &str,
+        label: &'static str,
+        init: I,
+        mut handle: H,
+    ) -> Result<Self, CommunicatorError>
+    where
+        I: FnOnce() -> IF + Send + 'static,
+        IF: std::future::Future<Output = Result<S, CommunicatorError>>,
+        H: FnMut(&S, T) -> HF + Send + 'static,
+        HF: std::future::Future<Output = Result<(), CommunicatorError>>,
+    {
+        Self::start(name, label, move |mut jobs, ready| {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = ready.send(Err(CommunicatorError::Execute(e.to_string())));
+                    return;
+                }
+            };
+            runtime.block_on(async move {
+                let state = match init().await {
+                    Ok(state) => {
+                        let _ = ready.send(Ok(()));
+                        state
+                    }
+                    Err(e) => {
+                        let _ = ready.send(Err(e));
+                        return;
+                    }
+                };
+                while let Some((job, reply)) = jobs.recv().await {
+                    let _ = reply.send(handle(&state, job).await);
+                }
+            });
+        })
+    }
+
+    /// Lanza el hilo con `run` y espera a que confirme que está listo.
+    fn start<F>(name: &str, label: &'static str, run: F) -> Result<Self, CommunicatorError>
+    where
+        F: FnOnce(mpsc::UnboundedReceiver<Job<T>>, Reply) + Send + 'static,
+    {
+        let (jobs, rx) = mpsc::unbounded_channel();
+        let (ready_tx, ready_rx) = std_mpsc::channel();
+        thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || run(rx, ready_tx))
+            .map_err(|e| CommunicatorError::Execute(e.to_string()))?;
+        let worker = Self { jobs, label };
+        ready_rx.recv().map_err(|_| worker.gone())??;
+        Ok(worker)
+    }
+
+    /// Encola `job` en el hilo y espera su resultado.
+    ///
+    /// # Errores
+    /// - El error con que respondió el hilo.
+    /// - `CommunicatorError::Execute` si el hilo ya terminó.
+    pub(crate) fn submit(&self, job: T) -> Result<(), CommunicatorError> {
+        let (reply_tx, reply_rx) = std_mpsc::channel();
+        self.jobs.send((job, reply_tx)).map_err(|_| self.gone())?;
+        reply_rx.recv().map_err(|_| self.gone())?
+    }
+
+    fn gone(&self) -> CommunicatorError {
+        CommunicatorError::Execute(format!("hilo {} terminó", self.label))
+    }
+}
+
+/// Envía la petición HTTP y traduce la respuesta (ver [`classify_status`]).
+///
+/// # Errores
+/// - `CommunicatorError::Timeout` si no hubo respuesta a tiempo.
+/// - `CommunicatorError::Send` si la petición no pudo enviarse.
+/// - Los de [`classify_status`].
+#[cfg(any(feature = "http", feature = "influx"))]
+pub(crate) fn execute(request: reqwest::blocking::RequestBuilder) -> Result<(), CommunicatorError> {
+    let response = request.send().map_err(|e| {
+        if e.is_timeout() {
+            CommunicatorError::Timeout
+        } else {
+            CommunicatorError::Send(e.to_string())
+        }
+    })?;
+    classify_status(response)
+}
+
+/// Traduce el estado de una respuesta HTTP: `2xx` es `Ok`, `4xx`
+/// `CommunicatorError::Client` y el resto `CommunicatorError::Server`, con el
+/// cuerpo de la respuesta como detalle.
+#[cfg(any(feature = "http", feature = "influx"))]
+pub(crate) fn classify_status(response: reqwest::blocking::Response) -> Result<(), CommunicatorError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let detail = response.text().unwrap_or_default();
+    if status.is_client_error() {
+        Err(CommunicatorError::Client(status.as_u16(), detail))
+    } else {
+        Err(CommunicatorError::Server(status.as_u16(), detail))
+    }
+}
@@ -0,0 +1,273 @@
+//! API HTTP de consulta y control del gateway.
+//!
+//! Complementa a los comunicadores, que empujan las lecturas, permitiendo
+//! consultar el estado actual bajo demanda y accionar actuadores desde una
+//! interfaz local:
+//!
+//! - `GET /readings`: objeto JSON con la última lectura de cada sensor,
+//!   indexado por id.
+//! - `GET /readings/{id}`: la última lectura del sensor `id`, o `404` si aún no
+//!   tiene ninguna.
+//! - `POST /actuators/{id}` con el cuerpo `{"command": {"Bool": true}}`: envía
+//!   la orden al actuador `id` y responde, cuando se ha ejecutado, con el
+//!   estado resultante: `{"actuator_id": "rele", "state": {"Bool": true},
+//!   "timestamp": 1700000000123}` (`state` es `null` si el actuador no lo
+//!   reporta). Devuelve
+//!   `404` si no hay un actuador con ese id y `400` si el cuerpo no es una
+//!   orden válida o el actuador la rechaza. Solo está disponible si la API se
+//!   creó con [`Api::with_control`].
+//!
+//! Las lecturas se sirven desde el [`ReadingCache`] del runtime, con el mismo
+//! formato JSON que `SensorReading` tiene con serde; los errores se responden
+//! como `{"error": "..."}`. El servidor se lanza como una tarea de `tokio`
+//! junto a [`RuntimeController::run`](crate::core::runtime::RuntimeController::run):
+//!
+//! ```no_run
+//! # async fn example(runtime: &mut iot_framework::core::runtime::RuntimeController,
+//! #                  shutdown: tokio::sync::watch::Receiver<bool>) {
+//! use iot_framework::platform::api_server::{self, Api};
+//!
+//! let addr = "0.0.0.0:8080".parse().unwrap();
+//! let api = Api::new(runtime.reading_cache()).with_control(runtime.handle());
+//! api_server::spawn(addr, api);
+//! runtime.run(shutdown).await;
+//! # }
+//! ```
+use crate::core::cache::ReadingCache;
+use crate::core::runtime::{CommandError, RuntimeHandle};
+use crate::core::traits::actuator::ActuatorState;
+use crate::core::{ActuatorCommand, SensorOutput};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::time::UNIX_EPOCH;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// Prefijo de la ruta de consulta de un sensor.
+const READING_PATH: &str = "/readings/";
+
+/// Prefijo de la ruta de órdenes a un actuador.
+const ACTUATOR_PATH: &str = "/actuators/";
+
+/// Tamaño máximo, en bytes, del cuerpo de una orden.
+const MAX_BODY_BYTES: usize = 16 * 1024;
+
+/// Estado que comparten las conexiones de la API.
+#[derive(Clone)]
+pub struct Api {
+    cache: ReadingCache,
+    control: Option<RuntimeHandle>,
+}
+
+impl Api {
+    /// API de solo lectura sobre `cache`.
+    pub fn new(cache: ReadingCache) -> Self {
+        Self { cache, control: None }
+    }
+
+    /// Habilita `POST /actuators/{id}`, que entrega las órdenes al runtime de
+    /// `handle` (ver [`RuntimeHandle::command`]).
+    pub fn with_control(mut self, handle: RuntimeHandle) -> Self {
+        self.control = Some(handle);
+        self
+    }
+}
+
+/// Cuerpo de `POST /actuators/{id}`.
+#[derive(serde::Deserialize)]
+struct CommandBody {
+    command: SensorOutput,
+}
+
+/// Enlaza `addr` y sirve la API en una tarea de `tokio`.
+///
+/// La tarea termina con `Err` solo si no se puede enlazar la dirección.
+pub fn spawn(addr: SocketAddr, api: Api) -> JoinHandle<io::Result<()>> {
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(addr).await?;
+        serve(listener, api).await
+    })
+}
+
+/// Atiende conexiones en `listener` indefinidamente.
+///
+/// Cada conexión se procesa en su propia tarea; los errores de una conexión se
+/// registran y no detienen el servidor.
+///
+/// # Ejemplo
+/// ```
+/// # #[tokio::main] async fn main() {
+/// use std::time::Duration;
+/// use iot_framework::core::runtime::RuntimeController;
+/// use iot_framework::core::traits::actuator::ActuatorState;
+/// use iot_framework::devices::actuators::dummy::DummyActuator;
+/// use iot_framework::platform::api_server::{self, Api};
+/// use iot_framework::{ConsoleCommunicator, SensorOutput, SensorReading};
+/// use tokio::io::{AsyncReadExt, AsyncWriteExt};
+/// use tokio::net::{TcpListener, TcpStream};
+///
+/// let mut runtime = RuntimeController::builder()
+///     .with_communicator(Box::new(ConsoleCommunicator::new()))
+///     .with_interval(Duration::from_secs(60))
+///     .add_actuator_with_id("rele", Box::new(DummyActuator::new()))
+///     .build()
+///     .unwrap();
+/// let cache = runtime.reading_cache();
+/// let api = Api::new(cache.clone()).with_control(runtime.handle());
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+/// let addr = listener.local_addr().unwrap();
+/// tokio::spawn(api_server::serve(listener, api));
+/// let (stop, shutdown) = tokio::sync::watch::channel(false);
+/// let running = tokio::spawn(async move {
+///     runtime.run(shutdown).await;
+///     runtime
+/// });
+///
+/// cache.insert(SensorReading::new("temp", SensorOutput::Float(21.5)));
+/// cache.insert(SensorReading::new("puerta", SensorOutput::Bool(true)));
+///
+/// async fn request(addr: std::net::SocketAddr, head: &str, body: &str) -> (String, serde_json::Value) {
+///     let mut stream = TcpStream::connect(addr).await.unwrap();
+///     let request = format!(
+///         "{} HTTP/1.1\r\nHost: gateway\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+///         head,
+///         body.len(),
+///         body
+///     );
+///     stream.write_all(request.as_bytes()).await.unwrap();
+///     let mut response = String::new();
+///     stream.read_to_string(&mut response).await.unwrap();
+///     let (head, body) = response.split_once("\r\n\r\n").unwrap();
+///     let status = head.lines().next().unwrap().to_string();
+///     (status, serde_json::from_str(body).unwrap())
+/// }
+///
+/// let (status, all) = request(addr, "GET /readings", "").await;
+/// assert_eq!(status, "HTTP/1.1 200 OK");
+/// assert_eq!(all["temp"]["value"], serde_json::json!({"Float": 21.5}));
+/// assert_eq!(all["puerta"]["value"], serde_json::json!({"Bool": true}));
+///
+/// let (_, temp) = request(addr, "GET /readings/temp", "").await;
+/// assert_eq!(temp["sensor_id"], "temp");
+/// assert!(temp["timestamp"].is_u64());
+///
+/// let (status, missing) = request(addr, "GET /readings/humedad", "").await;
+/// assert_eq!(status, "HTTP/1.1 404 Not Found");
+/// assert_eq!(missing["error"], "sensor sin lecturas: humedad");
+///
+/// // Órdenes a actuadores: ejecutada, actuador desconocido y cuerpo mal formado.
+/// let (status, ack) = request(addr, "POST /actuators/rele", r#"{"command": {"Bool": true}}"#).await;
+/// assert_eq!(status, "HTTP/1.1 200 OK");
+/// assert_eq!(ack["state"], serde_json::json!({"Bool": true}));
+/// let (status, unknown) = request(addr, "POST /actuators/bomba", r#"{"command": {"Bool": true}}"#).await;
+/// assert_eq!(status, "HTTP/1.1 404 Not Found");
+/// assert_eq!(unknown["error"], "actuador desconocido: bomba");
+/// let (status, _) = request(addr, "POST /actuators/rele", r#"{"command": true}"#).await;
+/// assert_eq!(status, "HTTP/1.1 400 Bad Request");
+///
+/// stop.send(true).unwrap();
+/// let runtime = running.await.unwrap();
+/// let states = runtime.actuator_states();
+/// assert_eq!(states, vec![(Some("rele".to_string()), ActuatorState::Known(SensorOutput::Bool(true)))]);
+/// # }
+/// ```
+pub async fn serve(listener: TcpListener, api: Api) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let api = api.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |req| handle(req, api.clone()));
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                tracing::warn!("Error en conexión de la API: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle(req: Request<Incoming>, api: Api) -> Result<Response<Full<Bytes>>, Infallible> {
+    let path = req.uri().path().to_string();
+    let response = match *req.method() {
+        Method::GET if path == "/readings" => {
+            // Ordenadas por id para que la respuesta sea estable.
+            let readings: BTreeMap<_, _> = api.cache.snapshot().into_iter().collect();
+            json(StatusCode::OK, &readings)
+        }
+        Method::GET => match path.strip_prefix(READING_PATH).filter(|id| !id.is_empty()) {
+            Some(id) => match api.cache.get(id) {
+                Some(reading) => json(StatusCode::OK, &reading),
+                None => json_error(StatusCode::NOT_FOUND, &format!("sensor sin lecturas: {}", id)),
+            },
+            None => json_error(StatusCode::NOT_FOUND, "ruta no encontrada"),
+        },
+        Method::POST => match path.strip_prefix(ACTUATOR_PATH).filter(|id| !id.is_empty()) {
+            Some(id) => match &api.control {
+                Some(control) => command(control, id, req.into_body()).await,
+                None => json_error(StatusCode::FORBIDDEN, "control de actuadores deshabilitado"),
+            },
+            None => json_error(StatusCode::NOT_FOUND, "ruta no encontrada"),
+        },
+        _ => json_error(StatusCode::METHOD_NOT_ALLOWED, "método no permitido"),
+    };
+    Ok(response)
+}
+
+/// Lee la orden de `body` y la entrega al actuador `id`.
+async fn command(control: &RuntimeHandle, id: &str, body: Incoming) -> Response<Full<Bytes>> {
+    let bytes = match Limited::new(body, MAX_BODY_BYTES).collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => return json_error(StatusCode::BAD_REQUEST, &format!("cuerpo no válido: {}", e)),
+    };
+    let body: CommandBody = match serde_json::from_slice(&bytes) {
+        Ok(body) => body,
+        Err(e) => return json_error(StatusCode::BAD_REQUEST, &format!("orden no válida: {}", e)),
+    };
+    match control.command(ActuatorCommand::new(id, body.command)).await {
+        Ok(result) => {
+            let state = match result.state {
+                ActuatorState::Known(value) => Some(value),
+                ActuatorState::Unknown => None,
+            };
+            let timestamp = result.timestamp.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as u64);
+            let body = serde_json::json!({ "actuator_id": id, "state": state, "timestamp": timestamp });
+            json(StatusCode::OK, &body)
+        }
+        Err(e @ CommandError::UnknownActuator(_)) => json_error(StatusCode::NOT_FOUND, &e.to_string()),
+        Err(e @ CommandError::Execute(_)) => json_error(StatusCode::BAD_REQUEST, &e.to_string()),
+        Err(e @ CommandError::Closed(_)) => json_error(StatusCode::SERVICE_UNAVAILABLE, &e.to_string()),
+    }
+}
+
+/// Respuesta JSON con `body` y el estado `status`.
+fn json<T: serde::Serialize>(status: StatusCode, body: &T) -> Response<Full<Bytes>> {
+    match serde_json::to_vec(body) {
+        Ok(bytes) => Response::builder()
+            .status(status)
+            .header("Content-Type", "application/json")
+            .body(Full::new(Bytes::from(bytes)))
+            .expect("respuesta JSON válida"),
+        Err(e) => {
+            tracing::warn!("No se pudo serializar la respuesta de la API: {}", e);
+            Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Full::new(Bytes::new()))
+                .expect("respuesta estática válida")
+        }
+    }
+}
+
+/// Respuesta `{"error": message}` con el estado `status`.
+fn json_error(status: StatusCode, message: &str) -> Response<Full<Bytes>> {
+    json(status, &serde_json::json!({ "error": message }))
+}
@@ -0,0 +1,176 @@
+//! Endpoint HTTP `/metrics` en formato de texto de Prometheus.
+//!
+//! Expone los contadores de [`RuntimeMetrics`] y el último valor numérico de
+//! cada sensor. El servidor se lanza como una tarea de `tokio` junto a
+//! [`RuntimeController::run`](crate::core::runtime::RuntimeController::run):
+//!
+//! ```no_run
+//! # async fn example(runtime: &mut iot_framework::core::runtime::RuntimeController,
+//! #                  shutdown: tokio::sync::watch::Receiver<bool>) {
+//! use iot_framework::platform::metrics_server;
+//!
+//! let addr = "0.0.0.0:9100".parse().unwrap();
+//! metrics_server::spawn(addr, runtime.metrics_handle());
+//! runtime.run(shutdown).await;
+//! # }
+//! ```
+use crate::core::metrics::RuntimeMetrics;
+use crate::core::SensorOutput;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// Tipo de contenido del formato de exposición de texto de Prometheus.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Enlaza `addr` y sirve `/metrics` en una tarea de `tokio`.
+///
+/// La tarea termina con `Err` solo si no se puede enlazar la dirección.
+pub fn spawn(addr: SocketAddr, metrics: Arc<RuntimeMetrics>) -> JoinHandle<io::Result<()>> {
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(addr).await?;
+        serve(listener, metrics).await
+    })
+}
+
+/// Atiende conexiones en `listener` indefinidamente.
+///
+/// Cada conexión se procesa en su propia tarea; los errores de una conexión se
+/// registran y no detienen el servidor.
+pub async fn serve(listener: TcpListener, metrics: Arc<RuntimeMetrics>) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let service = service_fn(move |req| handle(req, Arc::clone(&metrics)));
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                tracing::warn!("Error en conexión de métricas: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle<B>(
+    req: Request<B>,
+    metrics: Arc<RuntimeMetrics>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let response = match (req.method(), req.uri().path()) {
+        (&Method::GET, "/metrics") => Response::builder()
+            .header("Content-Type", CONTENT_TYPE)
+            .body(Full::new(Bytes::from(render(&metrics)))),
+        _ => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Full::new(Bytes::from_static(b"not found\n"))),
+    };
+    Ok(response.expect("respuesta estática válida"))
+}
+
+/// Genera el texto de exposición de Prometheus para `metrics`.
+///
+/// - `iot_reads_total{result="ok"|"error"}` y `iot_sends_total{...}`: contadores.
+/// - `iot_last_cycle_seconds`: duración del último ciclo de lectura.
+/// - `iot_sensor_value{sensor="..."}`: último valor de cada sensor numérico o
+///   booleano (1/0); los `Map` añaden la etiqueta `field`.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::core::metrics::RuntimeMetrics;
+/// use iot_framework::platform::metrics_server::render;
+/// use iot_framework::{SensorOutput, SensorReading};
+///
+/// let metrics = RuntimeMetrics::default();
+/// metrics.record_read(true);
+/// metrics.record_read(false);
+/// metrics.record_value(&SensorReading::new("temp", SensorOutput::Float(21.5)));
+///
+/// let text = render(&metrics);
+/// assert!(text.contains("iot_reads_total{result=\"error\"} 1"));
+/// assert!(text.contains("iot_sensor_value{sensor=\"temp\"} 21.5"));
+/// ```
+pub fn render(metrics: &RuntimeMetrics) -> String {
+    let snapshot = metrics.snapshot();
+    let mut out = String::new();
+    // `write!` sobre un String no puede fallar.
+    let _ = writeln!(out, "# HELP iot_reads_total Lecturas de sensores.");
+    let _ = writeln!(out, "# TYPE iot_reads_total counter");
+    let _ = writeln!(out, "iot_reads_total{{result=\"ok\"}} {}", snapshot.reads_ok);
+    let _ = writeln!(out, "iot_reads_total{{result=\"error\"}} {}", snapshot.reads_err);
+    let _ = writeln!(out, "# HELP iot_read_timeouts_total Lecturas abandonadas por superar el tiempo máximo.");
+    let _ = writeln!(out, "# TYPE iot_read_timeouts_total counter");
+    let _ = writeln!(out, "iot_read_timeouts_total {}", snapshot.read_timeouts);
+    let _ = writeln!(out, "# HELP iot_out_of_range_total Lecturas descartadas por salir del rango del sensor.");
+    let _ = writeln!(out, "# TYPE iot_out_of_range_total counter");
+    let _ = writeln!(out, "iot_out_of_range_total {}", snapshot.out_of_range);
+    let _ = writeln!(out, "# HELP iot_batches_dropped_total Lotes de lecturas descartados por un comunicador saturado.");
+    let _ = writeln!(out, "# TYPE iot_batches_dropped_total counter");
+    let _ = writeln!(out, "iot_batches_dropped_total {}", snapshot.batches_dropped);
+    let _ = writeln!(out, "# HELP iot_sends_total Envíos del comunicador.");
+    let _ = writeln!(out, "# TYPE iot_sends_total counter");
+    let _ = writeln!(out, "iot_sends_total{{result=\"ok\"}} {}", snapshot.sends_ok);
+    let _ = writeln!(out, "iot_sends_total{{result=\"error\"}} {}", snapshot.sends_err);
+    let _ = writeln!(out, "# HELP iot_last_cycle_seconds Duración del último ciclo de lectura.");
+    let _ = writeln!(out, "# TYPE iot_last_cycle_seconds gauge");
+    let _ = writeln!(
+        out,
+        "iot_last_cycle_seconds {}",
+        snapshot.last_cycle_duration.as_secs_f64()
+    );
+    let _ = writeln!(out, "# HELP iot_sensor_value Último valor leído de cada sensor.");
+    let _ = writeln!(out, "# TYPE iot_sensor_value gauge");
+    for (id, value) in metrics.last_values() {
+        let id = escape_label(&id);
+        match value {
+            SensorOutput::Bool(b) => {
+                let _ = writeln!(out, "iot_sensor_value{{sensor=\"{}\"}} {}", id, b as u8);
+            }
+            SensorOutput::Int(v) => {
+                let _ = writeln!(out, "iot_sensor_value{{sensor=\"{}\"}} {}", id, v);
+            }
+            SensorOutput::Float(v) => {
+                let _ = writeln!(out, "iot_sensor_value{{sensor=\"{}\"}} {}", id, v);
+            }
+            SensorOutput::Map(fields) => {
+                for (field, v) in fields {
+                    let _ = writeln!(
+                        out,
+                        "iot_sensor_value{{sensor=\"{}\",field=\"{}\"}} {}",
+                        id,
+                        escape_label(&field),
+                        v
+                    );
+                }
+            }
+            // Instantes como segundos desde el UNIX epoch, al estilo de `*_timestamp_seconds`.
+            SensorOutput::Timestamp(at) => {
+                let secs = at.duration_since(UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64());
+                let _ = writeln!(out, "iot_sensor_value{{sensor=\"{}\"}} {}", id, secs);
+            }
+            SensorOutput::Text(_) | SensorOutput::Bytes(_) => {}
+            #[cfg(feature = "serde")]
+            SensorOutput::Json(_) => {}
+        }
+    }
+    out
+}
+
+/// Escapa `\`, `"` y saltos de línea en el valor de una etiqueta.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "metrics")]
+pub mod metrics_server;
+#[cfg(feature = "http-api")]
+pub mod api_server;
@@ -0,0 +1,156 @@
+use crate::core::traits::storage::StorageError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Corrección lineal de un sensor: `valor * scale + offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl Calibration {
+    /// Calibración con el factor `scale` y el desplazamiento `offset`.
+    pub fn new(scale: f64, offset: f64) -> Self {
+        Self { scale, offset }
+    }
+
+    /// Aplica la corrección a `value`.
+    pub fn apply(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+}
+
+impl Default for Calibration {
+    /// La identidad: `scale = 1`, `offset = 0`.
+    fn default() -> Self {
+        Self::new(1.0, 0.0)
+    }
+}
+
+/// Calibraciones por sensor guardadas en un archivo JSON.
+///
+/// El archivo es un objeto `{"<id>": {"scale": 1.0, "offset": -0.4}, ...}`. Se
+/// lee al abrir el almacén y se reescribe completo con cada cambio: primero en
+/// un archivo temporal junto al original y luego se renombra, de modo que un
+/// corte de corriente nunca deja el archivo a medio escribir.
+///
+/// Clonar un `CalibrationStore` comparte el mismo contenido, como
+/// [`ReadingCache`](crate::core::cache::ReadingCache): los
+/// [`CalibratedSensor`](crate::core::decorators::CalibratedSensor) registrados
+/// en el runtime aplican una calibración nueva desde su siguiente lectura.
+///
+/// # Ejemplo
+/// ```
+/// use iot_framework::storage::calibration::{Calibration, CalibrationStore};
+///
+/// let dir = std::env::temp_dir().join(format!("calibration-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("calibration.json");
+///
+/// // Sin archivo el almacén empieza vacío.
+/// let store = CalibrationStore::open(&path).unwrap();
+/// assert_eq!(store.get("temp"), None);
+/// store.set_calibration("temp", 1.02, -0.4).unwrap();
+///
+/// // Se guardó al instante: otro almacén (tras reiniciar) la recupera.
+/// let reloaded = CalibrationStore::open(&path).unwrap();
+/// assert_eq!(reloaded.get("temp"), Some(Calibration::new(1.02, -0.4)));
+/// assert!(reloaded.remove("temp").unwrap());
+/// assert!(CalibrationStore::open(&path).unwrap().is_empty());
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CalibrationStore {
+    path: PathBuf,
+    entries: Arc<RwLock<BTreeMap<String, Calibration>>>,
+}
+
+impl CalibrationStore {
+    /// Abre el almacén en `path`, leyendo las calibraciones guardadas. Si el
+    /// archivo no existe empieza vacío; se crea al guardar la primera.
+    ///
+    /// # Errores
+    /// - `StorageError::LoadError` si el archivo no puede leerse o no es un JSON válido.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| StorageError::LoadError(format!("{}: {}", path.display(), e)))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(StorageError::LoadError(format!("{}: {}", path.display(), e))),
+        };
+        Ok(Self {
+            path,
+            entries: Arc::new(RwLock::new(entries)),
+        })
+    }
+
+    /// Ruta del archivo de calibraciones.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Calibración del sensor `id`, si tiene una.
+    pub fn get(&self, id: &str) -> Option<Calibration> {
+        self.entries.read().ok()?.get(id).copied()
+    }
+
+    /// Copia de todas las calibraciones, ordenadas por id.
+    pub fn snapshot(&self) -> BTreeMap<String, Calibration> {
+        self.entries.read().map(|entries| entries.clone()).unwrap_or_default()
+    }
+
+    /// Indica si no hay calibraciones guardadas.
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().map(|entries| entries.is_empty()).unwrap_or(true)
+    }
+
+    /// Guarda la calibración del sensor `id` y la persiste de inmediato.
+    ///
+    /// # Errores
+    /// - `StorageError::SaveError` si el archivo no puede escribirse; en ese
+    ///   caso la calibración anterior sigue vigente.
+    pub fn set_calibration(&self, id: &str, scale: f64, offset: f64) -> Result<(), StorageError> {
+        self.update(|entries| {
+            entries.insert(id.to_string(), Calibration::new(scale, offset));
+        })
+    }
+
+    /// Elimina la calibración del sensor `id` y persiste el cambio. Devuelve si
+    /// existía.
+    ///
+    /// # Errores
+    /// - `StorageError::SaveError` si el archivo no puede escribirse.
+    pub fn remove(&self, id: &str) -> Result<bool, StorageError> {
+        let mut existed = false;
+        self.update(|entries| existed = entries.remove(id).is_some())?;
+        Ok(existed)
+    }
+
+    /// Aplica `change` a una copia, la escribe en disco y, si se guardó, la
+    /// hace vigente.
+    fn update<F>(&self, change: F) -> Result<(), StorageError>
+    where
+        F: FnOnce(&mut BTreeMap<String, Calibration>),
+    {
+        let mut entries = self
+            .entries
+            .write()
+            .map_err(|_| StorageError::SaveError("calibraciones no disponibles".to_string()))?;
+        let mut updated = entries.clone();
+        change(&mut updated);
+        let json = serde_json::to_vec_pretty(&updated).map_err(|e| StorageError::SaveError(e.to_string()))?;
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, json)
+            .and_then(|()| fs::rename(&tmp, &self.path))
+            .map_err(|e| StorageError::SaveError(format!("{}: {}", self.path.display(), e)))?;
+        *entries = updated;
+        Ok(())
+    }
+}
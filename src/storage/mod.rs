@@ -0,0 +1,7 @@
+//! Implementaciones de [`Storage`](crate::core::traits::storage::Storage) para
+//! persistir lecturas en el gateway.
+
+#[cfg(feature = "serde")]
+pub mod calibration;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
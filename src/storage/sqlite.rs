@@ -0,0 +1,83 @@
+use crate::core::traits::storage::{Storage, StorageError};
+use crate::core::SensorReading;
+use rusqlite::{params, Connection};
+
+/// `SqliteStore` persiste cada lectura como una fila de una base SQLite.
+///
+/// Al abrirse crea (si no existe) la tabla:
+///
+/// ```sql
+/// readings(id TEXT, timestamp INTEGER, value TEXT, unit TEXT)
+/// ```
+///
+/// donde `id` es el identificador del sensor, `timestamp` son milisegundos
+/// desde el UNIX epoch y `value` es el `SensorOutput` serializado a JSON.
+pub struct SqliteStore {
+    conn: Connection,
+}
+
+impl SqliteStore {
+    /// Abre (o crea) la base de datos en `path`.
+    pub fn open(path: &str) -> Result<Self, StorageError> {
+        let conn = Connection::open(path).map_err(|e| StorageError::LoadError(e.to_string()))?;
+        Self::from_connection(conn)
+    }
+
+    /// Abre una base de datos en memoria, útil para pruebas.
+    ///
+    /// # Ejemplo
+    /// ```
+    /// use iot_framework::core::traits::storage::Storage;
+    /// use iot_framework::storage::sqlite::SqliteStore;
+    /// use iot_framework::{SensorOutput, SensorReading};
+    ///
+    /// let mut store = SqliteStore::open_in_memory().unwrap();
+    /// store.persist(&SensorReading::new("temp", SensorOutput::Float(21.5))).unwrap();
+    /// store.persist(&SensorReading::new("lluvia", SensorOutput::Text("SECO".into()))).unwrap();
+    /// assert_eq!(store.count().unwrap(), 2);
+    /// ```
+    pub fn open_in_memory() -> Result<Self, StorageError> {
+        let conn = Connection::open_in_memory().map_err(|e| StorageError::LoadError(e.to_string()))?;
+        Self::from_connection(conn)
+    }
+
+    /// Número de lecturas almacenadas.
+    pub fn count(&self) -> Result<u64, StorageError> {
+        self.conn
+            .query_row("SELECT COUNT(*) FROM readings", [], |row| row.get(0))
+            .map_err(|e| StorageError::LoadError(e.to_string()))
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self, StorageError> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS readings (
+                id        TEXT NOT NULL,
+                timestamp INTEGER NOT NULL,
+                value     TEXT NOT NULL,
+                unit      TEXT
+            )",
+            [],
+        )
+        .map_err(|e| StorageError::LoadError(e.to_string()))?;
+        Ok(Self { conn })
+    }
+}
+
+impl Storage for SqliteStore {
+    fn persist(&mut self, reading: &SensorReading) -> Result<(), StorageError> {
+        let value = serde_json::to_string(&reading.value)
+            .map_err(|e| StorageError::SaveError(e.to_string()))?;
+        self.conn
+            .execute(
+                "INSERT INTO readings (id, timestamp, value, unit) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    reading.sensor_id,
+                    reading.timestamp_millis() as i64,
+                    value,
+                    reading.unit.map(|u| u.symbol()),
+                ],
+            )
+            .map_err(|e| StorageError::SaveError(e.to_string()))?;
+        Ok(())
+    }
+}
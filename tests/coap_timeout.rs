@@ -0,0 +1,28 @@
+//! `CoapCommunicator` frente a un servidor que nunca confirma las peticiones.
+#![cfg(feature = "coap")]
+
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use iot_framework::core::traits::communicator::CommunicatorError;
+use iot_framework::network::coap::CoapCommunicator;
+use iot_framework::{Communicator, SensorOutput, SensorReading};
+
+#[test]
+fn unanswered_request_times_out() {
+    // El socket está abierto, así que los datagramas llegan, pero nadie responde.
+    let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let port = silent.local_addr().unwrap().port();
+
+    let mut coap = CoapCommunicator::new(&format!("coap://127.0.0.1:{port}/lecturas"))
+        .unwrap()
+        .with_ack_timeout(Duration::from_millis(10), 1);
+    let result = coap.send(SensorReading::new("temp", SensorOutput::Float(21.5)));
+    assert!(matches!(result, Err(CommunicatorError::Timeout)), "{:?}", result);
+
+    // El hilo de trabajo sigue vivo tras el timeout.
+    let result = coap.send(SensorReading::new("temp", SensorOutput::Float(21.6)));
+    assert!(matches!(result, Err(CommunicatorError::Timeout)), "{:?}", result);
+    let mut buf = [0; 512];
+    assert!(silent.recv(&mut buf).unwrap() > 0);
+}
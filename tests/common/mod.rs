@@ -0,0 +1,25 @@
+//! Utilidades compartidas por las pruebas de integración.
+
+use std::io::Read;
+use std::net::TcpStream;
+
+/// Lee un paquete MQTT completo de `stream`: devuelve el primer byte de la
+/// cabecera fija y el resto del paquete, o `None` si el cliente cerró la conexión.
+pub fn read_mqtt_packet(stream: &mut TcpStream) -> Option<(u8, Vec<u8>)> {
+    let mut header = [0u8; 1];
+    stream.read_exact(&mut header).ok()?;
+    // Longitud restante: hasta 4 bytes de 7 bits, el bit alto indica si sigue otro.
+    let (mut len, mut shift) = (0usize, 0);
+    loop {
+        let mut byte = [0u8; 1];
+        stream.read_exact(&mut byte).ok()?;
+        len |= ((byte[0] & 0x7f) as usize) << shift;
+        shift += 7;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body).ok()?;
+    Some((header[0], body))
+}
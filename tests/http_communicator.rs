@@ -0,0 +1,100 @@
+//! `HttpCommunicator` contra un servidor HTTP simulado con `wiremock`.
+#![cfg(feature = "http")]
+
+use wiremock::matchers::{body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use iot_framework::core::traits::communicator::CommunicatorError;
+use iot_framework::network::http::HttpCommunicator;
+use iot_framework::{Communicator, SensorOutput, SensorReading};
+
+/// Ejecuta `f` fuera del runtime: `send()` bloquea hasta tener la respuesta
+/// y el servidor simulado necesita el runtime libre para darla.
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    tokio::task::spawn_blocking(f).await.unwrap()
+}
+
+/// URL del endpoint de lecturas en `server`.
+fn readings_url(server: &MockServer) -> String {
+    format!("{}/readings", server.uri())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn posts_reading_as_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/readings"))
+        .and(header("content-type", "application/json"))
+        .and(header("authorization", "Bearer abc"))
+        .and(body_string(r#"{"sensor_id":"temp","timestamp":1000,"value":{"Float":21.5}}"#))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let url = readings_url(&server);
+    blocking(move || {
+        let mut http = HttpCommunicator::new(&url, Some("Bearer abc")).unwrap();
+        let mut reading = SensorReading::new("temp", SensorOutput::Float(21.5));
+        reading.timestamp = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1000);
+        http.send(reading).unwrap();
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn posts_batch_as_json_array() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/readings"))
+        .and(header("content-type", "application/json"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let batch = vec![
+        SensorReading::new("a", SensorOutput::Int(1)),
+        SensorReading::new("b", SensorOutput::Bool(true)),
+    ];
+    let (url, sent) = (readings_url(&server), batch.clone());
+    blocking(move || HttpCommunicator::new(&url, None).unwrap().send_batch(sent).unwrap()).await;
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests[0].headers.contains_key("authorization"));
+    let sent: Vec<SensorReading> = requests[0].body_json().unwrap();
+    let summary = |readings: &[SensorReading]| -> Vec<(String, SensorOutput)> {
+        readings.iter().map(|r| (r.sensor_id.clone(), r.value.clone())).collect()
+    };
+    assert_eq!(summary(&sent), summary(&batch));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn maps_error_statuses() {
+    let server = MockServer::start().await;
+    for status in [422, 503] {
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(status).set_body_string(format!("estado {}", status)))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let url = readings_url(&server);
+    let results = blocking(move || {
+        let mut http = HttpCommunicator::new(&url, None).unwrap();
+        let reading = || SensorReading::new("temp", SensorOutput::Float(21.5));
+        [http.send(reading()), http.send(reading())]
+    })
+    .await;
+
+    match &results[0] {
+        Err(CommunicatorError::Client(422, detail)) => assert_eq!(detail, "estado 422"),
+        other => panic!("se esperaba un error 4xx: {:?}", other),
+    }
+    match &results[1] {
+        Err(CommunicatorError::Server(503, detail)) => assert_eq!(detail, "estado 503"),
+        other => panic!("se esperaba un error 5xx: {:?}", other),
+    }
+}
@@ -0,0 +1,63 @@
+//! `MqttCommunicator` frente a un broker que envía más órdenes de las que caben en la cola.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::io::Write;
+use std::net::TcpListener;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use iot_framework::core::ActuatorCommand;
+use iot_framework::network::mqtt::INCOMING_CAPACITY;
+use iot_framework::{Communicator, MqttCommunicator, SensorOutput};
+
+use common::read_mqtt_packet;
+
+/// Paquete PUBLISH (QoS 0) en `topic` con `payload`.
+fn publish_packet(topic: &str, payload: &[u8]) -> Vec<u8> {
+    let len = 2 + topic.len() + payload.len();
+    assert!(len < 128, "la longitud restante debe caber en un byte");
+    let mut packet = vec![0x30, len as u8];
+    packet.extend_from_slice(&(topic.len() as u16).to_be_bytes());
+    packet.extend_from_slice(topic.as_bytes());
+    packet.extend_from_slice(payload);
+    packet
+}
+
+/// Broker mínimo: confirma CONNECT, envía `count` órdenes seguidas y cierra la conexión.
+fn flooding_broker(listener: TcpListener, count: usize) {
+    let (mut stream, _) = listener.accept().unwrap();
+    let Some((header, _)) = read_mqtt_packet(&mut stream) else { return };
+    assert_eq!(header >> 4, 1, "se esperaba CONNECT");
+    stream.write_all(&[0x20, 0x02, 0x00, 0x00]).unwrap();
+    for i in 0..count {
+        let command = ActuatorCommand::new("rele", SensorOutput::Int(i as i64));
+        let payload = serde_json::to_vec(&command).unwrap();
+        stream.write_all(&publish_packet("ordenes", &payload)).unwrap();
+    }
+}
+
+#[test]
+fn drops_incoming_messages_beyond_capacity() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let broker = thread::spawn(move || flooding_broker(listener, INCOMING_CAPACITY + 5));
+
+    let mut mqtt = MqttCommunicator::new("127.0.0.1", port, "lecturas").unwrap();
+    broker.join().unwrap();
+    // El event loop procesa los mensajes en orden antes de ver que el broker cerró.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while mqtt.is_connected() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!mqtt.is_connected());
+
+    // Se conservan las primeras órdenes; las que no cabían se descartaron.
+    let mut received = Vec::new();
+    while let Some(command) = mqtt.receive().unwrap() {
+        received.push(command.value);
+    }
+    let expected: Vec<_> = (0..INCOMING_CAPACITY as i64).map(SensorOutput::Int).collect();
+    assert_eq!(received, expected);
+}
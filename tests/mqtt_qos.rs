@@ -0,0 +1,70 @@
+//! QoS y *retain* de `MqttCommunicator` frente a un broker local.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use iot_framework::core::alert::{Alert, Comparison, Severity};
+use iot_framework::{Communicator, MqttCommunicator, SensorOutput, SensorReading};
+use rumqttc::QoS;
+
+use common::read_mqtt_packet;
+
+/// Broker mínimo: confirma CONNECT y el flujo de QoS 2 (PUBREC / PUBCOMP) y
+/// reenvía la cabecera fija de cada PUBLISH.
+fn broker(listener: TcpListener, headers: mpsc::Sender<u8>) {
+    let (mut stream, _) = listener.accept().unwrap();
+    while let Some((header, body)) = read_mqtt_packet(&mut stream) {
+        match header >> 4 {
+            1 => stream.write_all(&[0x20, 0x02, 0x00, 0x00]).unwrap(),
+            3 => {
+                let topic_len = u16::from_be_bytes([body[0], body[1]]) as usize;
+                let id = &body[2 + topic_len..4 + topic_len];
+                stream.write_all(&[0x50, 0x02, id[0], id[1]]).unwrap();
+                if headers.send(header).is_err() {
+                    return;
+                }
+            }
+            6 => stream.write_all(&[0x70, 0x02, body[0], body[1]]).unwrap(),
+            12 => stream.write_all(&[0xd0, 0x00]).unwrap(),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn publishes_readings_retained_and_alerts_not_retained() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (tx, headers) = mpsc::channel();
+    thread::spawn(move || broker(listener, tx));
+
+    let mut mqtt = MqttCommunicator::new("127.0.0.1", port, "estado")
+        .unwrap()
+        .with_qos(QoS::ExactlyOnce)
+        .with_retain(true);
+    assert_eq!((mqtt.qos(), mqtt.retain()), (QoS::ExactlyOnce, true));
+
+    mqtt.send(SensorReading::new("valvula", SensorOutput::Bool(true))).unwrap();
+    mqtt.send_alert(Alert {
+        sensor_id: "temp".into(),
+        field: None,
+        severity: Severity::Critical,
+        comparison: Comparison::Above,
+        threshold: 40.0,
+        value: 41.0,
+        active: true,
+        timestamp: SystemTime::now(),
+    })
+    .unwrap();
+
+    // PUBLISH (0x30) | QoS 2 (0b100) | retain (0b1).
+    let header = || headers.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(header(), 0x35);
+    assert_eq!(header(), 0x34);
+}
@@ -0,0 +1,71 @@
+//! `MqttCommunicator` frente a un broker local que se reinicia.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use iot_framework::{Communicator, MqttCommunicator, SensorOutput, SensorReading};
+
+use common::read_mqtt_packet;
+
+/// Broker mínimo: acepta una conexión, confirma CONNECT, PUBLISH (QoS 1) y
+/// PINGREQ, reenvía cada payload y corta la conexión tras `limit` publicaciones.
+fn broker(listener: TcpListener, limit: usize, payloads: mpsc::Sender<Vec<u8>>) {
+    let (mut stream, _) = listener.accept().unwrap();
+    let mut received = 0;
+    while received < limit {
+        let Some((header, body)) = read_mqtt_packet(&mut stream) else { return };
+        match header >> 4 {
+            1 => stream.write_all(&[0x20, 0x02, 0x00, 0x00]).unwrap(),
+            3 => {
+                let topic_len = u16::from_be_bytes([body[0], body[1]]) as usize;
+                let id = &body[2 + topic_len..4 + topic_len];
+                stream.write_all(&[0x40, 0x02, id[0], id[1]]).unwrap();
+                payloads.send(body[4 + topic_len..].to_vec()).unwrap();
+                received += 1;
+            }
+            12 => stream.write_all(&[0xd0, 0x00]).unwrap(),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn buffers_while_offline_and_publishes_in_order_on_reconnect() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, payloads) = mpsc::channel();
+    let first = {
+        let tx = tx.clone();
+        thread::spawn(move || broker(listener, 1, tx))
+    };
+
+    let mut mqtt = MqttCommunicator::new("127.0.0.1", addr.port(), "lecturas").unwrap();
+    mqtt.send(SensorReading::new("t", SensorOutput::Int(1))).unwrap();
+    assert!(payloads.recv_timeout(Duration::from_secs(5)).is_ok());
+
+    // El broker se reinicia: lo que se publica mientras tanto queda en el buffer.
+    first.join().unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while mqtt.is_connected() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!mqtt.is_connected());
+    mqtt.send(SensorReading::new("t", SensorOutput::Int(2))).unwrap();
+    mqtt.send(SensorReading::new("t", SensorOutput::Int(3))).unwrap();
+    assert_eq!(mqtt.buffered(), 2);
+
+    let listener = TcpListener::bind(addr).unwrap();
+    thread::spawn(move || broker(listener, 2, tx));
+    for expected in [2, 3] {
+        let payload = payloads.recv_timeout(Duration::from_secs(10)).unwrap();
+        let reading: SensorReading = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(reading.value, SensorOutput::Int(expected));
+    }
+    assert_eq!(mqtt.buffered(), 0);
+}
@@ -0,0 +1,76 @@
+//! Un sensor colgado no debe bloquear el runtime al reiniciar la sesión.
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use iot_framework::core::runtime::RuntimeController;
+use iot_framework::core::traits::sensor::{Sensor, SensorError};
+use iot_framework::devices::sensors::mock::MockSensor;
+use iot_framework::{ConsoleCommunicator, SensorOutput, Unit};
+
+/// Simula un bus bloqueado: la lectura no vuelve hasta que se descarta el emisor.
+struct Stuck(mpsc::Receiver<()>);
+
+impl Sensor for Stuck {
+    type Output = SensorOutput;
+
+    fn read(&mut self) -> Result<SensorOutput, SensorError> {
+        let _ = self.0.recv();
+        Ok(SensorOutput::Int(0))
+    }
+
+    fn unit(&self) -> Option<Unit> {
+        Some(Unit::Celsius)
+    }
+}
+
+/// Espera, sondeando, a que `done` se cumpla; `false` si pasan 5 s.
+async fn wait_until(mut done: impl FnMut() -> bool) -> bool {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !done() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .is_ok()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn hung_read_does_not_block_session_restart() {
+    let (release, hang) = mpsc::channel();
+    let mut runtime = RuntimeController::builder()
+        .with_communicator(Box::new(ConsoleCommunicator::new()))
+        .with_interval(Duration::from_millis(10))
+        .with_read_timeout(Duration::from_millis(20))
+        .with_range_check()
+        .add_sensor("i2c", Box::new(Stuck(hang)))
+        .add_sensor("ok", Box::new(MockSensor::cycling(vec![SensorOutput::Int(1)])))
+        .build()
+        .unwrap();
+    let metrics = runtime.metrics_handle();
+    let cache = runtime.reading_cache();
+    let handle = runtime.handle();
+
+    let (tx, rx) = tokio::sync::watch::channel(false);
+    let running = tokio::spawn(async move {
+        runtime.run(rx).await;
+        runtime
+    });
+
+    // La primera lectura de "i2c" se abandona y queda colgada con el sensor bloqueado.
+    let timed_out = wait_until(|| metrics.snapshot().read_timeouts >= 1).await;
+    // Añadir un sensor reinicia la sesión, que vuelve a consultar unidad y metadatos.
+    handle
+        .add_sensor("nuevo", Box::new(MockSensor::cycling(vec![SensorOutput::Int(2)])))
+        .unwrap();
+    let restarted = wait_until(|| cache.get("nuevo").is_some()).await;
+
+    // Se libera el sensor antes de comprobar nada para que la prueba no se cuelgue.
+    drop(release);
+    assert!(timed_out, "la lectura colgada no se abandonó");
+    assert!(restarted, "el runtime no reanudó las lecturas tras reiniciar la sesión");
+
+    tx.send(true).unwrap();
+    let runtime = running.await.unwrap();
+    assert_eq!(runtime.sensor_ids(), ["i2c", "ok", "nuevo"]);
+}
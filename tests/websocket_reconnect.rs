@@ -0,0 +1,58 @@
+//! `WebSocketCommunicator` en modo cliente frente a un servidor que se reinicia.
+#![cfg(feature = "websocket")]
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::net::TcpListener;
+
+use iot_framework::network::websocket::WebSocketCommunicator;
+use iot_framework::{Communicator, SensorOutput, SensorReading};
+
+/// Espera, sondeando, a que `done` se cumpla; `false` si pasan 5 s.
+async fn wait_until(mut done: impl FnMut() -> bool) -> bool {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !done() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .is_ok()
+}
+
+/// Servidor de una sola conexión: acepta un cliente, devuelve su primera trama
+/// de texto y cierra el socket (y el listener) al terminar.
+async fn serve_one(listener: TcpListener) -> SensorReading {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+    let frame = ws.next().await.unwrap().unwrap();
+    serde_json::from_str(frame.to_text().unwrap()).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn client_reconnects_after_server_restart() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr: SocketAddr = listener.local_addr().unwrap();
+    let server = tokio::spawn(serve_one(listener));
+
+    let mut ws = WebSocketCommunicator::connect_with_backoff(
+        &format!("ws://{}", addr),
+        Duration::from_millis(10),
+        Duration::from_millis(50),
+    )
+    .unwrap();
+    assert!(wait_until(|| ws.is_connected()).await, "el cliente no llegó a conectar");
+    ws.send(SensorReading::new("temp", SensorOutput::Int(1))).unwrap();
+    assert_eq!(server.await.unwrap().value, SensorOutput::Int(1));
+
+    // El servidor cae: el cliente lo detecta y rechaza las lecturas mientras tanto.
+    assert!(wait_until(|| !ws.is_connected()).await, "el cliente no detectó la caída");
+    assert!(ws.send(SensorReading::new("temp", SensorOutput::Int(2))).is_err());
+
+    // Vuelve en la misma dirección y el cliente se reconecta solo.
+    let server = tokio::spawn(serve_one(TcpListener::bind(addr).await.unwrap()));
+    assert!(wait_until(|| ws.is_connected()).await, "el cliente no se reconectó");
+    ws.send(SensorReading::new("temp", SensorOutput::Int(3))).unwrap();
+    assert_eq!(server.await.unwrap().value, SensorOutput::Int(3));
+}